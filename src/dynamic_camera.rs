@@ -2,12 +2,13 @@ use bevy::{
     app::{
         App, 
         Plugin, 
+        PostUpdate, 
         Startup, 
         Update
     }, 
     core_pipeline::core_2d::Camera2d, 
     ecs::{
-        event::EventReader, query::With, resource::Resource, schedule::IntoScheduleConfigs, system::{
+        event::EventReader, reflect::ReflectResource, resource::Resource, schedule::IntoScheduleConfigs, system::{
             Commands, 
             Res, 
             Single, 
@@ -15,13 +16,37 @@ use bevy::{
     }, input::{
         keyboard::KeyCode, 
         mouse::MouseWheel, 
+        touch::Touches, 
         ButtonInput
-    }, math::{
+    }, log::debug, math::{
         Vec2, 
         Vec3
     }, 
+    reflect::Reflect, 
     render::camera::Projection, 
-    transform::components::Transform 
+    transform::{
+        components::Transform, 
+        TransformSystem
+    }
+};
+
+use crate::{
+    error::{
+        Bevy2dUtilError, 
+        ErrorReporter
+    }, 
+    input::{
+        scrolled_lines, 
+        InputActions, 
+        InputActionsPlugin
+    }, 
+    MainCameraFilter
+};
+
+#[cfg(feature = "inspector")]
+use bevy_inspector_egui::{
+    prelude::ReflectInspectorOptions, 
+    InspectorOptions
 };
 
 /// A [`Plugin`] that defines an interface for camera dynamicity support in Bevy
@@ -68,7 +93,33 @@ pub struct Dynamic2dCameraPlugin {
     /// 
     /// Any variation of `Some(CameraZoomConfigs::new(true / false, Some(lower_limit), None, movement_speed))` 
     /// will construct a closure system with the given speed, as well as the upper and/or lower bounds
-    pub enable_scroll_zoom: Option<CameraZoomConfigs>
+    pub enable_scroll_zoom: Option<CameraZoomConfigs>,
+
+    /// Whether camera movement and zooming read the [`InputActions`] resource instead of raw input.
+    /// 
+    /// `true` will use [`build_move_camera_by_actions_system`] and [`build_zoom_camera_by_actions_system`] 
+    /// in place of the WASD, scroll and pinch systems, and add the [`InputActionsPlugin`] if it has not been added, 
+    /// so bindings of every device are configured in one place.
+    /// 
+    /// Defaults to `false`.
+    pub use_input_actions: bool,
+
+    /// Accessibility configurations applied to every camera movement and zoom system of this plugin.
+    /// 
+    /// `Some(_)` will add the [`CameraAccessibilityConfigs`] to the world as a [`Resource`], 
+    /// which can also be changed at runtime (e.g. from an options menu).
+    /// 
+    /// Defaults to `None`.
+    pub accessibility: Option<CameraAccessibilityConfigs>,
+
+    /// Whether to confine the camera to a polygon region, e.g. the outline of an irregular map.
+    /// 
+    /// `Some(_)` will add the [`CameraConfinement`] to the world as a [`Resource`] and add 
+    /// [`confine_camera`] to the [`PostUpdate`] schedule, with the rc field of the resource 
+    /// being related to its run condition.
+    /// 
+    /// Defaults to `None`.
+    pub confinement: Option<CameraConfinement>,
 }
 
 impl Default for Dynamic2dCameraPlugin {
@@ -76,23 +127,59 @@ impl Default for Dynamic2dCameraPlugin {
         Dynamic2dCameraPlugin{ 
             spawn_camera: Some(Camera2d::default()),
             enable_wasd_movment: None,
-            enable_scroll_zoom: None
+            enable_scroll_zoom: None,
+            use_input_actions: false,
+            accessibility: None,
+            confinement: None,
         }
     }
 }
 
 impl Plugin for Dynamic2dCameraPlugin {
     fn build(&self, app: &mut App) {
+        app.register_type::<CameraMoveConfigs>();
+        app.register_type::<CameraZoomConfigs>();
+        app.register_type::<CameraAccessibilityConfigs>();
+        app.register_type::<CameraConfinement>();
+
+        app.add_event::<Bevy2dUtilError>();
+
+        if self.use_input_actions && !app.is_plugin_added::<InputActionsPlugin>() {
+            app.add_plugins(InputActionsPlugin::default());
+        }
+
+        if let Some(accessibility) = self.accessibility.clone() {
+            app.insert_resource(accessibility);
+        }
+
         if let Some(chosen_2dcamera) = self.spawn_camera.clone() {
             app.add_systems(Startup,  build_spawn_camera_system(chosen_2dcamera));
         }
         if let Some(camera_move_configs) = self.enable_wasd_movment.clone() {
             app.insert_resource(camera_move_configs.clone());
-            app.add_systems(Update, build_wasd_move_camera_system(camera_move_configs).run_if(run_if_configured_to_move));
+            if self.use_input_actions {
+                app.add_systems(Update, build_move_camera_by_actions_system(camera_move_configs).run_if(run_if_configured_to_move));
+            } else {
+                app.add_systems(Update, build_wasd_move_camera_system(camera_move_configs).run_if(run_if_configured_to_move));
+            }
         }
         if let Some(camera_zoom_configs) = self.enable_scroll_zoom.clone() {
             app.insert_resource(camera_zoom_configs.clone());
-            app.add_systems(Update, build_scroll_zoom_camera_system(camera_zoom_configs).run_if(run_if_configured_to_zoom));
+            if self.use_input_actions {
+                app.add_systems(Update, build_zoom_camera_by_actions_system(camera_zoom_configs).run_if(run_if_configured_to_zoom));
+            } else {
+                app.add_systems(Update, (
+                    build_scroll_zoom_camera_system(camera_zoom_configs),
+                    pinch_zoom_camera,
+                ).run_if(run_if_configured_to_zoom));
+            }
+        }
+        if let Some(confinement) = self.confinement.clone() {
+            app.insert_resource(confinement);
+            app.add_systems(PostUpdate, confine_camera
+                .run_if(run_if_configured_to_confine)
+                .before(TransformSystem::TransformPropagate)
+            );
         }
     }
 }
@@ -103,6 +190,9 @@ fn run_if_configured_to_move(camera_movement_configs: Res<CameraMoveConfigs>) ->
 /// Run condition which checks whether the configuration as a resource says to run or not to run a system
 fn run_if_configured_to_zoom(camera_movement_configs: Res<CameraZoomConfigs>) -> bool { camera_movement_configs.rc }
 
+/// Run condition which checks whether the configuration as a resource says to run or not to run a system
+fn run_if_configured_to_confine(camera_confinement: Res<CameraConfinement>) -> bool { camera_confinement.rc }
+
 /// Build closure which spawns a custom `Camera2d`
 pub fn build_spawn_camera_system(camera2d: Camera2d) -> impl FnMut(Commands) {
     move | mut commands: Commands | {
@@ -111,12 +201,16 @@ pub fn build_spawn_camera_system(camera2d: Camera2d) -> impl FnMut(Commands) {
 }
 
 /// Configurations for camera movement speed, and optional configurations for (right, left, top, bottom) movement limits 
-#[derive(Clone, Resource)]
+#[derive(Clone, Resource, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+#[cfg_attr(feature = "inspector", derive(InspectorOptions), reflect(InspectorOptions))]
 pub struct CameraMoveConfigs {
     /// Run condition to the closure system configured by this
     pub rc: bool,
     
     /// The speed will be multiplied by a normalized `Vec2`, and added to `transform.translation` if unobstructed
+    #[cfg_attr(feature = "inspector", inspector(min = 0.0))]
     pub speed: f32,
 
     /// `Some((f32,f32,f32,f32))` will add correspondingly: (right, left, top, bottom) movemement limits, which will set movement into the direction in question to 0`
@@ -139,12 +233,16 @@ impl CameraMoveConfigs {
 /// and changes the `Transform.translation` of the [`Entity`] with the [`Camera2d`] 
 /// component accordingly - in order to move the camera [`Entity`].
 pub fn build_wasd_move_camera_system(camera_movement_configs: CameraMoveConfigs) -> impl FnMut(
-    Single<&mut Transform, With<Camera2d>>,
-    Res<ButtonInput<KeyCode>>
+    Single<&mut Transform, MainCameraFilter>,
+    Res<ButtonInput<KeyCode>>,
+    Option<Res<CameraAccessibilityConfigs>>
 ) {
+    let mut previous_direction = Vec2::ZERO;
+
     move | 
-        query_camera: Single<&mut Transform, With<Camera2d>>, 
-        keys: Res<ButtonInput<KeyCode>>
+        query_camera: Single<&mut Transform, MainCameraFilter>, 
+        keys: Res<ButtonInput<KeyCode>>,
+        accessibility: Option<Res<CameraAccessibilityConfigs>>
     |{
         let mut movement = Vec2::new(0.,0.);
 
@@ -161,38 +259,83 @@ pub fn build_wasd_move_camera_system(camera_movement_configs: CameraMoveConfigs)
             movement.x += -1.;
         }
 
-        if movement != Vec2::new(0., 0.) {
-            movement = movement.normalize();
+        let direction = movement.normalize_or_zero();
+        let newly_pressed = direction != previous_direction;
+        previous_direction = direction;
 
-            let mut movement = Vec3::new(
-                movement.x * camera_movement_configs.speed, 
-                movement.y * camera_movement_configs.speed, 
-                0.0_f32
-            );
+        if movement != Vec2::new(0., 0.) {
+            move_camera(&camera_movement_configs, accessibility.as_deref(), &mut query_camera.into_inner(), direction, newly_pressed);
+        }
+    }
+}
 
-            let mut transform = query_camera.into_inner();
+/// Action counterpart of [`build_wasd_move_camera_system`]: moves the camera [`Entity`] in the 
+/// `pan` direction of the [`InputActions`] resource, whichever device it comes from.
+pub fn build_move_camera_by_actions_system(camera_movement_configs: CameraMoveConfigs) -> impl FnMut(
+    Single<&mut Transform, MainCameraFilter>,
+    Res<InputActions>,
+    Option<Res<CameraAccessibilityConfigs>>
+) {
+    let mut previous_direction = Vec2::ZERO;
 
-            // BUG, doesnt work for some reason, for the moment set to none
-            if let Some((right_x, left_x, up_y, down_y)) = camera_movement_configs.xxyy_limits {
-                if movement.x + transform.translation.x >= right_x || movement.x + transform.translation.x <= left_x  {
-                    movement.x = 0.
-                } 
-                if movement.y + transform.translation.y >= up_y || movement.y + transform.translation.y <= down_y  {
-                    movement.y = 0.
-                } 
-            }
+    move | 
+        query_camera: Single<&mut Transform, MainCameraFilter>, 
+        actions: Res<InputActions>,
+        accessibility: Option<Res<CameraAccessibilityConfigs>>
+    |{
+        let newly_pressed = actions.pan != previous_direction;
+        previous_direction = actions.pan;
 
-            transform.translation += movement;
+        if actions.pan != Vec2::ZERO {
+            move_camera(&camera_movement_configs, accessibility.as_deref(), &mut query_camera.into_inner(), actions.pan, newly_pressed);
         }
     }
 }
 
-#[derive(Clone, Resource)]
+/// Moves the camera by `direction * speed`, within the movement limits and the accessibility configurations
+fn move_camera(
+    camera_movement_configs: &CameraMoveConfigs, 
+    accessibility: Option<&CameraAccessibilityConfigs>, 
+    transform: &mut Transform, 
+    direction: Vec2, 
+    newly_pressed: bool
+) {
+    let speed = match accessibility.and_then(CameraAccessibilityConfigs::snap_pan_distance) {
+        // Snap movement: one jump per press instead of continuous scrolling
+        Some(_) if !newly_pressed => return,
+        Some(distance) => distance,
+        None => accessibility.map_or(camera_movement_configs.speed, |accessibility| accessibility.pan_speed(camera_movement_configs.speed)),
+    };
+
+    let mut movement = Vec3::new(
+        direction.x * speed, 
+        direction.y * speed, 
+        0.0_f32
+    );
+
+    // BUG, doesnt work for some reason, for the moment set to none
+    if let Some((right_x, left_x, up_y, down_y)) = camera_movement_configs.xxyy_limits {
+        if movement.x + transform.translation.x >= right_x || movement.x + transform.translation.x <= left_x  {
+            movement.x = 0.
+        } 
+        if movement.y + transform.translation.y >= up_y || movement.y + transform.translation.y <= down_y  {
+            movement.y = 0.
+        } 
+    }
+
+    transform.translation += movement;
+}
+
+#[derive(Clone, Resource, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+#[cfg_attr(feature = "inspector", derive(InspectorOptions), reflect(InspectorOptions))]
 pub struct CameraZoomConfigs {
     /// Run condition to the closure system configured by this
     pub rc: bool,
 
     /// The speed will be multiplied by a normalized `Vec2`, and added to `transform.translation` if unobstructed
+    #[cfg_attr(feature = "inspector", inspector(min = 0.0))]
     pub speed: f32,
    
     /// Sets lower limit to changes of `OrthographicProjection.scale` in system built from [`build_scroll_zoom_camera_system`] 
@@ -211,6 +354,11 @@ impl CameraZoomConfigs {
             limit_max,
         }
     }
+
+    /// Returns whether the `OrthographicProjection.scale` respects both `limit_min` and `limit_max`
+    pub fn is_within_limits(&self, scale: f32) -> bool {
+        self.limit_min.is_none_or(|min| scale >= min) && self.limit_max.is_none_or(|max| scale <= max)
+    }
 }
 
 /// Build a closure which takes in custom [`CameraZoomConfigs`], checks mouse_scroll 
@@ -219,43 +367,355 @@ impl CameraZoomConfigs {
 /// the projection scale of the camera [`Entity`].
 pub fn build_scroll_zoom_camera_system(camera_zoom_configs: CameraZoomConfigs) -> impl FnMut(
     EventReader<MouseWheel>,
-    Single<&mut Projection, With<Camera2d>>,
+    Single<&mut Projection, MainCameraFilter>,
+    Option<Res<CameraAccessibilityConfigs>>,
+    ErrorReporter,
 ) {
     move |
         mut evr_scroll: EventReader<MouseWheel>,
-        mut query_camera: Single<&mut Projection, With<Camera2d>>
+        mut query_camera: Single<&mut Projection, MainCameraFilter>,
+        accessibility: Option<Res<CameraAccessibilityConfigs>>,
+        mut error_reporter: ErrorReporter
     | {
         if let Some(mouse_wheel) = evr_scroll.read().next() {
             match query_camera.as_mut() {
                 Projection::Orthographic(ortho) => {
                     // Alter the zoom
-                    println!("Attempting to alter the zoom\nScale = {}\nScroll = x:{} y:{}", ortho.scale, mouse_wheel.x, mouse_wheel.y);
-                    let new_ortho_scale = ortho.scale + -(mouse_wheel.y * camera_zoom_configs.speed);
-
-                    if let Some(min) = camera_zoom_configs.limit_min {
-                        if let Some(max) = camera_zoom_configs.limit_max {
-                            if new_ortho_scale >= min && new_ortho_scale <= max {
-                                ortho.scale = new_ortho_scale;
-                            }
-                        } else {
-                            if new_ortho_scale >= min {
-                                ortho.scale = new_ortho_scale;
-                            }
-                        }
-                    } else {
-                        if let Some(max) = camera_zoom_configs.limit_max {
-                            if new_ortho_scale <= max {
-                                ortho.scale = new_ortho_scale;
-                            }
-                        } else {
-                            ortho.scale = new_ortho_scale;
-                        }
+                    debug!("Attempting to alter the zoom, scale = {}, scroll = x:{} y:{}", ortho.scale, mouse_wheel.x, mouse_wheel.y);
+                    let new_ortho_scale = ortho.scale + zoom_step(accessibility.as_deref(), -(scrolled_lines(mouse_wheel) * camera_zoom_configs.speed));
+
+                    if camera_zoom_configs.is_within_limits(new_ortho_scale) {
+                        ortho.scale = new_ortho_scale;
                     }
                 }
                 _ => {
-                    eprintln!("Scrolling Error: Projection is not Orthograpic as should be by Default");
+                    error_reporter.report(Bevy2dUtilError::CameraNotOrthographic);
+                }
+            }
+        }
+    }
+}
+/// Touch counterpart of [`build_scroll_zoom_camera_system`] for touchscreens and web builds: 
+/// changes the `OrthographicProjection.scale` proportionally to the change of distance 
+/// between two fingers, within the limits of the [`CameraZoomConfigs`] resource.
+pub fn pinch_zoom_camera(
+    touches: Res<Touches>,
+    camera_zoom_configs: Res<CameraZoomConfigs>,
+    accessibility: Option<Res<CameraAccessibilityConfigs>>,
+    mut query_camera: Single<&mut Projection, MainCameraFilter>,
+) {
+    let mut active_touches = touches.iter();
+    let (Some(first), Some(second), None) = (active_touches.next(), active_touches.next(), active_touches.next()) else {
+        return;
+    };
+
+    let previous_distance = first.previous_position().distance(second.previous_position());
+    let distance = first.position().distance(second.position());
+    if previous_distance <= f32::EPSILON || distance <= f32::EPSILON {
+        return;
+    }
+
+    if let Projection::Orthographic(ortho) = query_camera.as_mut() {
+        // Spreading the fingers apart zooms in
+        let new_ortho_scale = ortho.scale + zoom_step(accessibility.as_deref(), ortho.scale * previous_distance / distance - ortho.scale);
+
+        if camera_zoom_configs.is_within_limits(new_ortho_scale) {
+            ortho.scale = new_ortho_scale;
+        }
+    }
+}
+
+/// Action counterpart of [`build_scroll_zoom_camera_system`] and [`pinch_zoom_camera`]: changes the 
+/// `OrthographicProjection.scale` of the camera [`Entity`] by the `zoom` steps of the [`InputActions`] 
+/// resource, whichever device they come from.
+pub fn build_zoom_camera_by_actions_system(camera_zoom_configs: CameraZoomConfigs) -> impl FnMut(
+    Res<InputActions>,
+    Single<&mut Projection, MainCameraFilter>,
+    Option<Res<CameraAccessibilityConfigs>>,
+    ErrorReporter,
+) {
+    move |
+        actions: Res<InputActions>,
+        mut query_camera: Single<&mut Projection, MainCameraFilter>,
+        accessibility: Option<Res<CameraAccessibilityConfigs>>,
+        mut error_reporter: ErrorReporter
+    | {
+        if actions.zoom == 0. {
+            return;
+        }
+
+        match query_camera.as_mut() {
+            Projection::Orthographic(ortho) => {
+                let new_ortho_scale = ortho.scale + zoom_step(accessibility.as_deref(), -actions.zoom * camera_zoom_configs.speed);
+
+                if camera_zoom_configs.is_within_limits(new_ortho_scale) {
+                    ortho.scale = new_ortho_scale;
                 }
             }
+            _ => {
+                error_reporter.report(Bevy2dUtilError::CameraNotOrthographic);
+            }
+        }
+    }
+}
+
+/// Accessibility configurations for motion-sensitive players, applied to every camera movement and 
+/// zoom system of this module while `reduced_motion` is set, stored as a [`Resource`] by the [`Dynamic2dCameraPlugin`]
+#[derive(Clone, Resource, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+#[cfg_attr(feature = "inspector", derive(InspectorOptions), reflect(InspectorOptions))]
+pub struct CameraAccessibilityConfigs {
+    /// Whether the other configurations are applied
+    pub reduced_motion: bool,
+
+    /// Upper limit to the movement speed of the camera, in the units of [`CameraMoveConfigs`] `speed`
+    #[cfg_attr(feature = "inspector", inspector(min = 0.0))]
+    pub max_pan_speed: f32,
+
+    /// Upper limit to the change of `OrthographicProjection.scale` in a single frame
+    #[cfg_attr(feature = "inspector", inspector(min = 0.0))]
+    pub max_zoom_step: f32,
+
+    /// `Some(distance)` replaces continuous camera movement by a single jump of `distance` per press
+    pub snap_pan_distance: Option<f32>,
+}
+
+impl Default for CameraAccessibilityConfigs {
+    fn default() -> Self {
+        CameraAccessibilityConfigs {
+            reduced_motion: true,
+            max_pan_speed: 4.,
+            max_zoom_step: 0.05,
+            snap_pan_distance: None,
+        }
+    }
+}
+
+impl CameraAccessibilityConfigs {
+    /// Returns the movement speed to use instead of `speed`
+    pub fn pan_speed(&self, speed: f32) -> f32 {
+        if self.reduced_motion { speed.min(self.max_pan_speed) } else { speed }
+    }
+
+    /// Returns the change of `OrthographicProjection.scale` to apply instead of `step`
+    pub fn zoom_step(&self, step: f32) -> f32 {
+        if self.reduced_motion { step.clamp(-self.max_zoom_step, self.max_zoom_step) } else { step }
+    }
+
+    /// Returns the distance of snap movement, if it is enabled
+    pub fn snap_pan_distance(&self) -> Option<f32> {
+        self.snap_pan_distance.filter(|_| self.reduced_motion)
+    }
+}
+
+/// Applies the accessibility configurations, if any, to a change of `OrthographicProjection.scale`
+fn zoom_step(accessibility: Option<&CameraAccessibilityConfigs>, step: f32) -> f32 {
+    accessibility.map_or(step, |accessibility| accessibility.zoom_step(step))
+}
+
+/// What part of the camera view [`CameraConfinement`] keeps within its polygon
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfinementTarget {
+    /// Only the center of the camera
+    #[default]
+    Center,
+
+    /// The whole area visible through an orthographic projection, 
+    /// falls back to the center when the area does not fit in the polygon
+    VisibleArea,
+}
+
+/// Configurations confining the camera to an arbitrary (convex or concave) polygon region, 
+/// stored as a [`Resource`] by the [`Dynamic2dCameraPlugin`] and applied by [`confine_camera`]
+#[derive(Clone, Resource, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+#[cfg_attr(feature = "inspector", derive(InspectorOptions), reflect(InspectorOptions))]
+pub struct CameraConfinement {
+    /// Run condition to [`confine_camera`]
+    pub rc: bool,
+
+    /// Corners of the polygon in world coordinates, in order, the last one connecting back to the first
+    pub polygon: Vec<Vec2>,
+
+    pub target: ConfinementTarget,
+}
+
+impl CameraConfinement {
+    pub fn new(rc: bool, polygon: Vec<Vec2>, target: ConfinementTarget) -> Self {
+        CameraConfinement {
+            rc,
+            polygon,
+            target,
+        }
+    }
+
+    /// Whether the point lies within the polygon (even-odd rule)
+    pub fn contains(&self, point: Vec2) -> bool {
+        let mut inside = false;
+
+        for (start, end) in self.edges() {
+            if (start.y > point.y) != (end.y > point.y) 
+                && point.x < start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x) 
+            {
+                inside = !inside;
+            }
         }
+
+        inside
+    }
+
+    /// Returns the point itself if it lies within the polygon, otherwise the closest point on its outline
+    pub fn project(&self, point: Vec2) -> Vec2 {
+        if self.polygon.len() < 3 || self.contains(point) {
+            return point;
+        }
+
+        self.edges()
+            .map(|(start, end)| {
+                let edge = end - start;
+                let along = if edge == Vec2::ZERO { 0. } else { ((point - start).dot(edge) / edge.length_squared()).clamp(0., 1.) };
+                start + edge * along
+            })
+            .min_by(|a, b| a.distance_squared(point).total_cmp(&b.distance_squared(point)))
+            .unwrap_or(point)
     }
-}
\ No newline at end of file
+
+    /// Returns the camera center closest to `center` at which the confinement target lies within the polygon, 
+    /// `half_extents` being half the size of the visible area
+    pub fn confine(&self, center: Vec2, half_extents: Vec2) -> Vec2 {
+        let center = self.project(center);
+
+        if self.target == ConfinementTarget::Center {
+            return center;
+        }
+
+        // Pushes the view back by the largest overshoot of its corners, a few times since 
+        // correcting one corner of a concave region may push another one out
+        let mut confined = center;
+        for _ in 0..8 {
+            let correction = [
+                Vec2::new(half_extents.x, half_extents.y),
+                Vec2::new(-half_extents.x, half_extents.y),
+                Vec2::new(-half_extents.x, -half_extents.y),
+                Vec2::new(half_extents.x, -half_extents.y),
+            ]
+                .into_iter()
+                .map(|offset| self.project(confined + offset) - (confined + offset))
+                .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+                .unwrap_or(Vec2::ZERO);
+
+            if correction == Vec2::ZERO {
+                return confined;
+            }
+            confined += correction;
+        }
+
+        center
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.polygon
+            .iter()
+            .zip(self.polygon.iter().cycle().skip(1))
+            .map(|(start, end)| (*start, *end))
+    }
+}
+
+/// Moves the main camera back into the polygon of the [`CameraConfinement`] resource after it was moved 
+/// or zoomed out of it, should run after camera movement, e.g. in [`PostUpdate`]
+pub fn confine_camera(
+    query_camera: Single<(&mut Transform, &Projection), MainCameraFilter>,
+    camera_confinement: Res<CameraConfinement>,
+) {
+    let (mut transform, projection) = query_camera.into_inner();
+
+    let half_extents = match projection {
+        Projection::Orthographic(ortho) => ortho.area.half_size(),
+        _ => Vec2::ZERO,
+    };
+
+    let center = transform.translation.truncate();
+    let confined = camera_confinement.confine(center, half_extents);
+
+    if confined != center {
+        transform.translation.x = confined.x;
+        transform.translation.y = confined.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(target: ConfinementTarget) -> CameraConfinement {
+        CameraConfinement::new(true, vec![Vec2::new(-100., -100.), Vec2::new(100., -100.), Vec2::new(100., 100.), Vec2::new(-100., 100.)], target)
+    }
+
+    /// An L-shaped region, missing its top-right quarter
+    fn concave(target: ConfinementTarget) -> CameraConfinement {
+        CameraConfinement::new(true, vec![
+            Vec2::new(0., 0.), 
+            Vec2::new(200., 0.), 
+            Vec2::new(200., 100.), 
+            Vec2::new(100., 100.), 
+            Vec2::new(100., 200.), 
+            Vec2::new(0., 200.),
+        ], target)
+    }
+
+    #[test]
+    fn contains_follows_the_outline_of_concave_polygons() {
+        let confinement = concave(ConfinementTarget::Center);
+
+        assert!(confinement.contains(Vec2::new(50., 50.)));
+        assert!(confinement.contains(Vec2::new(150., 50.)));
+        assert!(confinement.contains(Vec2::new(50., 150.)));
+        assert!(!confinement.contains(Vec2::new(150., 150.)));
+        assert!(!confinement.contains(Vec2::new(-10., 50.)));
+    }
+
+    #[test]
+    fn project_moves_outside_points_to_the_closest_point_of_the_outline() {
+        let confinement = square(ConfinementTarget::Center);
+
+        assert_eq!(confinement.project(Vec2::new(20., -30.)), Vec2::new(20., -30.));
+        assert_eq!(confinement.project(Vec2::new(250., 40.)), Vec2::new(100., 40.));
+        assert_eq!(confinement.project(Vec2::new(-300., 300.)), Vec2::new(-100., 100.));
+
+        // Into the notch of the concave region, towards whichever edge is closer
+        let confinement = concave(ConfinementTarget::Center);
+        assert_eq!(confinement.project(Vec2::new(110., 160.)), Vec2::new(100., 160.));
+        assert_eq!(confinement.project(Vec2::new(160., 110.)), Vec2::new(160., 100.));
+    }
+
+    #[test]
+    fn polygons_with_less_than_three_corners_confine_nothing() {
+        let confinement = CameraConfinement::new(true, vec![Vec2::ZERO, Vec2::X], ConfinementTarget::VisibleArea);
+
+        assert_eq!(confinement.project(Vec2::new(500., 500.)), Vec2::new(500., 500.));
+        assert_eq!(confinement.confine(Vec2::new(500., 500.), Vec2::splat(10.)), Vec2::new(500., 500.));
+    }
+
+    #[test]
+    fn confine_keeps_the_visible_area_within_the_polygon() {
+        let half_extents = Vec2::new(40., 20.);
+
+        let confinement = square(ConfinementTarget::Center);
+        assert_eq!(confinement.confine(Vec2::new(90., 95.), half_extents), Vec2::new(90., 95.));
+
+        let confinement = square(ConfinementTarget::VisibleArea);
+        assert_eq!(confinement.confine(Vec2::new(0., 0.), half_extents), Vec2::new(0., 0.));
+        assert_eq!(confinement.confine(Vec2::new(90., 95.), half_extents), Vec2::new(60., 80.));
+        assert!(confinement.confine(Vec2::new(-500., 0.), half_extents).abs_diff_eq(Vec2::new(-60., 0.), 1e-3));
+    }
+
+    #[test]
+    fn confine_falls_back_to_the_center_when_the_visible_area_does_not_fit() {
+        let confinement = square(ConfinementTarget::VisibleArea);
+
+        assert_eq!(confinement.confine(Vec2::new(50., 300.), Vec2::splat(150.)), Vec2::new(50., 100.));
+    }
+}
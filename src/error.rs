@@ -0,0 +1,97 @@
+use bevy::ecs::{
+    event::{
+        Event, 
+        Events
+    }, 
+    system::{
+        ResMut, 
+        SystemParam
+    }, 
+    world::World
+};
+use bevy::log::warn;
+
+use std::fmt;
+
+/// An [`Event`] through which every subsystem of this crate reports recoverable problems, 
+/// instead of printing them or panicking, so games can surface or log them consistently.
+/// 
+/// Registered by every plugin of this crate. Each error is also logged as a warning.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub enum Bevy2dUtilError {
+    /// No spawned `HexGrid` has the requested id
+    GridNotFound { grid_id: u64 },
+
+    /// A `HexGrid` was spawned with the id of an already spawned grid
+    DuplicateGridId { grid_id: u64 },
+
+    /// A `HexGrid` would have no tiles, or a hextile width which is not a positive number
+    InvalidGridDimensions { columns: u32, rows: u32, hextile_width: f32 },
+
+    /// Hexgrid coordinates lie outside of the `HexGrid` with the given id
+    TileOutOfBounds { grid_id: u64, coord: (u32, u32) },
+
+    /// A texture image used by tiles could not be loaded
+    TextureNotFound { path: String, reason: String },
+
+    /// The tile textures of the `HexGrid` with the given id could not be packed into a shared atlas
+    TextureAtlasFailed { grid_id: u64, reason: String },
+
+    /// The main camera's [`Projection`](bevy::render::camera::Projection) is not orthographic
+    CameraNotOrthographic,
+
+    /// The image given as window icon could not be turned into an icon
+    InvalidWindowIcon { reason: String },
+
+    /// The selected monitor is not connected
+    MonitorNotFound,
+
+    /// A file could not be read, written or parsed
+    FileIo { path: String, reason: String },
+}
+
+impl fmt::Display for Bevy2dUtilError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bevy2dUtilError::GridNotFound { grid_id } => write!(f, "no HexGrid with id {grid_id} is spawned"),
+            Bevy2dUtilError::DuplicateGridId { grid_id } => write!(f, "a HexGrid with id {grid_id} is already spawned"),
+            Bevy2dUtilError::InvalidGridDimensions { columns, rows, hextile_width } => write!(f, "a HexGrid cannot have {columns} columns, {rows} rows and a hextile width of {hextile_width}"),
+            Bevy2dUtilError::TileOutOfBounds { grid_id, coord } => write!(f, "tile {coord:?} lies outside of the HexGrid with id {grid_id}"),
+            Bevy2dUtilError::TextureNotFound { path, reason } => write!(f, "texture `{path}` could not be loaded: {reason}"),
+            Bevy2dUtilError::TextureAtlasFailed { grid_id, reason } => write!(f, "the textures of the HexGrid with id {grid_id} could not be packed into an atlas: {reason}"),
+            Bevy2dUtilError::CameraNotOrthographic => write!(f, "the camera projection is not orthographic"),
+            Bevy2dUtilError::InvalidWindowIcon { reason } => write!(f, "the window icon is invalid: {reason}"),
+            Bevy2dUtilError::MonitorNotFound => write!(f, "the selected monitor could not be found"),
+            Bevy2dUtilError::FileIo { path, reason } => write!(f, "file `{path}` could not be accessed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Bevy2dUtilError {}
+
+/// A [`SystemParam`] for reporting a [`Bevy2dUtilError`]: logs it as a warning and sends 
+/// it as an event, if the event has been registered (so systems keep working when used 
+/// without the plugins of this crate).
+#[derive(SystemParam)]
+pub struct ErrorReporter<'w> {
+    events: Option<ResMut<'w, Events<Bevy2dUtilError>>>,
+}
+
+impl ErrorReporter<'_> {
+    pub fn report(&mut self, error: Bevy2dUtilError) {
+        warn!("{error}");
+
+        if let Some(events) = self.events.as_mut() {
+            events.send(error);
+        }
+    }
+}
+
+/// Reports a [`Bevy2dUtilError`] from an 'exclusive' system, the same way as [`ErrorReporter::report()`]
+pub(crate) fn report_in_world(world: &mut World, error: Bevy2dUtilError) {
+    warn!("{error}");
+
+    if let Some(mut events) = world.get_resource_mut::<Events<Bevy2dUtilError>>() {
+        events.send(error);
+    }
+}
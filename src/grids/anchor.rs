@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use super::{
+    hexgrid::HexGrid, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+
+/// A named point of a [`HexTile`](super::hexgrid::HexTile) to which entities can be attached.
+/// 
+/// Corners and edges are numbered counter-clockwise from `0` to `5`, in the same order
+/// as [`hexagon_corners()`](super::mesh::hexagon_corners); edge `i` goes from corner `i` to corner `i + 1`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub enum HexAnchor {
+    Center,
+    Corner(u8),
+    Edge(u8),
+}
+
+impl HexAnchor {
+    /// Returns the position of this anchor relative to the center of a tile of the [`HexGrid`].
+    pub fn offset(&self, hexgrid: &HexGrid) -> Vec2 {
+        let corners = hexgrid.hextile_corners();
+
+        match *self {
+            HexAnchor::Center => Vec2::ZERO,
+            HexAnchor::Corner(index) => corners[index as usize % 6],
+            HexAnchor::Edge(index) => (corners[index as usize % 6] + corners[(index as usize + 1) % 6]) / 2.,
+        }
+    }
+}
+
+/// A [`Component`] placing its [`Entity`] at an anchor of the [`HexTile`](super::hexgrid::HexTile) [`Entity`] it is a child of
+/// (e.g. flags, resource icons, edge walls).
+/// 
+/// The `x` and `y` of its [`Transform`] are kept at the anchor by the [`GridsPlugin`](super::GridsPlugin)
+/// when the anchor or the [`HexGrid`] layout changes, while the parent-child relationship makes
+/// it follow any change of the tile's [`Transform`] (e.g. elevation).
+/// 
+/// Its `z` is left untouched, so attachments can be layered over the tile.
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexAttachment {
+    pub anchor: HexAnchor,
+}
+
+/// Spawns an [`Entity`] with the given [`Bundle`] attached to an anchor of the [`HexTile`](super::hexgrid::HexTile) at
+/// `(x, y)` in the [`HexGrid`] specified by id.
+/// 
+/// Returns [`None`] (spawning nothing) if no such tile exists.
+pub fn attach_to_hextile(
+    commands: &mut Commands,
+    tile_index: &HexTileIndex,
+    grid_id: u64,
+    (x, y): (u32, u32),
+    anchor: HexAnchor,
+    bundle: impl Bundle,
+) -> Option<Entity> {
+    let tile_entity = tile_index.get_tile_entity(grid_id, x, y)?;
+
+    Some(
+        commands.spawn((
+            bundle,
+            HexAttachment { anchor },
+            ChildOf(tile_entity),
+        ))
+        .id()
+    )
+}
+
+/// Moves attachments to their anchor when it changed, or when a [`HexGrid`] was changed (e.g. resized)
+pub(crate) fn update_hextile_attachments(
+    changed_grids: Query<(), Changed<HexGrid>>,
+    mut attachments: Query<(Ref<HexAttachment>, &mut Transform, &ChildOf)>,
+    tile_index: Res<HexTileIndex>,
+    registry: Res<HexGridRegistry>,
+    hexgrids: Query<&HexGrid>,
+) {
+    let relayout = !changed_grids.is_empty();
+
+    for (attachment, mut transform, child_of) in &mut attachments {
+        if !relayout && !attachment.is_changed() {
+            continue;
+        }
+        let Some((grid_id, _, _)) = tile_index.get_tile_key(child_of.parent()) else { continue; };
+        let Some(hexgrid) = registry.get(grid_id).and_then(|grid_entity| hexgrids.get(grid_entity).ok()) else { continue; };
+
+        let offset = attachment.anchor.offset(hexgrid);
+
+        if transform.translation.truncate() != offset {
+            transform.translation.x = offset.x;
+            transform.translation.y = offset.y;
+        }
+    }
+}
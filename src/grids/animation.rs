@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use super::hexgrid::HexTile;
+
+/// A [`Component`] animating the [`Sprite`] of a [`HexTile`] [`Entity`] through frames of its 
+/// texture atlas, e.g. for water or lava tiles.
+/// 
+/// Inserted by [`build_change_hexgrid_textures_system()`](super::hexgrid::build_change_hexgrid_textures_system) 
+/// for tiles configured by [`TileTextures::AtlasAnimated`](super::hexgrid::TileTextures::AtlasAnimated), 
+/// and driven by the [`GridsPlugin`](super::GridsPlugin). The frame is derived from the elapsed time, 
+/// so all tiles with the same animation stay in sync. Has no effect on sprites without a texture atlas.
+#[derive(Component, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TileAnimation {
+    /// Atlas indices of the frames, in order
+    pub frames: Vec<usize>,
+
+    /// Frames per second
+    pub fps: f32,
+}
+
+impl TileAnimation {
+    pub fn new(frames: Vec<usize>, fps: f32) -> Self {
+        TileAnimation { frames, fps }
+    }
+
+    /// Returns the atlas index of the frame shown after `elapsed_secs` seconds, if there are frames
+    pub fn frame_at(&self, elapsed_secs: f32) -> Option<usize> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        let frame = (elapsed_secs * self.fps.max(0.)) as usize % self.frames.len();
+
+        Some(self.frames[frame])
+    }
+}
+
+/// Sets the atlas index of the [`Sprite`] of every animated tile to its current frame
+pub(crate) fn animate_hextiles(
+    time: Res<Time>,
+    mut animated_hextiles: Query<(&TileAnimation, &mut Sprite), With<HexTile>>,
+) {
+    let elapsed_secs = time.elapsed_secs();
+
+    for (animation, mut sprite) in &mut animated_hextiles {
+        let Some(index) = animation.frame_at(elapsed_secs) else { continue; };
+
+        // Only mutated when the frame changes, to keep change detection meaningful
+        if sprite.texture_atlas.as_ref().is_some_and(|atlas| atlas.index != index) {
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                atlas.index = index;
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use bevy::{
+    image::TextureAtlasBuilder, 
+    prelude::*
+};
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// A [`Plugin`] packing the tile textures of the grids with a [`HexGridTextureAtlas`] into one shared
+/// atlas image, so their tiles render in a handful of batches instead of one per texture change.
+/// 
+/// [`Sprite`]s of [`HexTile`]s drawing a whole image (e.g. as set by
+/// [`build_change_hexgrid_textures_system()`](super::hexgrid::build_change_hexgrid_textures_system)) are
+/// switched to the shared atlas image once their image is loaded, the atlas being rebuilt whenever a tile
+/// uses an image it does not contain yet. Sprites already drawing from a [`TextureAtlas`] are left untouched.
+#[derive(Clone, Default)]
+pub struct HexTextureBatchingPlugin;
+
+impl Plugin for HexTextureBatchingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Bevy2dUtilError>();
+
+        app.add_systems(PostUpdate, (queue_hextiles_for_batching, batch_hextile_textures).chain());
+    }
+}
+
+/// A [`Component`] enabling texture batching (see [`HexTextureBatchingPlugin`]) on the
+/// [`HexGrid`](super::hexgrid::HexGrid) [`Entity`] it is inserted on, holding its shared atlas.
+#[derive(Component, Clone)]
+pub struct HexGridTextureAtlas {
+    /// Maximum size in pixels of the atlas image, the atlas fails to build if the textures do not fit
+    pub max_size: UVec2,
+
+    /// Space in pixels between the textures of the atlas, avoiding bleeding between neighboring textures
+    pub padding: UVec2,
+
+    /// `None` until the atlas was built (default handles are those of the default assets)
+    image: Option<Handle<Image>>,
+    layout: Option<Handle<TextureAtlasLayout>>,
+
+    /// Images packed into the atlas, kept loaded so the atlas can be rebuilt
+    sources: Vec<Handle<Image>>,
+    indices: HashMap<AssetId<Image>, usize>,
+
+    /// Tiles waiting for their image to load, or for the atlas to be rebuilt
+    pending: HashSet<Entity>,
+}
+
+impl Default for HexGridTextureAtlas {
+    fn default() -> Self {
+        HexGridTextureAtlas {
+            max_size: UVec2::splat(4096),
+            padding: UVec2::splat(2),
+            image: None,
+            layout: None,
+            sources: Vec::new(),
+            indices: HashMap::new(),
+            pending: HashSet::new(),
+        }
+    }
+}
+
+impl HexGridTextureAtlas {
+    /// Returns the shared atlas image, `None` until the atlas was built
+    pub fn image(&self) -> Option<&Handle<Image>> {
+        self.image.as_ref()
+    }
+
+    /// Returns the layout of the shared atlas, `None` until the atlas was built
+    pub fn layout(&self) -> Option<&Handle<TextureAtlasLayout>> {
+        self.layout.as_ref()
+    }
+
+    /// Returns the amount of distinct images packed into the atlas
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// Queues the tiles of batched grids whose [`Sprite`] draws an image of its own
+fn queue_hextiles_for_batching(
+    mut hexgrids: Query<(&mut HexGridTextureAtlas, Option<&Children>)>,
+    changed_sprites: Query<(Entity, &ChildOf), (With<HexTile>, Changed<Sprite>)>,
+    sprites: Query<&Sprite, With<HexTile>>,
+) {
+    let draws_own_image = |tile_entity: Entity| sprites.get(tile_entity).is_ok_and(|sprite| sprite.texture_atlas.is_none());
+
+    // Grids which were just set up batch all of their tiles
+    for (mut atlas, children) in &mut hexgrids {
+        if !atlas.is_added() {
+            continue;
+        }
+        let Some(children) = children else { continue; };
+
+        atlas.pending.extend(children.into_iter().copied().filter(|&child| draws_own_image(child)));
+    }
+
+    for (tile_entity, child_of) in &changed_sprites {
+        if !draws_own_image(tile_entity) {
+            continue;
+        }
+        if let Ok((mut atlas, _)) = hexgrids.get_mut(child_of.parent()) {
+            atlas.pending.insert(tile_entity);
+        }
+    }
+}
+
+/// Switches the queued tiles whose image is loaded to the shared atlas, rebuilding it with their new images
+fn batch_hextile_textures(
+    mut hexgrids: Query<(&HexGrid, &mut HexGridTextureAtlas, &Children)>,
+    mut sprites: Query<&mut Sprite, With<HexTile>>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut error_reporter: ErrorReporter,
+) {
+    for (hexgrid, mut atlas, children) in &mut hexgrids {
+        if atlas.pending.is_empty() {
+            continue;
+        }
+
+        // Tiles which were despawned or given an atlas texture since are dropped, as are the images which failed to load
+        let mut ready = Vec::new();
+        let mut waiting = HashSet::new();
+        for &tile_entity in &atlas.pending {
+            let Ok(sprite) = sprites.get(tile_entity) else { continue; };
+            if sprite.texture_atlas.is_some() {
+                continue;
+            }
+
+            if images.contains(&sprite.image) {
+                ready.push((tile_entity, sprite.image.clone()));
+            } else if !asset_server.load_state(&sprite.image).is_failed() {
+                waiting.insert(tile_entity);
+            }
+        }
+        atlas.pending = waiting;
+
+        if ready.is_empty() {
+            continue;
+        }
+
+        let new_sources: Vec<Handle<Image>> = ready
+            .iter()
+            .map(|(_, image)| image.clone())
+            .filter(|image| !atlas.indices.contains_key(&image.id()))
+            // Deduplicated, as several tiles may wait for the same texture
+            .map(|image| (image.id(), image))
+            .collect::<HashMap<AssetId<Image>, Handle<Image>>>()
+            .into_values()
+            .collect();
+
+        if !new_sources.is_empty() {
+            let previous_sources: HashMap<usize, AssetId<Image>> = atlas.indices.iter().map(|(&source, &index)| (index, source)).collect();
+
+            let mut sources = atlas.sources.clone();
+            sources.extend(new_sources);
+
+            if let Err(reason) = rebuild_atlas(&mut atlas, sources, &mut images, &mut layouts) {
+                error_reporter.report(Bevy2dUtilError::TextureAtlasFailed { grid_id: hexgrid.id, reason });
+                continue;
+            }
+
+            // Tiles switched to the previous atlas point at the same textures in the rebuilt one
+            for &child in children {
+                let Ok(mut sprite) = sprites.get_mut(child) else { continue; };
+                if atlas.image.as_ref() != Some(&sprite.image) {
+                    continue;
+                }
+                let Some(texture_atlas) = sprite.texture_atlas.as_mut() else { continue; };
+                let Some(source) = previous_sources.get(&texture_atlas.index) else { continue; };
+
+                if let Some(&index) = atlas.indices.get(source) {
+                    texture_atlas.index = index;
+                }
+            }
+        }
+
+        let (Some(atlas_image), Some(atlas_layout)) = (atlas.image.clone(), atlas.layout.clone()) else { continue; };
+
+        for (tile_entity, image) in ready {
+            let Some(&index) = atlas.indices.get(&image.id()) else { continue; };
+            let Ok(mut sprite) = sprites.get_mut(tile_entity) else { continue; };
+
+            sprite.image = atlas_image.clone();
+            sprite.texture_atlas = Some(TextureAtlas { layout: atlas_layout.clone(), index });
+        }
+    }
+}
+
+/// Packs `sources` into the atlas image and layout, replacing their content if they already exist
+fn rebuild_atlas(
+    atlas: &mut HexGridTextureAtlas,
+    sources: Vec<Handle<Image>>,
+    images: &mut Assets<Image>,
+    layouts: &mut Assets<TextureAtlasLayout>,
+) -> Result<(), String> {
+    let (layout, texture_sources, image) = {
+        let mut builder = TextureAtlasBuilder::default();
+        builder.max_size(atlas.max_size).padding(atlas.padding);
+
+        for source in &sources {
+            let Some(image) = images.get(source) else { continue; };
+            builder.add_texture(Some(source.id()), image);
+        }
+
+        builder.build().map_err(|error| error.to_string())?
+    };
+
+    let indices = sources
+        .iter()
+        .filter_map(|source| Some((source.id(), texture_sources.texture_index(source.id())?)))
+        .collect();
+
+    // The handles are kept, so tiles already using the atlas draw the rebuilt one
+    match atlas.image.as_ref().and_then(|handle| images.get_mut(handle)) {
+        Some(atlas_image) => *atlas_image = image,
+        None => atlas.image = Some(images.add(image)),
+    }
+    match atlas.layout.as_ref().and_then(|handle| layouts.get_mut(handle)) {
+        Some(atlas_layout) => *atlas_layout = layout,
+        None => atlas.layout = Some(layouts.add(layout)),
+    }
+
+    atlas.sources = sources;
+    atlas.indices = indices;
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use bevy::{
+    asset::RenderAssetUsages, 
+    prelude::*, 
+    render::mesh::{
+        Indices, 
+        PrimitiveTopology
+    }
+};
+
+use std::collections::HashSet;
+
+use super::{
+    coords::HexDirection, 
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// Configurations of a region border
+#[derive(Clone, Copy)]
+pub struct HexRegionBorderConfigs {
+    pub color: Color,
+
+    /// Thickness of the border in pixels, drawn inside of the region
+    pub thickness: f32,
+
+    /// `z` of the border relative to the grid, high enough to be drawn above the tiles
+    pub z_offset: f32,
+}
+
+impl Default for HexRegionBorderConfigs {
+    fn default() -> Self {
+        HexRegionBorderConfigs {
+            color: Color::srgb(1., 1., 1.),
+            thickness: 3.,
+            z_offset: 1.,
+        }
+    }
+}
+
+/// A [`Component`] marking the [`Entity`] holding the border [`Mesh2d`] of a region of tiles,
+/// spawned as a child of the grid by [`build_spawn_region_border_system()`].
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component)]
+pub struct HexRegionBorder {
+    pub grid_id: u64,
+
+    /// The `x` and `y` hexgrid coordinates of the tiles of the region
+    pub tiles: Vec<(u32, u32)>,
+}
+
+impl HexGrid {
+    /// Returns the edges on the perimeter of a region of tiles, as `(tile, edge index)` pairs (see
+    /// [`HexGrid::tile_edge()`]): the edges of its tiles which are not shared with another tile of the region,
+    /// the edges along the bounds of the grid included.
+    /// 
+    /// Tiles outside of the grid are ignored, and edges are returned in the same order for the same region.
+    pub fn region_border_edges(&self, tiles: &HashSet<(u32, u32)>) -> Vec<((u32, u32), u8)> {
+        let mut region: Vec<(u32, u32)> = tiles
+            .iter()
+            .copied()
+            .filter(|&(x, y)| x < self.columns && y < self.rows)
+            .collect();
+        region.sort_unstable();
+
+        let mut edges = Vec::new();
+        for tile in region {
+            for edge_index in 0..6 {
+                let direction = HexDirection::from_edge_index(edge_index, self.orientation);
+
+                if !self.neighbor_towards(tile, direction).is_some_and(|neighbor| tiles.contains(&neighbor)) {
+                    edges.push((tile, edge_index));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns a [`Mesh`] outlining the perimeter of a region of tiles (see [`HexGrid::region_border_edges()`])
+    /// to be used with [`Mesh2d`] as a child of the grid [`Entity`], e.g. to draw territory boundaries.
+    /// 
+    /// Every border edge becomes a strip `thickness` pixels thick, inset into its tile so that the borders
+    /// of neighboring regions do not overlap. The `v` texture coordinate goes from `0` along the edge
+    /// to `1` on the inner side of the strip, e.g. for fading borders.
+    pub fn region_border_mesh(&self, tiles: &HashSet<(u32, u32)>, thickness: f32) -> Mesh {
+        // Distance from the center to the middle of the flat sides, along which hextiles may be squashed
+        let apothem = self.hextile_height() / 2.;
+        let inner_scale = (1. - thickness / apothem).max(0.);
+
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut uvs: Vec<[f32; 2]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+
+        for ((x, y), edge_index) in self.region_border_edges(tiles) {
+            let center = HexTile::new(x, y).coord_to_world(self);
+            let (start, end) = self.tile_edge((x, y), edge_index);
+            let inset = |corner: Vec2| center + (corner - center) * inner_scale;
+
+            let first = positions.len() as u32;
+            positions.extend([start, end, inset(end), inset(start)].map(|point| point.extend(0.).to_array()));
+            uvs.extend([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+            indices.extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+        }
+
+        let normals = vec![[0., 0., 1.]; positions.len()];
+
+        Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+            .with_inserted_indices(Indices::U32(indices))
+    }
+}
+
+/// Builds a [`System`] closure which spawns the border of a region of tiles of the [`HexGrid`]
+/// specified by id (see [`HexGrid::region_border_mesh()`]), as a child [`Entity`] of the grid
+/// with a [`HexRegionBorder`] component, a [`Mesh2d`] and a [`ColorMaterial`] of the configured color.
+/// 
+/// Despawn the [`HexRegionBorder`] [`Entity`] and spawn a new border when the region changes.
+/// 
+/// Can be added to the [`Startup`] schedule if set to run after the grid has
+/// been spawned, also can be used as a one-shot system.
+pub fn build_spawn_region_border_system(
+    grid_id: u64,
+    tiles: HashSet<(u32, u32)>,
+    border_configs: HexRegionBorderConfigs,
+) -> impl FnMut(
+    Commands,
+    ResMut<Assets<Mesh>>,
+    ResMut<Assets<ColorMaterial>>,
+    Query<(Entity, &HexGrid)>,
+    ErrorReporter,
+) {
+    move |
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+        hexgrids: Query<(Entity, &HexGrid)>,
+        mut error_reporter: ErrorReporter
+    | {
+        let Some((grid_entity, hexgrid)) = hexgrids.iter().find(|(_, hexgrid)| hexgrid.id == grid_id) else {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return;
+        };
+
+        let mut region: Vec<(u32, u32)> = tiles.iter().copied().collect();
+        region.sort_unstable();
+
+        commands.spawn((
+            HexRegionBorder { grid_id, tiles: region },
+            Mesh2d(meshes.add(hexgrid.region_border_mesh(&tiles, border_configs.thickness))),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(border_configs.color))),
+            Transform::from_xyz(0., 0., border_configs.z_offset),
+            // The grid itself is hidden, only its children are visible
+            Visibility::Visible,
+            ChildOf(grid_entity),
+        ));
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::{
+    ecs::system::RunSystemOnce, 
+    prelude::*
+};
+
+use super::hexgrid::{
+    build_change_hexgrid_textures_system, 
+    HexGrid, 
+    TileTextures
+};
+
+/// Extends [`Commands`] with grid spawning, so a [`HexGrid`] can be created from any system
+/// mid-game without wiring an exclusive one-shot system.
+pub trait HexGridCommandsExt {
+    /// Queues the spawning of `hexgrid` at the specified translation relative to the world (global)
+    /// (see [`HexGrid::build_spawn_hexgrid_entity_system()`]), then applies `textures` to its tiles
+    /// (see [`build_change_hexgrid_textures_system()`]).
+    /// 
+    /// The grid is spawned when the commands are applied, its [`Entity`] is known from the
+    /// [`HexGridSpawned`](super::hexgrid::HexGridSpawned) event or the [`HexGridRegistry`](super::registry::HexGridRegistry).
+    fn spawn_hexgrid(&mut self, hexgrid: HexGrid, hexgrid_translation: Vec3, textures: TileTextures);
+}
+
+impl HexGridCommandsExt for Commands<'_, '_> {
+    fn spawn_hexgrid(&mut self, hexgrid: HexGrid, hexgrid_translation: Vec3, textures: TileTextures) {
+        self.queue(move |world: &mut World| {
+            let grid_id = hexgrid.id;
+
+            let mut spawn_hexgrid = hexgrid.build_spawn_hexgrid_entity_system(hexgrid_translation);
+            spawn_hexgrid(world);
+
+            if let Err(error) = world.run_system_once(build_change_hexgrid_textures_system(textures, grid_id)) {
+                warn!("{error}");
+            }
+        });
+    }
+}
@@ -0,0 +1,569 @@
+use bevy::prelude::*;
+
+use std::ops::{
+    Add, 
+    Sub
+};
+
+use super::hexgrid::{
+    HexGrid, 
+    HexGridOrientation, 
+    HexTile
+};
+
+/// Which rows or columns of an offset coordinate system are shifted by half a hexagon.
+/// 
+/// Offset coordinates are the `x` and `y` hexgrid coordinates used by [`HexTile`], 
+/// grids of this crate use [`OffsetLayout::OddQ`] when vertical and [`OffsetLayout::OddR`] when horizontal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OffsetLayout {
+    /// Flat-top hexagons, odd columns shifted by half a hexagon
+    OddQ,
+
+    /// Flat-top hexagons, even columns shifted by half a hexagon
+    EvenQ,
+
+    /// Pointy-top hexagons, odd rows shifted by half a hexagon
+    OddR,
+
+    /// Pointy-top hexagons, even rows shifted by half a hexagon
+    EvenR,
+}
+
+impl OffsetLayout {
+    /// Returns the layout used by grids of this crate with the given orientation
+    pub fn from_orientation(orientation: HexGridOrientation) -> Self {
+        match orientation {
+            HexGridOrientation::Vertical => OffsetLayout::OddQ,
+            HexGridOrientation::Horizontal => OffsetLayout::OddR,
+        }
+    }
+}
+
+/// Axial hexagon coordinates, as assumed by most hexagonal grid algorithms: every hexagon 
+/// is reached from the origin by `q` steps along one axis and `r` steps along another.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AxialCoord {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl AxialCoord {
+    /// Steps towards the 6 neighbors, going clockwise on screen (as the `y` axis points up)
+    pub const DIRECTIONS: [AxialCoord; 6] = [
+        AxialCoord::new(1, 0),
+        AxialCoord::new(1, -1),
+        AxialCoord::new(0, -1),
+        AxialCoord::new(-1, 0),
+        AxialCoord::new(-1, 1),
+        AxialCoord::new(0, 1),
+    ];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        AxialCoord { q, r }
+    }
+
+    /// Converts offset coordinates, which may lie outside of any grid
+    pub fn from_offset(x: i32, y: i32, layout: OffsetLayout) -> Self {
+        match layout {
+            OffsetLayout::OddQ => AxialCoord::new(x, y - (x - (x & 1)) / 2),
+            OffsetLayout::EvenQ => AxialCoord::new(x, y - (x + (x & 1)) / 2),
+            OffsetLayout::OddR => AxialCoord::new(x - (y - (y & 1)) / 2, y),
+            OffsetLayout::EvenR => AxialCoord::new(x - (y + (y & 1)) / 2, y),
+        }
+    }
+
+    /// Converts to offset coordinates, which may lie outside of any grid
+    pub fn to_offset(self, layout: OffsetLayout) -> (i32, i32) {
+        let AxialCoord { q, r } = self;
+
+        match layout {
+            OffsetLayout::OddQ => (q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenQ => (q, r + (q + (q & 1)) / 2),
+            OffsetLayout::OddR => (q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenR => (q + (r + (r & 1)) / 2, r),
+        }
+    }
+
+    /// Returns the hexagon containing fractional axial coordinates
+    pub fn round(q: f32, r: f32) -> Self {
+        CubeCoord::round(q, r, -q - r).to_axial()
+    }
+
+    pub fn to_cube(self) -> CubeCoord {
+        CubeCoord::new(self.q, self.r, -self.q - self.r)
+    }
+
+    /// Returns the amount of steps between both hexagons
+    pub fn distance(self, other: AxialCoord) -> u32 {
+        self.to_cube().distance(other.to_cube())
+    }
+
+    /// Returns the neighbor in one of the [`AxialCoord::DIRECTIONS`], wrapping around after `5`
+    pub fn neighbor(self, direction: usize) -> Self {
+        self + AxialCoord::DIRECTIONS[direction % 6]
+    }
+
+    /// Returns these coordinates rotated around `center` by `steps` times 60° (see [`CubeCoord::rotate()`])
+    pub fn rotate_around(self, center: AxialCoord, steps: i32) -> Self {
+        center + (self - center).to_cube().rotate(steps).to_axial()
+    }
+
+    /// Returns these coordinates mirrored across the line through `center` along `axis` (see [`CubeCoord::reflect()`])
+    pub fn reflect_around(self, center: AxialCoord, axis: HexAxis) -> Self {
+        center + (self - center).to_cube().reflect(axis).to_axial()
+    }
+}
+
+impl Add for AxialCoord {
+    type Output = AxialCoord;
+
+    fn add(self, other: AxialCoord) -> AxialCoord {
+        AxialCoord::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for AxialCoord {
+    type Output = AxialCoord;
+
+    fn sub(self, other: AxialCoord) -> AxialCoord {
+        AxialCoord::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+/// Cube hexagon coordinates: axial coordinates with the redundant third axis `s = -q - r`, 
+/// which keeps distances, rotations and rounding symmetric.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubeCoord {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+impl CubeCoord {
+    pub const fn new(q: i32, r: i32, s: i32) -> Self {
+        CubeCoord { q, r, s }
+    }
+
+    pub fn to_axial(self) -> AxialCoord {
+        AxialCoord::new(self.q, self.r)
+    }
+
+    /// Returns the hexagon containing fractional cube coordinates, 
+    /// fixing the component with the largest rounding error so that `q + r + s = 0`
+    pub fn round(q: f32, r: f32, s: f32) -> Self {
+        CubeCoord::round_f64(q as f64, r as f64, s as f64)
+    }
+
+    /// Same as [`CubeCoord::round()`] in double precision, for callers which need sub-`f32` precision far from the origin
+    pub(crate) fn round_f64(q: f64, r: f64, s: f64) -> Self {
+        let (mut rounded_q, mut rounded_r, mut rounded_s) = (q.round(), r.round(), s.round());
+        let (error_q, error_r, error_s) = ((rounded_q - q).abs(), (rounded_r - r).abs(), (rounded_s - s).abs());
+
+        if error_q > error_r && error_q > error_s {
+            rounded_q = -rounded_r - rounded_s;
+        } else if error_r > error_s {
+            rounded_r = -rounded_q - rounded_s;
+        } else {
+            rounded_s = -rounded_q - rounded_r;
+        }
+
+        CubeCoord::new(rounded_q as i32, rounded_r as i32, rounded_s as i32)
+    }
+
+    /// Returns the amount of steps between both hexagons
+    pub fn distance(self, other: CubeCoord) -> u32 {
+        ((self.q - other.q).unsigned_abs() + (self.r - other.r).unsigned_abs() + (self.s - other.s).unsigned_abs()) / 2
+    }
+
+    /// Returns these coordinates rotated around the origin by `steps` times 60°, 
+    /// clockwise on screen (counter-clockwise if negative)
+    pub fn rotate(self, steps: i32) -> Self {
+        let mut rotated = self;
+        for _ in 0..steps.rem_euclid(6) {
+            rotated = CubeCoord::new(-rotated.s, -rotated.q, -rotated.r);
+        }
+        rotated
+    }
+
+    /// Returns these coordinates mirrored across the line through the origin along `axis`
+    pub fn reflect(self, axis: HexAxis) -> Self {
+        let CubeCoord { q, r, s } = self;
+
+        match axis {
+            HexAxis::Q => CubeCoord::new(q, s, r),
+            HexAxis::R => CubeCoord::new(s, r, q),
+            HexAxis::S => CubeCoord::new(r, q, s),
+        }
+    }
+}
+
+/// One of the 3 axes of cube coordinates, along which the other two are swapped by a reflection.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexAxis {
+    Q,
+    R,
+    S,
+}
+
+/// A named direction towards a neighbor of a hexagon, e.g. for facing and directional effects.
+/// 
+/// Vertical grids (flat-top hexagons) have neighbors towards `North`, `NorthEast`, `SouthEast`, 
+/// `South`, `SouthWest` and `NorthWest`, horizontal grids (pointy-top hexagons) towards `NorthEast`, 
+/// `East`, `SouthEast`, `SouthWest`, `West` and `NorthWest` (see [`HexDirection::all()`]).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl HexDirection {
+    /// Returns the 6 directions of hexagons of the given orientation, clockwise, in the 
+    /// order of the [`AxialCoord::DIRECTIONS`] they correspond to.
+    pub fn all(orientation: HexGridOrientation) -> [HexDirection; 6] {
+        match orientation {
+            HexGridOrientation::Vertical => [
+                HexDirection::NorthEast,
+                HexDirection::SouthEast,
+                HexDirection::South,
+                HexDirection::SouthWest,
+                HexDirection::NorthWest,
+                HexDirection::North,
+            ],
+            HexGridOrientation::Horizontal => [
+                HexDirection::East,
+                HexDirection::SouthEast,
+                HexDirection::SouthWest,
+                HexDirection::West,
+                HexDirection::NorthWest,
+                HexDirection::NorthEast,
+            ],
+        }
+    }
+
+    /// Returns the position of this direction in [`HexDirection::all()`], 
+    /// or `None` if hexagons of the given orientation have no neighbor towards it
+    pub fn index(self, orientation: HexGridOrientation) -> Option<usize> {
+        HexDirection::all(orientation).iter().position(|&direction| direction == self)
+    }
+
+    /// Returns the axial step towards this direction, 
+    /// or `None` if hexagons of the given orientation have no neighbor towards it
+    pub fn to_axial(self, orientation: HexGridOrientation) -> Option<AxialCoord> {
+        self.index(orientation).map(|index| AxialCoord::DIRECTIONS[index])
+    }
+
+    /// Returns the direction of an axial step, if it is one of the [`AxialCoord::DIRECTIONS`]
+    pub fn from_axial(step: AxialCoord, orientation: HexGridOrientation) -> Option<Self> {
+        AxialCoord::DIRECTIONS
+            .iter()
+            .position(|&direction| direction == step)
+            .map(|index| HexDirection::all(orientation)[index])
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            HexDirection::North => HexDirection::South,
+            HexDirection::NorthEast => HexDirection::SouthWest,
+            HexDirection::East => HexDirection::West,
+            HexDirection::SouthEast => HexDirection::NorthWest,
+            HexDirection::South => HexDirection::North,
+            HexDirection::SouthWest => HexDirection::NorthEast,
+            HexDirection::West => HexDirection::East,
+            HexDirection::NorthWest => HexDirection::SouthEast,
+        }
+    }
+
+    /// Returns the direction `steps` neighbors further clockwise (counter-clockwise if negative), 
+    /// or `None` if hexagons of the given orientation have no neighbor towards this direction
+    pub fn rotate(self, steps: i32, orientation: HexGridOrientation) -> Option<Self> {
+        self.index(orientation)
+            .map(|index| HexDirection::all(orientation)[(index as i32 + steps).rem_euclid(6) as usize])
+    }
+}
+
+impl HexTile {
+    /// Returns the axial coordinates of this [`HexTile`] in a grid with the given orientation
+    pub fn to_axial(&self, orientation: HexGridOrientation) -> AxialCoord {
+        AxialCoord::from_offset(self.x as i32, self.y as i32, OffsetLayout::from_orientation(orientation))
+    }
+
+    /// Returns the [`HexTile`] at the axial coordinates in a grid with the given orientation, 
+    /// or `None` if they lie before the first column or row
+    pub fn from_axial(axial: AxialCoord, orientation: HexGridOrientation) -> Option<Self> {
+        let (x, y) = axial.to_offset(OffsetLayout::from_orientation(orientation));
+
+        (x >= 0 && y >= 0).then(|| HexTile::new(x as u32, y as u32))
+    }
+
+    /// Returns the neighboring [`HexTile`] towards `direction` in a grid with the given orientation, 
+    /// or `None` if hexagons of that orientation have no neighbor towards it, or it lies before the first column or row.
+    /// 
+    /// Grid bounds are not checked, see [`HexGrid::neighbor_towards()`].
+    pub fn neighbor(&self, direction: HexDirection, orientation: HexGridOrientation) -> Option<Self> {
+        HexTile::from_axial(self.to_axial(orientation) + direction.to_axial(orientation)?, orientation)
+    }
+
+    /// Returns the direction from this [`HexTile`] towards an adjacent one, or `None` if they are not adjacent
+    pub fn direction_to(&self, other: &HexTile, orientation: HexGridOrientation) -> Option<HexDirection> {
+        HexDirection::from_axial(other.to_axial(orientation) - self.to_axial(orientation), orientation)
+    }
+}
+
+impl HexGrid {
+    /// Returns the `x` and `y` hexgrid coordinates of the neighbor of the tile at `coord` towards `direction`, 
+    /// or `None` if there is none in this grid (taking wrapping into account).
+    pub fn neighbor_towards(&self, (x, y): (u32, u32), direction: HexDirection) -> Option<(u32, u32)> {
+        let layout = OffsetLayout::from_orientation(self.orientation);
+        let (neighbor_x, neighbor_y) = (AxialCoord::from_offset(x as i32, y as i32, layout) + direction.to_axial(self.orientation)?).to_offset(layout);
+
+        self.wrap_coord(neighbor_x as i64, neighbor_y as i64)
+    }
+}
+
+/// Signed offset coordinates, centered on the grid rather than starting at its corner, for grids 
+/// growing in all directions around an origin.
+/// 
+/// The signed origin `(0, 0)` is the tile at [`HexGrid::signed_origin()`], near the center of the grid. 
+/// Signed coordinates keep the parity of the `x` and `y` hexgrid coordinates, so they use the same 
+/// [`OffsetLayout`] and may lie outside of the grid.
+impl HexGrid {
+    /// Returns the `x` and `y` hexgrid coordinates of the tile at signed coordinates `(0, 0)`: 
+    /// the center tile, moved back to an even column and row if needed.
+    pub fn signed_origin(&self) -> (u32, u32) {
+        ((self.columns / 2) & !1, (self.rows / 2) & !1)
+    }
+
+    /// Returns the signed coordinates of the tile at `x` and `y` hexgrid coordinates
+    pub fn tile_to_signed(&self, (x, y): (u32, u32)) -> IVec2 {
+        let (origin_x, origin_y) = self.signed_origin();
+
+        IVec2::new(x as i32 - origin_x as i32, y as i32 - origin_y as i32)
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the tile at signed coordinates, 
+    /// or `None` if they lie outside of the grid
+    pub fn signed_to_tile(&self, signed: IVec2) -> Option<(u32, u32)> {
+        let (origin_x, origin_y) = self.signed_origin();
+        let (x, y) = (signed.x + origin_x as i32, signed.y + origin_y as i32);
+
+        (x >= 0 && y >= 0 && (x as u32) < self.columns && (y as u32) < self.rows).then_some((x as u32, y as u32))
+    }
+
+    /// Returns the position, relative to the grid [`Entity`], of the hexagon at signed 
+    /// coordinates, extending the layout of the grid beyond its bounds
+    pub fn signed_coord_to_world(&self, signed: IVec2) -> Vec2 {
+        let (origin_x, origin_y) = self.signed_origin();
+        let origin = HexTile::new(origin_x, origin_y).coord_to_world(self);
+
+        // Horizontal grids are the transpose of vertical ones, whose odd columns are shifted up
+        let (col, row, step) = match self.orientation {
+            HexGridOrientation::Vertical => (signed.x, signed.y, self.tile_step()),
+            HexGridOrientation::Horizontal => (signed.y, signed.x, self.tile_step().yx()),
+        };
+        let offset = Vec2::new(
+            col as f32 * step.x,
+            row as f32 * step.y + if col.rem_euclid(2) == 1 { step.y / 2. } else { 0. },
+        );
+
+        match self.orientation {
+            HexGridOrientation::Vertical => origin + offset,
+            HexGridOrientation::Horizontal => origin + offset.yx(),
+        }
+    }
+
+    /// Returns the signed coordinates of the hexagon containing a position relative to the grid 
+    /// [`Entity`], which may lie outside of the grid
+    pub fn world_to_signed_coord(&self, local_pos: Vec2) -> IVec2 {
+        let (x, y) = self.local_to_offset_coord(local_pos);
+        let (origin_x, origin_y) = self.signed_origin();
+
+        IVec2::new(x as i32 - origin_x as i32, y as i32 - origin_y as i32)
+    }
+
+    /// Returns the signed coordinates of the 6 neighbors of the hexagon at signed coordinates, 
+    /// in the order of [`AxialCoord::DIRECTIONS`], whether or not they lie inside of the grid
+    pub fn signed_neighbors(&self, signed: IVec2) -> [IVec2; 6] {
+        let layout = OffsetLayout::from_orientation(self.orientation);
+        let axial = AxialCoord::from_offset(signed.x, signed.y, layout);
+
+        AxialCoord::DIRECTIONS.map(|direction| {
+            let (x, y) = (axial + direction).to_offset(layout);
+            IVec2::new(x, y)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [OffsetLayout; 4] = [OffsetLayout::OddQ, OffsetLayout::EvenQ, OffsetLayout::OddR, OffsetLayout::EvenR];
+    const ORIENTATIONS: [HexGridOrientation; 2] = [HexGridOrientation::Vertical, HexGridOrientation::Horizontal];
+
+    #[test]
+    fn offset_round_trips_through_axial() {
+        for layout in LAYOUTS {
+            for x in -6..=6 {
+                for y in -6..=6 {
+                    assert_eq!(AxialCoord::from_offset(x, y, layout).to_offset(layout), (x, y), "{layout:?} ({x}, {y})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hextiles_round_trip_through_axial() {
+        for orientation in ORIENTATIONS {
+            for x in 0..8 {
+                for y in 0..8 {
+                    let hextile = HexTile::from_axial(HexTile::new(x, y).to_axial(orientation), orientation).unwrap();
+                    assert_eq!((hextile.x, hextile.y), (x, y));
+                }
+            }
+        }
+
+        assert!(HexTile::from_axial(AxialCoord::new(-1, 0), HexGridOrientation::Vertical).is_none());
+    }
+
+    #[test]
+    fn axial_distance_matches_hextile_distance() {
+        for orientation in ORIENTATIONS {
+            let origin = HexTile::new(3, 4);
+
+            for x in 0..8 {
+                for y in 0..8 {
+                    let hextile = HexTile::new(x, y);
+                    assert_eq!(origin.to_axial(orientation).distance(hextile.to_axial(orientation)), origin.distance(&hextile, orientation));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn axial_neighbors_are_one_step_away() {
+        let axial = AxialCoord::new(2, -3);
+
+        for direction in 0..6 {
+            assert_eq!(axial.neighbor(direction).distance(axial), 1);
+            assert_eq!(axial.neighbor(direction) - axial, AxialCoord::DIRECTIONS[direction]);
+        }
+    }
+
+    #[test]
+    fn cube_round_keeps_integer_coordinates() {
+        for q in -5..=5 {
+            for r in -5..=5 {
+                let cube = AxialCoord::new(q, r).to_cube();
+                assert_eq!(CubeCoord::round(cube.q as f32, cube.r as f32, cube.s as f32), cube);
+            }
+        }
+    }
+
+    #[test]
+    fn cube_round_returns_the_closest_valid_hexagon() {
+        for q in -20..=20 {
+            for r in -20..=20 {
+                let (q, r) = (q as f32 * 0.37, r as f32 * 0.29);
+                let rounded = CubeCoord::round(q, r, -q - r);
+
+                assert_eq!(rounded.q + rounded.r + rounded.s, 0);
+
+                // Any point lies within half a hexagon of the center of the hexagon containing it
+                let (error_q, error_r) = (q - rounded.q as f32, r - rounded.r as f32);
+                let error_s = -error_q - error_r;
+                assert!(error_q.abs().max(error_r.abs()).max(error_s.abs()) <= 2. / 3. + 1e-4, "({q}, {r}) -> {rounded:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn hex_direction_rotation() {
+        for orientation in ORIENTATIONS {
+            for direction in HexDirection::all(orientation) {
+                assert_eq!(direction.rotate(6, orientation), Some(direction));
+                assert_eq!(direction.rotate(1, orientation).and_then(|rotated| rotated.rotate(-1, orientation)), Some(direction));
+                assert_eq!(direction.rotate(3, orientation), Some(direction.opposite()));
+                assert_eq!(direction.to_axial(orientation).and_then(|step| HexDirection::from_axial(step, orientation)), Some(direction));
+            }
+        }
+
+        // Flat-top hexagons have no eastern neighbor, pointy-top ones no northern one
+        assert_eq!(HexDirection::East.rotate(1, HexGridOrientation::Vertical), None);
+        assert_eq!(HexDirection::North.rotate(1, HexGridOrientation::Horizontal), None);
+    }
+
+    #[test]
+    fn hextile_neighbors_and_directions_agree() {
+        for orientation in ORIENTATIONS {
+            let hextile = HexTile::new(3, 4);
+
+            for direction in HexDirection::all(orientation) {
+                let neighbor = hextile.neighbor(direction, orientation).unwrap();
+                assert_eq!(hextile.distance(&neighbor, orientation), 1);
+                assert_eq!(hextile.direction_to(&neighbor, orientation), Some(direction));
+                assert_eq!(neighbor.neighbor(direction.opposite(), orientation).map(|back| (back.x, back.y)), Some((3, 4)));
+            }
+        }
+    }
+
+    #[test]
+    fn cube_rotation_steps_through_the_directions() {
+        for (index, direction) in AxialCoord::DIRECTIONS.iter().enumerate() {
+            assert_eq!(direction.to_cube().rotate(1).to_axial(), AxialCoord::DIRECTIONS[(index + 1) % 6]);
+            assert_eq!(direction.to_cube().rotate(-1).to_axial(), AxialCoord::DIRECTIONS[(index + 5) % 6]);
+        }
+
+        let cube = AxialCoord::new(3, -1).to_cube();
+        assert_eq!(cube.rotate(6), cube);
+        assert_eq!(cube.rotate(2).rotate(-2), cube);
+        for steps in 0..6 {
+            assert_eq!(cube.rotate(steps).distance(CubeCoord::default()), cube.distance(CubeCoord::default()));
+        }
+    }
+
+    #[test]
+    fn cube_reflection_is_an_involution() {
+        let cube = AxialCoord::new(2, -5).to_cube();
+
+        for axis in [HexAxis::Q, HexAxis::R, HexAxis::S] {
+            let reflected = cube.reflect(axis);
+            assert_eq!(reflected.reflect(axis), cube);
+            assert_eq!(reflected.q + reflected.r + reflected.s, 0);
+            assert_eq!(reflected.distance(CubeCoord::default()), cube.distance(CubeCoord::default()));
+        }
+        assert_eq!(cube.reflect(HexAxis::Q).q, cube.q);
+        assert_eq!(cube.reflect(HexAxis::R).r, cube.r);
+        assert_eq!(cube.reflect(HexAxis::S).s, cube.s);
+    }
+
+    #[test]
+    fn rotation_and_reflection_keep_their_center() {
+        let center = AxialCoord::new(4, 1);
+
+        assert_eq!(center.rotate_around(center, 2), center);
+        assert_eq!(center.reflect_around(center, HexAxis::R), center);
+        assert_eq!(AxialCoord::new(6, 0).rotate_around(center, 6), AxialCoord::new(6, 0));
+    }
+
+    #[test]
+    fn hex_direction_matches_cube_rotation() {
+        for orientation in ORIENTATIONS {
+            for direction in HexDirection::all(orientation) {
+                let rotated_step = direction.to_axial(orientation).map(|step| step.to_cube().rotate(1).to_axial());
+                assert_eq!(rotated_step, direction.rotate(1, orientation).and_then(|rotated| rotated.to_axial(orientation)));
+            }
+        }
+    }
+}
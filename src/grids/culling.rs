@@ -0,0 +1,300 @@
+use bevy::{
+    prelude::*, 
+    render::view::VisibilitySystems
+};
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile, 
+        HexTileSpawned
+    }, 
+    lod::HexGridLod, 
+    overlap::tiles_overlapping_aabb, 
+    registry::HexTileIndex
+};
+use crate::MainCameraFilter;
+
+/// A [`Plugin`] culling the tiles of the grids with a [`HexGridCulling`] which lie outside of the
+/// area seen by the main camera, so very large maps do not burden visibility checks, extraction and
+/// rendering with thousands of off-screen tiles. Culled tiles are restored when they come back into view.
+/// 
+/// The visible area is the `area` of the orthographic [`Projection`] of the main camera, which is
+/// assumed not to be rotated, around its translation.
+/// 
+/// Culling pauses on grids whose [`HexGridLod`] mesh is shown, as the tiles are all hidden by the 
+/// [`HexLodPlugin`](super::lod::HexLodPlugin) meanwhile, and resumes once they are shown again.
+#[derive(Clone, Default)]
+pub struct HexCullingPlugin;
+
+impl Plugin for HexCullingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexCullingMode>();
+
+        app.add_event::<HexTileSpawned>();
+
+        app.add_systems(PostUpdate, cull_hextiles.before(VisibilitySystems::VisibilityPropagate));
+    }
+}
+
+/// How the [`HexCullingPlugin`] culls off-screen tiles
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+pub enum HexCullingMode {
+    /// Tiles are given [`Visibility::Hidden`], and their previous [`Visibility`] back when in view again
+    #[default]
+    Hide,
+
+    /// Tiles are despawned, and spawned again (with the [`HexTile::get_bundle()`] components, their previous
+    /// [`Sprite`], and a [`HexTileSpawned`] event to add game components back) when in view again.
+    /// 
+    /// Meant for plain tile maps, as any other component of the tiles is lost.
+    Despawn,
+}
+
+/// A tile which is out of view
+#[derive(Clone)]
+enum CulledHexTile {
+    Hidden(Visibility),
+    Despawned(Option<Sprite>),
+}
+
+/// A [`Component`] enabling the culling of off-screen tiles (see [`HexCullingPlugin`]) on the
+/// [`HexGrid`] [`Entity`] it is inserted on.
+/// 
+/// Tiles which are culled when the component is removed stay culled, switch culling off by
+/// setting a `margin` covering the whole grid first.
+#[derive(Component, Clone, Default)]
+pub struct HexGridCulling {
+    pub mode: HexCullingMode,
+
+    /// Distance in pixels around the visible area within which tiles are kept, so tiles are
+    /// restored before they come into view
+    pub margin: f32,
+
+    /// Visible area relative to the grid during the previous update
+    view: Option<Rect>,
+    visible: HashSet<(u32, u32)>,
+    culled: HashMap<(u32, u32), CulledHexTile>,
+}
+
+impl HexGridCulling {
+    pub fn new(mode: HexCullingMode, margin: f32) -> Self {
+        HexGridCulling { mode, margin, ..Default::default() }
+    }
+
+    /// Returns whether the tile at `coord` is currently culled
+    pub fn is_culled(&self, coord: (u32, u32)) -> bool {
+        self.culled.contains_key(&coord)
+    }
+
+    /// Returns the amount of tiles currently culled
+    pub fn culled_len(&self) -> usize {
+        self.culled.len()
+    }
+}
+
+/// Culls the tiles which left the visible area, and restores those which came back into it
+fn cull_hextiles(
+    mut commands: Commands,
+    camera: Query<(&GlobalTransform, &Projection), MainCameraFilter>,
+    mut hexgrids: Query<(Entity, &HexGrid, &GlobalTransform, &mut HexGridCulling, Option<&HexGridLod>)>,
+    mut spawned_reader: EventReader<HexTileSpawned>,
+    tile_index: Res<HexTileIndex>,
+    mut hextiles: Query<(&mut Visibility, Option<&Sprite>), With<HexTile>>,
+) {
+    let mut spawned_per_grid: HashMap<Entity, Vec<(u32, u32)>> = HashMap::new();
+    for spawned in spawned_reader.read() {
+        spawned_per_grid.entry(spawned.grid_entity).or_default().push(spawned.coord);
+    }
+
+    let Ok((camera_transform, Projection::Orthographic(ortho))) = camera.single() else { return; };
+    let camera_translation = camera_transform.translation().truncate();
+
+    for (grid_entity, hexgrid, grid_transform, mut culling, lod) in &mut hexgrids {
+        // The level-of-detail mesh owns the visibility of the tiles, which are all checked again once it is hidden
+        if lod.is_some_and(HexGridLod::is_active) {
+            culling.view = None;
+            continue;
+        }
+
+        let view = Rect::from_corners(camera_translation + ortho.area.min, camera_translation + ortho.area.max)
+            .inflate(culling.margin);
+        let local_view = Rect::from_corners(
+            view.min - grid_transform.translation().truncate(),
+            view.max - grid_transform.translation().truncate(),
+        );
+        let spawned = spawned_per_grid.remove(&grid_entity).unwrap_or_default();
+
+        if culling.view == Some(local_view) && spawned.is_empty() && !culling.is_added() {
+            continue;
+        }
+
+        let visible: HashSet<(u32, u32)> = tiles_overlapping_aabb(hexgrid, grid_transform, view).into_iter().collect();
+
+        // Every tile may lie out of view when culling starts, otherwise only the ones which were in view
+        let candidates: Vec<(u32, u32)> = if culling.is_added() {
+            hexgrid.iter_coords().collect()
+        } else {
+            culling.visible.iter().copied().chain(spawned).collect()
+        };
+
+        for coord in candidates {
+            if visible.contains(&coord) || culling.culled.contains_key(&coord) {
+                continue;
+            }
+            let Some(tile_entity) = tile_index.get_tile_entity(hexgrid.id, coord.0, coord.1) else { continue; };
+            let Ok((mut visibility, sprite)) = hextiles.get_mut(tile_entity) else { continue; };
+
+            let culled = match culling.mode {
+                HexCullingMode::Hide => {
+                    let previous = *visibility;
+                    *visibility = Visibility::Hidden;
+                    CulledHexTile::Hidden(previous)
+                },
+                HexCullingMode::Despawn => {
+                    commands.entity(tile_entity).despawn();
+                    CulledHexTile::Despawned(sprite.cloned())
+                },
+            };
+            culling.culled.insert(coord, culled);
+        }
+
+        for &coord in &visible {
+            let Some(culled) = culling.culled.remove(&coord) else { continue; };
+
+            match culled {
+                CulledHexTile::Hidden(previous) => {
+                    let Some(tile_entity) = tile_index.get_tile_entity(hexgrid.id, coord.0, coord.1) else { continue; };
+                    if let Ok((mut visibility, _)) = hextiles.get_mut(tile_entity) {
+                        *visibility = previous;
+                    }
+                },
+                CulledHexTile::Despawned(sprite) => {
+                    let mut tile_commands = commands.spawn((
+                        HexTile::new(coord.0, coord.1).get_bundle(hexgrid),
+                        ChildOf(grid_entity),
+                    ));
+                    if let Some(sprite) = sprite {
+                        tile_commands.insert(sprite);
+                    }
+
+                    let tile_entity = tile_commands.id();
+                    commands.send_event(HexTileSpawned { grid_entity, tile_entity, coord });
+                },
+            }
+        }
+
+        culling.view = Some(local_view);
+        culling.visible = visible;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::{
+        hexgrid::HexGridOrientation, 
+        registry::{
+            index_hextile, 
+            register_hexgrid, 
+            unindex_hextile, 
+            unregister_hexgrid, 
+            HexGridRegistry
+        }
+    };
+
+    fn hexgrid() -> HexGrid {
+        HexGrid::with_id(0, HexGridOrientation::Vertical, 20, 20, 32.)
+    }
+
+    /// Spawns a camera seeing a 96 pixels wide square around `translation` (without the camera systems 
+    /// updating its `area`), and the grid culled with `mode`
+    fn app(mode: HexCullingMode, translation: Vec2) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, HexCullingPlugin));
+        app.init_resource::<HexGridRegistry>();
+        app.init_resource::<HexTileIndex>();
+        app.add_observer(register_hexgrid);
+        app.add_observer(unregister_hexgrid);
+        app.add_observer(index_hextile);
+        app.add_observer(unindex_hextile);
+
+        app.world_mut().spawn((
+            Camera2d,
+            Projection::Orthographic(OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(96.)),
+                ..OrthographicProjection::default_2d()
+            }),
+            GlobalTransform::from_translation(translation.extend(0.)),
+        ));
+
+        let mut spawn_hexgrid = hexgrid().build_spawn_hexgrid_entity_system(Vec3::ZERO);
+        spawn_hexgrid(app.world_mut());
+
+        let grid_entity = app.world().resource::<HexGridRegistry>().get(0).unwrap();
+        app.world_mut().entity_mut(grid_entity).insert(HexGridCulling::new(mode, 0.));
+        app
+    }
+
+    fn move_camera(app: &mut App, translation: Vec2) {
+        let mut cameras = app.world_mut().query_filtered::<&mut GlobalTransform, With<Camera2d>>();
+        *cameras.single_mut(app.world_mut()).unwrap() = GlobalTransform::from_translation(translation.extend(0.));
+    }
+
+    fn culling(app: &mut App) -> &HexGridCulling {
+        let mut cullings = app.world_mut().query::<&HexGridCulling>();
+        cullings.single(app.world()).unwrap()
+    }
+
+    fn visibility(app: &mut App, coord: (u32, u32)) -> Option<Visibility> {
+        let tile_entity = app.world().resource::<HexTileIndex>().get_tile_entity(0, coord.0, coord.1)?;
+        app.world().get::<Visibility>(tile_entity).copied()
+    }
+
+    #[test]
+    fn hidden_tiles_are_restored_when_back_in_view() {
+        let (center, corner) = (Vec2::ZERO, Vec2::new(-200., -220.));
+        let coord_at = |position| hexgrid().world_to_coord(&GlobalTransform::IDENTITY, position).unwrap();
+
+        let mut app = app(HexCullingMode::Hide, center);
+        app.update();
+
+        let in_view = tiles_overlapping_aabb(&hexgrid(), &GlobalTransform::IDENTITY, Rect::from_center_size(center, Vec2::splat(96.))).len();
+        assert_eq!(culling(&mut app).culled_len(), 400 - in_view);
+        assert_eq!(visibility(&mut app, coord_at(center)), Some(Visibility::Visible));
+        assert_eq!(visibility(&mut app, coord_at(corner)), Some(Visibility::Hidden));
+
+        move_camera(&mut app, corner);
+        app.update();
+
+        assert!(culling(&mut app).is_culled(coord_at(center)));
+        assert!(!culling(&mut app).is_culled(coord_at(corner)));
+        assert_eq!(visibility(&mut app, coord_at(center)), Some(Visibility::Hidden));
+        assert_eq!(visibility(&mut app, coord_at(corner)), Some(Visibility::Visible));
+    }
+
+    #[test]
+    fn despawned_tiles_are_spawned_again_when_back_in_view() {
+        let corner = Vec2::new(-200., -220.);
+        let corner_coord = hexgrid().world_to_coord(&GlobalTransform::IDENTITY, corner).unwrap();
+
+        let mut app = app(HexCullingMode::Despawn, Vec2::ZERO);
+        app.update();
+
+        let culled = culling(&mut app).culled_len();
+        assert_eq!(app.world_mut().query::<&HexTile>().iter(app.world()).count(), 400 - culled);
+        assert_eq!(visibility(&mut app, corner_coord), None);
+
+        move_camera(&mut app, corner);
+        app.update();
+
+        assert!(visibility(&mut app, corner_coord).is_some());
+        assert_eq!(app.world_mut().query::<&HexTile>().iter(app.world()).count(), 400 - culling(&mut app).culled_len());
+        assert!(app.world().resource::<Events<HexTileSpawned>>().iter_current_update_events().any(|spawned| spawned.coord == corner_coord));
+    }
+}
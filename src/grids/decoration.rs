@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    next_random, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+
+/// A [`Plugin`] which scatters decorative sprites (trees, rocks, grass tufts) over [`HexTile`]s
+/// according to their [`HexTerrain`] and a list of [`DecorationRule`]s.
+/// 
+/// Scattering is deterministic: the same `seed` always yields the same decorations on the same tiles.
+/// 
+/// Decorations are spawned as children of the tile with a [`HexDecoration`] component, and are
+/// despawned and scattered again whenever the tile's [`HexTerrain`] changes.
+#[derive(Clone, Default)]
+pub struct HexDecorationPlugin {
+    pub rules: Vec<DecorationRule>,
+    pub seed: u64,
+}
+
+impl Plugin for HexDecorationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexTerrain>();
+        app.register_type::<HexDecoration>();
+
+        app.insert_resource(DecorationRules {
+            rules: self.rules.clone(),
+            seed: self.seed,
+        });
+
+        app.add_systems(Update, scatter_hextile_decorations);
+    }
+}
+
+/// A [`Component`] describing the terrain type of a [`HexTile`] (e.g. `"forest"`),
+/// used to pick which [`DecorationRule`]s apply to it.
+#[derive(Component, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(Component)]
+pub struct HexTerrain(pub String);
+
+/// Describes which decorations are scattered on tiles of a given terrain.
+#[derive(Clone)]
+pub struct DecorationRule {
+    /// The [`HexTerrain`] this rule applies to
+    pub terrain: String,
+
+    /// Paths towards the images of the decorations, one is picked at random for each decoration
+    pub textures: Vec<String>,
+
+    /// Average amount of decorations per tile, e.g. `2.5` spawns two or three decorations per tile
+    pub density: f32,
+
+    /// Pixel size of the decoration sprites
+    pub size: Vec2,
+
+    /// How far from the tile center decorations can be placed, as a fraction of
+    /// the radius of the circle inscribed in the hexagon (`0.0` to `1.0`)
+    pub jitter: f32,
+}
+
+/// A [`Component`] marking a decoration [`Entity`] spawned by the [`HexDecorationPlugin`].
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexDecoration;
+
+/// The configs of the [`HexDecorationPlugin`]
+#[derive(Resource, Clone)]
+struct DecorationRules {
+    rules: Vec<DecorationRule>,
+    seed: u64,
+}
+
+/// Despawns the decorations of tiles whose [`HexTerrain`] changed or was removed, and scatters new ones
+fn scatter_hextile_decorations(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    decoration_rules: Res<DecorationRules>,
+    tile_index: Res<HexTileIndex>,
+    registry: Res<HexGridRegistry>,
+    hexgrids: Query<&HexGrid>,
+    changed_terrains: Query<(Entity, &HexTerrain, Option<&Children>), (With<HexTile>, Changed<HexTerrain>)>,
+    mut removed_terrains: RemovedComponents<HexTerrain>,
+    children_query: Query<&Children>,
+    decorations: Query<(), With<HexDecoration>>,
+) {
+    let despawn_decorations = |commands: &mut Commands, children: &Children| {
+        for &child in children {
+            if decorations.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+    };
+
+    for tile_entity in removed_terrains.read() {
+        if let Ok(children) = children_query.get(tile_entity) {
+            despawn_decorations(&mut commands, children);
+        }
+    }
+
+    for (tile_entity, terrain, children) in &changed_terrains {
+        if let Some(children) = children {
+            despawn_decorations(&mut commands, children);
+        }
+
+        let Some((grid_id, x, y)) = tile_index.get_tile_key(tile_entity) else { continue; };
+        let Some(hexgrid) = registry.get(grid_id).and_then(|grid_entity| hexgrids.get(grid_entity).ok()) else { continue; };
+
+        let inner_radius = hexgrid.hextile_sprite_size().min_element() / 2.;
+
+        for (rule_index, rule) in decoration_rules.rules.iter().enumerate() {
+            if rule.terrain != terrain.0 || rule.textures.is_empty() {
+                continue;
+            }
+
+            // Seeded per tile and rule, so scattering does not depend on the order tiles are processed in
+            let mut random_state = (decoration_rules.seed
+                ^ grid_id.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                ^ (((x as u64) << 32) | y as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9)
+                ^ (rule_index as u64).wrapping_mul(0x94D0_49BB_1331_11EB)) | 1;
+
+            let mut count = rule.density.floor() as u32;
+            if next_random(&mut random_state) < rule.density.fract() {
+                count += 1;
+            }
+
+            for _ in 0..count {
+                let texture = &rule.textures[(next_random(&mut random_state) * rule.textures.len() as f32) as usize % rule.textures.len()];
+
+                // Uniformly distributed over the disc
+                let angle = next_random(&mut random_state) * std::f32::consts::TAU;
+                let distance = next_random(&mut random_state).sqrt() * inner_radius * rule.jitter.clamp(0., 1.);
+                let offset = Vec2::from_angle(angle) * distance;
+
+                commands.spawn((
+                    HexDecoration,
+                    Sprite {
+                        custom_size: Some(rule.size),
+                        image: asset_server.load(texture),
+                        ..Default::default()
+                    },
+                    // Lower decorations are drawn in front of higher ones
+                    Transform::from_xyz(offset.x, offset.y, 0.1 - offset.y / hexgrid.hextile_width * 0.01),
+                    Visibility::Visible,
+                    ChildOf(tile_entity),
+                ));
+            }
+        }
+    }
+}
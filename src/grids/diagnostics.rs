@@ -0,0 +1,67 @@
+use bevy::{
+    diagnostic::{
+        Diagnostic, 
+        DiagnosticPath, 
+        Diagnostics, 
+        RegisterDiagnostic
+    }, 
+    prelude::*
+};
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// A [`Plugin`] that registers grid related diagnostics into Bevy's [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore), 
+/// so they can be logged with the `LogDiagnosticsPlugin` or read for performance tuning.
+/// 
+/// Measured once per frame in the [`Last`] schedule, after visibility has been computed.
+#[derive(Clone, Default)]
+pub struct HexGridDiagnosticsPlugin;
+
+impl HexGridDiagnosticsPlugin {
+    /// Amount of spawned [`HexGrid`] entities.
+    pub const GRID_COUNT: DiagnosticPath = DiagnosticPath::const_new("hexgrid/grid_count");
+
+    /// Amount of spawned [`HexTile`] entities over all grids.
+    pub const TILE_COUNT: DiagnosticPath = DiagnosticPath::const_new("hexgrid/tile_count");
+
+    /// Amount of [`HexTile`] entities spawned during this frame.
+    pub const TILES_SPAWNED: DiagnosticPath = DiagnosticPath::const_new("hexgrid/tiles_spawned");
+
+    /// Amount of [`HexTile`] entities whose [`Sprite`] was inserted or changed during this frame.
+    pub const TEXTURE_UPDATES: DiagnosticPath = DiagnosticPath::const_new("hexgrid/texture_updates");
+
+    /// Amount of [`HexTile`] entities visible to a camera after frustum culling.
+    pub const VISIBLE_TILES: DiagnosticPath = DiagnosticPath::const_new("hexgrid/visible_tiles");
+}
+
+impl Plugin for HexGridDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::GRID_COUNT).with_suffix(" grids"));
+        app.register_diagnostic(Diagnostic::new(Self::TILE_COUNT).with_suffix(" tiles"));
+        app.register_diagnostic(Diagnostic::new(Self::TILES_SPAWNED).with_suffix(" tiles"));
+        app.register_diagnostic(Diagnostic::new(Self::TEXTURE_UPDATES).with_suffix(" tiles"));
+        app.register_diagnostic(Diagnostic::new(Self::VISIBLE_TILES).with_suffix(" tiles"));
+
+        app.add_systems(Last, measure_hexgrid_diagnostics);
+    }
+}
+
+/// Adds one measurement per frame to each of the [`HexGridDiagnosticsPlugin`] diagnostics
+fn measure_hexgrid_diagnostics(
+    mut diagnostics: Diagnostics,
+    hexgrids: Query<(), With<HexGrid>>,
+    hextiles: Query<&ViewVisibility, With<HexTile>>,
+    spawned_hextiles: Query<(), Added<HexTile>>,
+    retextured_hextiles: Query<(), (With<HexTile>, Changed<Sprite>)>,
+) {
+    diagnostics.add_measurement(&HexGridDiagnosticsPlugin::GRID_COUNT, || hexgrids.iter().count() as f64);
+    diagnostics.add_measurement(&HexGridDiagnosticsPlugin::TILE_COUNT, || hextiles.iter().count() as f64);
+    diagnostics.add_measurement(&HexGridDiagnosticsPlugin::TILES_SPAWNED, || spawned_hextiles.iter().count() as f64);
+    diagnostics.add_measurement(&HexGridDiagnosticsPlugin::TEXTURE_UPDATES, || retextured_hextiles.iter().count() as f64);
+    diagnostics.add_measurement(&HexGridDiagnosticsPlugin::VISIBLE_TILES, || {
+        hextiles.iter().filter(|view_visibility| view_visibility.get()).count() as f64
+    });
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+
+use super::{
+    coords::HexDirection, 
+    hexgrid::{
+        HexGrid, 
+        HexGridOrientation, 
+        HexTile
+    }
+};
+
+impl HexDirection {
+    /// Returns the index of the edge of a hexagon facing its neighbor towards this direction,
+    /// numbered like the edges of [`HexAnchor`](super::anchor::HexAnchor) (edge `i` going from corner `i`
+    /// to corner `i + 1`), or `None` if hexagons of the given orientation have no neighbor towards it.
+    pub fn edge_index(self, orientation: HexGridOrientation) -> Option<u8> {
+        let index = self.index(orientation)? as u8;
+
+        // Directions go clockwise while edges go counter-clockwise, starting from the corner
+        // pointing right (vertical grids) or from the upper right corner (horizontal grids)
+        Some(match orientation {
+            HexGridOrientation::Vertical => (6 - index) % 6,
+            HexGridOrientation::Horizontal => (11 - index) % 6,
+        })
+    }
+
+    /// Returns the direction of the neighbor a hexagon of the given orientation shares its edge `edge_index` with
+    pub fn from_edge_index(edge_index: u8, orientation: HexGridOrientation) -> Self {
+        let edge_index = edge_index % 6;
+
+        HexDirection::all(orientation)
+            .into_iter()
+            .find(|direction| direction.edge_index(orientation) == Some(edge_index))
+            .unwrap_or(HexDirection::all(orientation)[0])
+    }
+}
+
+impl HexGrid {
+    /// Returns the 6 corners of the tile at `coord`, relative to the grid [`Entity`]
+    /// (like [`HexTile::coord_to_world()`]), in the same order as [`HexGrid::hextile_corners()`].
+    /// 
+    /// Use [`GlobalTransform::transform_point()`] with the transform of the grid [`Entity`] for world positions.
+    pub fn tile_corners(&self, (x, y): (u32, u32)) -> [Vec2; 6] {
+        let center = HexTile::new(x, y).coord_to_world(self);
+
+        self.hextile_corners().map(|corner| center + corner)
+    }
+
+    /// Returns the midpoints of the 6 edges of the tile at `coord`, relative to the grid [`Entity`],
+    /// edge `i` going from corner `i` to corner `i + 1` of [`HexGrid::tile_corners()`].
+    pub fn tile_edge_midpoints(&self, coord: (u32, u32)) -> [Vec2; 6] {
+        let corners = self.tile_corners(coord);
+
+        std::array::from_fn(|index| (corners[index] + corners[(index + 1) % 6]) / 2.)
+    }
+
+    /// Returns the start and end points of edge `edge_index` of the tile at `coord`, relative to the grid [`Entity`]
+    pub fn tile_edge(&self, coord: (u32, u32), edge_index: u8) -> (Vec2, Vec2) {
+        let corners = self.tile_corners(coord);
+        let edge_index = edge_index as usize % 6;
+
+        (corners[edge_index], corners[(edge_index + 1) % 6])
+    }
+
+    /// Returns the index of the edge of the tile at `a` shared with the adjacent tile at `b`
+    /// (taking wrapping into account), or `None` if they are not adjacent.
+    pub fn shared_edge_index(&self, a: (u32, u32), b: (u32, u32)) -> Option<u8> {
+        HexDirection::all(self.orientation)
+            .into_iter()
+            .find(|&direction| self.neighbor_towards(a, direction) == Some(b))
+            .and_then(|direction| direction.edge_index(self.orientation))
+    }
+
+    /// Returns the start and end points of the edge shared by the adjacent tiles at `a` and `b`,
+    /// relative to the grid [`Entity`], or `None` if they are not adjacent, e.g. to draw rivers or walls between them.
+    /// 
+    /// Across the seams of a wrapping grid, the edge is positioned along the tile at `a`.
+    pub fn shared_edge(&self, a: (u32, u32), b: (u32, u32)) -> Option<(Vec2, Vec2)> {
+        self.shared_edge_index(a, b).map(|edge_index| self.tile_edge(a, edge_index))
+    }
+}
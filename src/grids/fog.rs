@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::{
+    fov::{
+        update_hextile_visibility, 
+        HexFieldOfViewPlugin, 
+        HexViewer
+    }, 
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    registry::HexTileIndex
+};
+
+/// A [`Plugin`] adding fog of war to the grids with a [`HexGridFog`]: each of their tiles is 
+/// [`HexFogState::Unseen`] until a [`HexViewer`] of the grid sees it, [`HexFogState::Visible`] while 
+/// one does, and [`HexFogState::Seen`] afterwards, as stored in its [`HexTileFog`].
+/// 
+/// Sight is computed by the [`HexFieldOfViewPlugin`], which is added (without a hidden tint) if missing. 
+/// The [`Sprite`]s of tiles are dimmed or hidden according to their state, which takes over their `color` 
+/// and [`Visibility`].
+#[derive(Clone)]
+pub struct HexFogOfWarPlugin {
+    /// Color of the [`Sprite`] of seen tiles which are not visible anymore, `None` leaves it untouched
+    pub seen_tint: Option<Color>,
+
+    /// Color of the [`Sprite`] of tiles which were never seen, `None` hides them instead
+    pub unseen_tint: Option<Color>,
+}
+
+impl Default for HexFogOfWarPlugin {
+    fn default() -> Self {
+        HexFogOfWarPlugin {
+            seen_tint: Some(Color::srgb(0.5, 0.5, 0.5)),
+            unseen_tint: None,
+        }
+    }
+}
+
+impl Plugin for HexFogOfWarPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<HexFieldOfViewPlugin>() {
+            app.add_plugins(HexFieldOfViewPlugin::default());
+        }
+
+        app.register_type::<HexGridFog>();
+        app.register_type::<HexTileFog>();
+
+        app.insert_resource(FogTints {
+            seen: self.seen_tint,
+            unseen: self.unseen_tint,
+        });
+
+        app.add_systems(PostUpdate, update_hextile_fog.after(update_hextile_visibility));
+    }
+}
+
+/// A [`Component`] enabling fog of war on the [`HexGrid`] [`Entity`] it is inserted on, 
+/// holding the `x` and `y` hexgrid coordinates of the tiles seen so far.
+/// 
+/// Clear `seen` to cover the whole grid in fog again.
+#[derive(Component, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexGridFog {
+    pub seen: HashSet<(u32, u32)>,
+}
+
+/// The fog of war state of a tile.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Reflect)]
+pub enum HexFogState {
+    /// Never seen by a viewer
+    #[default]
+    Unseen,
+
+    /// Seen before, but by no viewer at the moment
+    Seen,
+
+    /// Seen by a viewer at the moment
+    Visible,
+}
+
+/// A [`Component`] holding the fog of war state of a [`HexTile`] [`Entity`] of a grid with a [`HexGridFog`], 
+/// kept up to date by the [`HexFogOfWarPlugin`].
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexTileFog(pub HexFogState);
+
+/// The configs of the [`HexFogOfWarPlugin`]
+#[derive(Resource, Clone, Copy)]
+struct FogTints {
+    seen: Option<Color>,
+    unseen: Option<Color>,
+}
+
+/// Reveals the tiles seen by viewers, and updates the fog state and sprites of the tiles of grids whose visible tiles changed
+fn update_hextile_fog(
+    mut commands: Commands,
+    fog_tints: Res<FogTints>,
+    tile_index: Res<HexTileIndex>,
+    mut previous_visible: Local<HashMap<Entity, HashSet<(u32, u32)>>>,
+    mut hexgrids: Query<(Entity, &HexGrid, &mut HexGridFog)>,
+    viewers: Query<(&HexViewer, &ChildOf), With<HexTile>>,
+    added_hextiles: Query<(), Added<HexTile>>,
+    mut hextiles: Query<(Option<&HexTileFog>, Option<&mut Sprite>, &mut Visibility), With<HexTile>>,
+) {
+    let mut visible_per_grid: HashMap<Entity, HashSet<(u32, u32)>> = HashMap::new();
+    for (viewer, child_of) in &viewers {
+        visible_per_grid.entry(child_of.parent()).or_default().extend(viewer.visible.iter().copied());
+    }
+
+    for (grid_entity, hexgrid, mut fog) in &mut hexgrids {
+        let visible = visible_per_grid.remove(&grid_entity).unwrap_or_default();
+
+        if !fog.is_changed() && added_hextiles.is_empty() && previous_visible.get(&grid_entity) == Some(&visible) {
+            continue;
+        }
+
+        if !visible.is_subset(&fog.seen) {
+            fog.seen.extend(visible.iter().copied());
+        }
+
+        for (x, y, tile_entity) in tile_index.iter_grid(hexgrid.id) {
+            let Ok((tile_fog, sprite, mut visibility)) = hextiles.get_mut(tile_entity) else { continue; };
+
+            let state = if visible.contains(&(x, y)) {
+                HexFogState::Visible
+            } else if fog.seen.contains(&(x, y)) {
+                HexFogState::Seen
+            } else {
+                HexFogState::Unseen
+            };
+
+            if tile_fog.is_some_and(|tile_fog| tile_fog.0 == state) {
+                continue;
+            }
+            commands.entity(tile_entity).insert(HexTileFog(state));
+
+            let (color, shown) = match state {
+                HexFogState::Visible => (Some(Color::WHITE), true),
+                HexFogState::Seen => (fog_tints.seen, true),
+                HexFogState::Unseen => (fog_tints.unseen, fog_tints.unseen.is_some()),
+            };
+
+            if let (Some(color), Some(mut sprite)) = (color, sprite) {
+                sprite.color = color;
+            }
+            *visibility = if shown { Visibility::Visible } else { Visibility::Hidden };
+        }
+
+        previous_visible.insert(grid_entity, visible);
+    }
+}
@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// A [`Plugin`] computing which [`HexTile`]s can be seen by the [`HexViewer`]s of their grid, 
+/// sight being blocked by tiles marked with [`HexTileOpaque`].
+/// 
+/// Tiles seen by no viewer of their grid receive a [`HexTileHidden`] marker, grids without 
+/// viewers are left untouched. Visibility is recomputed whenever a viewer or an opaque tile 
+/// is added, changed or removed.
+#[derive(Clone, Default)]
+pub struct HexFieldOfViewPlugin {
+    /// `Some(color)` also sets the `color` of the [`Sprite`] of hidden tiles to `color`, and back 
+    /// to white once they are visible again, to darken them.
+    /// 
+    /// Takes over the `color` of tile [`Sprite`]s, so should not be combined with the 
+    /// [`HexLightingPlugin`](super::lighting::HexLightingPlugin).
+    pub hidden_tint: Option<Color>,
+}
+
+impl Plugin for HexFieldOfViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexViewer>();
+        app.register_type::<HexTileOpaque>();
+        app.register_type::<HexTileHidden>();
+
+        app.insert_resource(HiddenTint(self.hidden_tint));
+
+        app.add_systems(PostUpdate, update_hextile_visibility);
+    }
+}
+
+/// A [`Component`] turning the [`HexTile`] [`Entity`] it is inserted on into a viewer seeing 
+/// up to `radius` steps away, e.g. a unit standing on the tile.
+#[derive(Component, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexViewer {
+    pub radius: u32,
+
+    /// The `x` and `y` hexgrid coordinates of the tiles seen by this viewer, kept up to date by the [`HexFieldOfViewPlugin`]
+    pub visible: HashSet<(u32, u32)>,
+}
+
+impl HexViewer {
+    pub fn new(radius: u32) -> Self {
+        HexViewer {
+            radius,
+            visible: HashSet::new(),
+        }
+    }
+}
+
+/// A [`Component`] marking a [`HexTile`] [`Entity`] as blocking sight, e.g. a wall or a forest.
+/// 
+/// Opaque tiles are visible themselves, but hide the tiles behind them.
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexTileOpaque;
+
+/// A [`Component`] marking a [`HexTile`] [`Entity`] seen by no [`HexViewer`] of its grid, 
+/// inserted and removed by the [`HexFieldOfViewPlugin`].
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexTileHidden;
+
+#[derive(Resource, Clone, Copy)]
+pub(crate) struct HiddenTint(Option<Color>);
+
+impl HexGrid {
+    /// Returns the `x` and `y` hexgrid coordinates of the tiles seen from `source` up to `radius` steps away, 
+    /// a tile being seen if no tile of `blocking` lies on the straight line between both (see [`HexGrid::hex_line()`]).
+    /// 
+    /// `source` and the blocking tiles themselves are seen.
+    pub fn field_of_view(&self, source: (u32, u32), radius: u32, blocking: &HashSet<(u32, u32)>) -> HashSet<(u32, u32)> {
+        self.tiles_in_range(source, radius)
+            .into_iter()
+            .filter(|&target| {
+                let line = self.hex_line(source, target);
+                line.len() < 3 || line[1..line.len() - 1].iter().all(|tile| !blocking.contains(tile))
+            })
+            .collect()
+    }
+}
+
+/// Recomputes the field of view of every viewer, and the hidden tiles of their grids, whenever viewers or opaque tiles change
+pub(crate) fn update_hextile_visibility(
+    mut commands: Commands,
+    hidden_tint: Res<HiddenTint>,
+    changed_viewers: Query<(), (With<HexViewer>, Or<(Changed<HexViewer>, Changed<HexTile>)>)>,
+    added_opaque: Query<(), Added<HexTileOpaque>>,
+    added_hextiles: Query<(), Added<HexTile>>,
+    mut removed_viewers: RemovedComponents<HexViewer>,
+    mut removed_opaque: RemovedComponents<HexTileOpaque>,
+    mut viewers: Query<(&mut HexViewer, &HexTile, &ChildOf)>,
+    opaque_tiles: Query<(&HexTile, &ChildOf), With<HexTileOpaque>>,
+    hexgrids: Query<&HexGrid>,
+    mut hextiles: Query<(Entity, &HexTile, &ChildOf, Has<HexTileHidden>, Option<&mut Sprite>)>,
+) {
+    let removed = removed_viewers.read().count() + removed_opaque.read().count() > 0;
+    if !removed && changed_viewers.is_empty() && added_opaque.is_empty() && added_hextiles.is_empty() {
+        return;
+    }
+
+    let mut opaque_per_grid: HashMap<Entity, HashSet<(u32, u32)>> = HashMap::new();
+    for (hextile, child_of) in &opaque_tiles {
+        opaque_per_grid.entry(child_of.parent()).or_default().insert((hextile.x, hextile.y));
+    }
+
+    let no_opaque_tiles = HashSet::new();
+    let mut visible_per_grid: HashMap<Entity, HashSet<(u32, u32)>> = HashMap::new();
+
+    for (mut viewer, hextile, child_of) in &mut viewers {
+        let Ok(hexgrid) = hexgrids.get(child_of.parent()) else { continue; };
+        let blocking = opaque_per_grid.get(&child_of.parent()).unwrap_or(&no_opaque_tiles);
+
+        let visible = hexgrid.field_of_view((hextile.x, hextile.y), viewer.radius, blocking);
+        visible_per_grid.entry(child_of.parent()).or_default().extend(visible.iter().copied());
+
+        // Not a change of the viewer itself, which would trigger a recomputation next frame
+        viewer.bypass_change_detection().visible = visible;
+    }
+
+    for (tile_entity, hextile, child_of, was_hidden, sprite) in &mut hextiles {
+        let hidden = visible_per_grid
+            .get(&child_of.parent())
+            .is_some_and(|visible| !visible.contains(&(hextile.x, hextile.y)));
+
+        if hidden == was_hidden {
+            continue;
+        }
+
+        if hidden {
+            commands.entity(tile_entity).insert(HexTileHidden);
+        } else {
+            commands.entity(tile_entity).remove::<HexTileHidden>();
+        }
+
+        if let (Some(tint), Some(mut sprite)) = (hidden_tint.0, sprite) {
+            sprite.color = if hidden { tint } else { Color::WHITE };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::hexgrid::HexGridOrientation;
+
+    #[test]
+    fn field_of_view_without_blockers_is_the_range() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 9, 9, 64.);
+
+        let seen = hexgrid.field_of_view((4, 4), 3, &HashSet::new());
+        let in_range: HashSet<(u32, u32)> = hexgrid.tiles_in_range((4, 4), 3).into_iter().collect();
+
+        assert_eq!(seen, in_range);
+    }
+
+    #[test]
+    fn blocking_tiles_hide_what_lies_behind_them() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 9, 9, 64.);
+        let blocking = HashSet::from([(4, 5)]);
+
+        // Straight up the column of the source
+        let seen = hexgrid.field_of_view((4, 4), 3, &blocking);
+        assert!(seen.contains(&(4, 4)));
+        assert!(seen.contains(&(4, 5)));
+        assert!(!seen.contains(&(4, 6)));
+        assert!(!seen.contains(&(4, 7)));
+
+        // Tiles on the other side are unaffected
+        assert!(seen.contains(&(4, 1)));
+    }
+
+    #[test]
+    fn blocking_source_still_sees_around() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Horizontal, 7, 7, 64.);
+        let blocking = HashSet::from([(3, 3)]);
+
+        let seen = hexgrid.field_of_view((3, 3), 2, &blocking);
+        let in_range: HashSet<(u32, u32)> = hexgrid.tiles_in_range((3, 3), 2).into_iter().collect();
+
+        assert_eq!(seen, in_range);
+    }
+}
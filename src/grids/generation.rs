@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use super::{
+    decoration::HexTerrain, 
+    hexgrid::{
+        HexGrid, 
+        HexTile, 
+        TileTextures
+    }, 
+    next_random, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// Seedable fractal value noise sampled over the tiles of a [`HexGrid`], used for procedural
+/// map generation: the same `seed` always yields the same map.
+/// 
+/// Noise values are mapped to terrain with a list of bands (see [`HexGrid::noise_tile_textures()`]
+/// and [`build_assign_hexgrid_terrain_system()`]).
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexNoise {
+    pub seed: u64,
+
+    /// Size of the features of the first octave, in tiles (e.g. `8.0` for continents about 8 tiles wide)
+    pub scale: f32,
+
+    /// Amount of layers of noise added together, each one with features half as big as the previous one
+    pub octaves: u32,
+
+    /// How much each octave weighs compared to the previous one, usually `0.5`
+    pub persistence: f32,
+}
+
+impl Default for HexNoise {
+    fn default() -> Self {
+        HexNoise {
+            seed: 0,
+            scale: 8.0,
+            octaves: 4,
+            persistence: 0.5,
+        }
+    }
+}
+
+impl HexNoise {
+    pub fn new(seed: u64) -> Self {
+        HexNoise {
+            seed,
+            ..default()
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_octaves(mut self, octaves: u32, persistence: f32) -> Self {
+        self.octaves = octaves;
+        self.persistence = persistence;
+        self
+    }
+
+    /// Returns the noise value at `pos` (in tiles), between `0.0` and `1.0`
+    pub fn sample(&self, pos: Vec2) -> f32 {
+        let mut value = 0.0;
+        let mut weight = 1.0;
+        let mut total_weight = 0.0;
+        let mut frequency = 1.0 / self.scale.max(f32::EPSILON);
+
+        for octave in 0..self.octaves.max(1) {
+            value += self.value_noise(pos * frequency, octave) * weight;
+            total_weight += weight;
+            weight *= self.persistence;
+            frequency *= 2.0;
+        }
+
+        value / total_weight
+    }
+
+    /// Smoothly interpolates between random values placed on the integer lattice
+    fn value_noise(&self, pos: Vec2, octave: u32) -> f32 {
+        let cell = pos.floor();
+        let t = pos - cell;
+        let t = t * t * (Vec2::splat(3.0) - 2.0 * t);
+        let (x, y) = (cell.x as i64, cell.y as i64);
+
+        let top = self.lattice_value(x, y, octave).lerp(self.lattice_value(x + 1, y, octave), t.x);
+        let bottom = self.lattice_value(x, y + 1, octave).lerp(self.lattice_value(x + 1, y + 1, octave), t.x);
+
+        top.lerp(bottom, t.y)
+    }
+
+    fn lattice_value(&self, x: i64, y: i64, octave: u32) -> f32 {
+        let mut state = self.seed
+            ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+            ^ (octave as u64).wrapping_mul(0x1656_67B1_9E37_79F9);
+        if state == 0 {
+            state = 0x2545_F491_4F6C_DD1D;
+        }
+
+        next_random(&mut state);
+        next_random(&mut state)
+    }
+}
+
+/// Returns the value of the first band whose threshold `noise_value` lies below,
+/// or the value of the last band if it lies above every threshold
+fn band_for<T>(bands: &[(f32, T)], noise_value: f32) -> Option<&T> {
+    bands
+        .iter()
+        .find(|(threshold, _)| noise_value < *threshold)
+        .or(bands.last())
+        .map(|(_, value)| value)
+}
+
+impl HexGrid {
+    /// Returns the noise value (see [`HexNoise::sample()`]) of every tile of the grid, by `x` and `y` hexgrid
+    /// coordinates, sampled at the tile centers so that features look the same in both orientations.
+    pub fn noise_map(&self, noise: &HexNoise) -> HashMap<(u32, u32), f32> {
+        self.iter_coords()
+            .map(|(x, y)| {
+                let pos = HexTile::new(x, y).coord_to_world(self) / (self.hextile_width * self.spacing);
+                ((x, y), noise.sample(pos))
+            })
+            .collect()
+    }
+
+    /// Returns [`TileTextures`] giving every tile the texture of the band its noise value falls in, e.g.:
+    /// 
+    /// `vec![(0.35, "water.png".into()), (0.7, "grass.png".into()), (1.0, "mountain.png".into())]`
+    /// 
+    /// Each band is `(threshold, texture path)`, sorted by increasing threshold: a tile takes the
+    /// first band whose threshold its noise value lies below, or the last band otherwise.
+    /// 
+    /// The result can be passed to [`build_change_hexgrid_textures_system()`](super::hexgrid::build_change_hexgrid_textures_system).
+    pub fn noise_tile_textures(&self, noise: &HexNoise, bands: &[(f32, String)]) -> TileTextures {
+        let Some((_, default_path)) = bands.last() else {
+            return TileTextures::Multiple(Vec::new(), String::new());
+        };
+
+        let noise_map = self.noise_map(noise);
+        let mut ranges_per_band: Vec<Vec<(u32, u32)>> = vec![Vec::new(); bands.len()];
+
+        // Consecutive tiles of the same band are merged into a single range
+        for (x, y) in self.iter_coords() {
+            let band = bands
+                .iter()
+                .position(|(threshold, _)| noise_map[&(x, y)] < *threshold)
+                .unwrap_or(bands.len() - 1);
+            let order_pos = HexTile::new(x, y).coord_to_order(self.columns);
+
+            match ranges_per_band[band].last_mut() {
+                Some((_, end)) if *end + 1 == order_pos => *end = order_pos,
+                _ => ranges_per_band[band].push((order_pos, order_pos)),
+            }
+        }
+
+        TileTextures::Multiple(
+            bands
+                .iter()
+                .zip(ranges_per_band)
+                .filter(|(_, ranges)| !ranges.is_empty())
+                .map(|((_, path), ranges)| (ranges, path.clone()))
+                .collect(),
+            default_path.clone(),
+        )
+    }
+}
+
+/// Builds a [`System`] closure which inserts a [`HexTerrain`] on every tile of the [`HexGrid`] specified by id,
+/// picked from `bands` by the noise value of the tile (see [`HexGrid::noise_tile_textures()`] for how bands work).
+/// 
+/// Combined with the [`HexDecorationPlugin`](super::decoration::HexDecorationPlugin), this also scatters
+/// the decorations of each terrain.
+/// 
+/// Reports a [`Bevy2dUtilError::GridNotFound`] if no such grid is spawned.
+/// 
+/// Can be added to the [`Startup`] schedule if set to run after the entities have
+/// been spawned, also can be used as a one-shot system.
+pub fn build_assign_hexgrid_terrain_system(
+    grid_id: u64,
+    noise: HexNoise,
+    bands: Vec<(f32, String)>,
+) -> impl FnMut(
+    Commands,
+    Res<HexGridRegistry>,
+    Res<HexTileIndex>,
+    Query<&HexGrid>,
+    ErrorReporter,
+) {
+    move |
+        mut commands: Commands,
+        registry: Res<HexGridRegistry>,
+        tile_index: Res<HexTileIndex>,
+        hexgrids: Query<&HexGrid>,
+        mut error_reporter: ErrorReporter
+    | {
+        let Some(hexgrid) = registry.get(grid_id).and_then(|grid_entity| hexgrids.get(grid_entity).ok()) else {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return;
+        };
+
+        for ((x, y), noise_value) in hexgrid.noise_map(&noise) {
+            let Some(tile_entity) = tile_index.get_tile_entity(grid_id, x, y) else { continue; };
+            let Some(terrain) = band_for(&bands, noise_value) else { return; };
+
+            commands.entity(tile_entity).insert(HexTerrain(terrain.clone()));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// A debug [`Plugin`] drawing the hexagon outlines, bounds and coordinate markers of every 
+/// [`HexGrid`] with [`Gizmos`], straight from the layout math (so it shows where tiles are 
+/// expected to be, whether or not they are spawned).
+/// 
+/// Inserts its `configs` as a [`Resource`], toggle its `rc` field at runtime (e.g. with 
+/// [`toggle_hexgrid_gizmos`]) to show or hide the gizmos.
+#[derive(Clone, Default)]
+pub struct HexGridGizmosPlugin {
+    pub configs: HexGridGizmoConfigs,
+}
+
+impl Plugin for HexGridGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexGridGizmoConfigs>();
+
+        app.insert_resource(self.configs.clone());
+
+        app.add_systems(Update, draw_hexgrid_gizmos.run_if(run_if_configured_to_draw));
+    }
+}
+
+/// Configurations of the gizmos drawn by the [`HexGridGizmosPlugin`]
+#[derive(Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct HexGridGizmoConfigs {
+    /// Run condition to the gizmo drawing system
+    pub rc: bool,
+
+    /// Color of the hexagon outline of every tile, `None` to skip the outlines
+    pub outline_color: Option<Color>,
+
+    /// Color of the rectangle bounding all tiles of a grid, `None` to skip the bounds
+    pub bounds_color: Option<Color>,
+
+    /// Color of the coordinate markers, `None` to skip them: a dot at every tile center, 
+    /// a circle around tile `(0, 0)`, and arrows from it towards tiles `(1, 0)` and `(0, 1)`
+    pub marker_color: Option<Color>,
+}
+
+impl Default for HexGridGizmoConfigs {
+    fn default() -> Self {
+        HexGridGizmoConfigs {
+            rc: true,
+            outline_color: Some(Color::srgb(0., 1., 0.)),
+            bounds_color: Some(Color::srgb(1., 1., 0.)),
+            marker_color: Some(Color::srgb(1., 0., 1.)),
+        }
+    }
+}
+
+fn run_if_configured_to_draw(gizmo_configs: Res<HexGridGizmoConfigs>) -> bool { gizmo_configs.rc }
+
+/// [`System`] which shows or hides the gizmos of the [`HexGridGizmosPlugin`].
+pub fn toggle_hexgrid_gizmos(mut gizmo_configs: ResMut<HexGridGizmoConfigs>) {
+    gizmo_configs.rc = !gizmo_configs.rc;
+}
+
+/// Draws the configured gizmos of every [`HexGrid`]
+fn draw_hexgrid_gizmos(
+    mut gizmos: Gizmos,
+    gizmo_configs: Res<HexGridGizmoConfigs>,
+    hexgrids: Query<(&HexGrid, &GlobalTransform)>,
+) {
+    for (hexgrid, grid_transform) in &hexgrids {
+        let to_world = |local: Vec2| grid_transform.transform_point(local.extend(0.)).truncate();
+        let tile_center = |x: u32, y: u32| {
+            HexTile::new(x, y).coord_to_world(hexgrid)
+        };
+        let corners = hexgrid.hextile_corners();
+
+        for col in 0..hexgrid.columns {
+            for row in 0..hexgrid.rows {
+                let center = tile_center(col, row);
+
+                if let Some(outline_color) = gizmo_configs.outline_color {
+                    gizmos.linestrip_2d(
+                        corners.iter().chain(corners.first()).map(|&corner| to_world(center + corner)),
+                        outline_color,
+                    );
+                }
+
+                if let Some(marker_color) = gizmo_configs.marker_color {
+                    gizmos.circle_2d(Isometry2d::from_translation(to_world(center)), hexgrid.hextile_width * 0.03, marker_color);
+                }
+            }
+        }
+
+        if let Some(bounds_color) = gizmo_configs.bounds_color {
+            let bounds = hexgrid.local_bounds();
+            if !bounds.is_empty() {
+                let bounds_corners = [bounds.min, Vec2::new(bounds.max.x, bounds.min.y), bounds.max, Vec2::new(bounds.min.x, bounds.max.y), bounds.min];
+                gizmos.linestrip_2d(bounds_corners.map(to_world), bounds_color);
+            }
+        }
+
+        if let Some(marker_color) = gizmo_configs.marker_color {
+            let origin = tile_center(0, 0);
+            gizmos.circle_2d(Isometry2d::from_translation(to_world(origin)), hexgrid.hextile_width * 0.25, marker_color);
+
+            if hexgrid.columns > 1 {
+                gizmos.arrow_2d(to_world(origin), to_world(tile_center(1, 0)), marker_color);
+            }
+            if hexgrid.rows > 1 {
+                gizmos.arrow_2d(to_world(origin), to_world(tile_center(0, 1)), marker_color);
+            }
+        }
+    }
+}
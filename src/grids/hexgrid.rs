@@ -1,19 +1,39 @@
-use bevy::{prelude::*};
+use bevy::{asset::AssetLoadFailedEvent, prelude::*};
+
+use super::{
+    animation::TileAnimation, 
+    coords::{
+        AxialCoord, 
+        OffsetLayout
+    }, 
+    mesh::hexagon_corners, 
+    next_random, 
+    picking::HoveredHexTile, 
+    registry::HexGridRegistry
+};
+use crate::error::{
+    report_in_world, 
+    Bevy2dUtilError, 
+    ErrorReporter
+};
 
 use std::{
+    borrow::Cow, 
     collections::HashSet, 
-    sync::atomic::{
-        AtomicU64, 
-        Ordering
-    }
+    sync::Arc
 };
 
+/// The height of a regular hexagon across its flat sides relative to its corner-to-corner width: `sqrt(3) / 2`
+pub const HEXTILE_HEIGHT_RATIO: f32 = 0.866_025_4;
+
 /// A placeholder [`Component`] for a hexagonal tile entity that keeps track of its grid coordinates.
 /// 
 /// This component is attached as a child of the [`Entity`] containing the [`HexGrid`] component.
 /// 
 /// The `x` and `y` fields denote its hexgrid coordinates.
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HexTile {
     pub x: u32,
     pub y: u32,
@@ -24,35 +44,33 @@ impl HexTile {
         HexTile{ x, y, }
     }
 
-    /// Returns the truncated intended `translation` of the [`Entity`] containing this [`HexTile`] 
-    /// relative to the center-point of the [`HexGrid`] containing [`Entity`] of which it is a child
-    /// by using data obtained describing the [`HexGrid`] in question.
-    pub fn coord_to_world(
-        &self, 
-        hextile_width: f32, 
-        columns: u32, 
-        rows: u32,
-        orientation: HexGridOrientation,
-    ) -> Vec2 {
-        let hextile_height = hextile_width * 0.866;
+    /// Returns the intended `translation` of the [`Entity`] containing this [`HexTile`] 
+    /// relative to the [`HexGrid`] containing [`Entity`] of which it is a child, placed 
+    /// according to the [`HexGridAnchor`] of the grid.
+    /// 
+    /// Vertical grids are laid out in straight columns spaced by `0.75` of a hextile, with odd 
+    /// columns shifted up by half a hextile, horizontal grids as their transpose: straight rows, 
+    /// with odd rows shifted right. Distances are scaled by the `spacing` of the grid.
+    pub fn coord_to_world(&self, hexgrid: &HexGrid) -> Vec2 {
+        self.centered_coord_to_world(hexgrid) + hexgrid.anchor_offset()
+    }
 
-        match orientation {
-            HexGridOrientation::Vertical => {
-                let x = (((self.x as f32) * hextile_width * 0.75) - ((hextile_width * 0.75) * (((columns / 2) as f32)) - hextile_width * 0.375)) - (if columns % 2 != 0 {
-                    hextile_width * 0.375
-                } else { 0.0 });
-                let y = ((self.y as f32) * hextile_height + (if self.x % 2 != 0 { 
-                    hextile_height / 2.0 
-                } else { 0.0 })) - (hextile_height * ((rows / 2) as f32) - (hextile_height / 4.0)) - (if rows % 2 != 0 {
-                    hextile_height / 2.0
-                } else { 0.0 });
-                
-                return Vec2::new(x,y);
-            },
-            HexGridOrientation::Horizontal => {
-                println!("Error: not functional yet, fucking wait");
-                panic!()
-            }
+    /// Same as [`HexTile::coord_to_world()`] for a grid centered on its [`Entity`]
+    fn centered_coord_to_world(&self, hexgrid: &HexGrid) -> Vec2 {
+        // Computed in the vertical layout, horizontal grids being its transpose
+        let (col, row, columns, rows, step) = match hexgrid.orientation {
+            HexGridOrientation::Vertical => (self.x, self.y, hexgrid.columns, hexgrid.rows, hexgrid.tile_step()),
+            HexGridOrientation::Horizontal => (self.y, self.x, hexgrid.rows, hexgrid.columns, hexgrid.tile_step().yx()),
+        };
+
+        let position = Vec2::new(
+            (col as f32 - (columns as f32 - 1.) / 2.) * step.x,
+            (row as f32 - (rows as f32 - 1.) / 2.) * step.y + if col % 2 != 0 { step.y / 2. } else { 0. } - step.y / 4.,
+        );
+
+        match hexgrid.orientation {
+            HexGridOrientation::Vertical => position,
+            HexGridOrientation::Horizontal => position.yx(),
         }
     }
 
@@ -60,7 +78,7 @@ impl HexTile {
     /// `(col: 0, row: 0) -> 1`, increment by each tile moved through the columns, and 
     /// increment each time we reach a new row.
     /// 
-    /// e.g. if `columns = 5` then `(col: 0, row: 1) -> 6`.
+    /// e.g. if `columns = 5` then `(col: 0, row: 1) -> 6`, see [`HexGrid::order_to_coord()`] for the inverse.
     pub fn coord_to_order(
         &self,
         columns: u32, 
@@ -68,6 +86,16 @@ impl HexTile {
         (self.x + 1) + (self.y * columns)
     }
 
+    /// Returns the amount of steps between this [`HexTile`] and the other one, 
+    /// moving from neighbor to neighbor.
+    pub fn distance(
+        &self,
+        other: &HexTile,
+        orientation: HexGridOrientation,
+    ) -> u32 {
+        self.to_axial(orientation).distance(other.to_axial(orientation))
+    }
+
     /// Returns a [`Vec<(u32, u32)>`] containing a list of this [`HexTile`]'s 
     /// neighbour hextiles' `x` and `y` hexgrid coordinates.
     /// 
@@ -103,8 +131,22 @@ impl HexTile {
                 }
             }
             HexGridOrientation::Horizontal => {
-                println!("Error: not functional yet, fucking wait");
-                panic!();
+                // offsets for odd/even rows
+                let offsets: &[(i32, i32)] = if y % 2 == 0 {
+                    // even row
+                    &[ (0, 1), (0, -1), (-1, 0), (1, 0), (-1, 1), (-1, -1) ]
+                } else {
+                    // odd row
+                    &[ (0, 1), (0, -1), (-1, 0), (1, 0), (1, 1), (1, -1) ]
+                };
+
+                for (dx, dy) in offsets {
+                    let nx = x + dx;
+                    let ny = y + dy;
+                    if nx >= 0 && ny >= 0 && nx < columns as i32 && ny < rows as i32 {
+                        neighbors.push((nx as u32, ny as u32));
+                    }
+                }
             }
         }
         neighbors
@@ -119,40 +161,22 @@ impl HexTile {
     /// Consumes the [`HexTile`].
     pub fn get_bundle(
         self,
-        orientation: HexGridOrientation,
-        columns: u32, 
-        rows: u32, 
-        hextile_width: f32
+        hexgrid: &HexGrid,
     ) -> impl Bundle {
         
-        let relative_pos = self.coord_to_world(hextile_width, columns, rows, orientation);
+        let relative_pos = self.coord_to_world(hexgrid);
 
-        match orientation {
-            HexGridOrientation::Vertical => {
-                return 
-                (
-                    self,
-                    Transform::from_xyz(relative_pos.x, relative_pos.y, 0.),
-                    Visibility::Visible,
-                );
-            },
-            HexGridOrientation::Horizontal => {
-                println!("Error: not functional yet, fucking wait");
-                panic!()
-            }
-        }
+        (
+            self,
+            Transform::from_xyz(relative_pos.x, relative_pos.y, 0.),
+            Visibility::Visible,
+        )
     }
 }
 
-// Global counter, starts at 1 because fetch_add returns the previous value
-/// Counter for the amount of [`HexGrid`] instances that have been created with [`HexGrid::new()`].
-static HEXGRID_COUNTER: AtomicU64 = AtomicU64::new(1);
-
-/// Resets the atomic integer counter to 1.
-pub fn reset_hexgrid_counter() { HEXGRID_COUNTER.store(1, Ordering::Relaxed); }
-
 /// Defines the orientation of the HexCells (pointy to the side, or upwards).
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HexGridOrientation {
     /// Straight columns offset by 0.75.
     /// 
@@ -165,14 +189,35 @@ pub enum HexGridOrientation {
     Horizontal
 }
 
+/// Defines which point of a [`HexGrid`] lies at the translation of its [`Entity`], 
+/// e.g. to align a grid to a corner of the screen or to another [`Entity`].
+#[derive(Clone, Copy, PartialEq, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexGridAnchor {
+    /// The grid is centered on its [`Entity`]
+    #[default]
+    Center,
+
+    /// The corners of the rectangle covered by all of the hexagons of the grid (see [`HexGrid::local_bounds()`])
+    BottomLeft,
+    BottomRight,
+    TopLeft,
+    TopRight,
+
+    /// The grid is centered on its [`Entity`], then moved by the offset
+    Offset(Vec2),
+}
+
 /// A [`Component`] for a grid of hexagonal cells [`Entity`] containing an 
 /// incremental id, as well as data which describes the grid.
 /// 
 /// This component is a parent to the [`HexTile`] entities which it contains.
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HexGrid{
-    /// A unique identifier counter which begins at one, and increments for 
-    /// each new instance of [`HexGrid`] created with [`HexGrid::new()`].
+    /// A unique identifier, either supplied by the user through [`HexGrid::with_id()`], 
+    /// or allocated per world by the [`HexGridRegistry`] through [`HexGrid::new()`].
     pub id: u64,
 
     /// The orientation of its hexagon tiles.
@@ -190,25 +235,511 @@ pub struct HexGrid{
 
     /// Pixel width of the individual hextiles.
     pub hextile_width: f32,
+
+    /// Pixel height of the individual hextiles across their flat sides, `None` for regular hexagons 
+    /// (see [`HexGrid::hextile_height()`] and [`HexGrid::with_hextile_height()`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height_override: Option<f32>,
+
+    /// Factor applied to the distances between the centers of tiles, `1.0` for touching tiles 
+    /// (see [`HexGrid::with_spacing()`]).
+    #[cfg_attr(feature = "serde", serde(default = "default_spacing"))]
+    pub spacing: f32,
+
+    /// Pixel width of the seams between neighboring tiles, `0.0` for touching tiles 
+    /// (see [`HexGrid::with_tile_gap()`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tile_gap: f32,
+
+    /// Which point of the grid lies at the translation of its [`Entity`] (see [`HexGrid::with_anchor()`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub anchor: HexGridAnchor,
+
+    /// Whether the last column is a neighbor of the first one, e.g. for planet-style maps 
+    /// (see [`HexGrid::with_wrapping()`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub wrap_x: bool,
+
+    /// Whether the last row is a neighbor of the first one (see [`HexGrid::with_wrapping()`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub wrap_y: bool,
+}
+
+#[cfg(feature = "serde")]
+fn default_spacing() -> f32 { 1. }
+
+/// Returns the width of regular hextiles which are `hextile_height` pixels tall on screen, see [`HexGrid::from_height()`]
+fn hextile_width_from_height(orientation: HexGridOrientation, hextile_height: f32) -> f32 {
+    match orientation {
+        HexGridOrientation::Vertical => hextile_height / HEXTILE_HEIGHT_RATIO,
+        HexGridOrientation::Horizontal => hextile_height,
+    }
 }
 
 impl HexGrid {
-    /// Returns a [`HexGrid`] with an `id` larger by one then the last instance created by this method (unless reset with [`reset_hexgrid_counter()`]).
+    /// Returns a [`HexGrid`] with an `id` allocated by the [`HexGridRegistry`] of the world 
+    /// it will be spawned in (see [`HexGridRegistry::allocate_id()`]).
+    /// 
+    /// Ids are allocated per world, so separate `App`s (e.g. tests running in parallel) 
+    /// never affect each other's ids.
     pub fn new(
+        registry: &mut HexGridRegistry,
         orientation: HexGridOrientation, 
         columns: u32, 
         rows: u32, 
         hextile_width: f32
     ) -> Self {
 
-        let id = HEXGRID_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let id = registry.allocate_id();
+
+        HexGrid {
+            id,
+            orientation,
+            columns,
+            rows,
+            hextile_width,
+            height_override: None,
+            spacing: 1.,
+            tile_gap: 0.,
+            anchor: HexGridAnchor::Center,
+            wrap_x: false,
+            wrap_y: false,
+        }
+    }
+
+    /// Same as [`HexGrid::new()`], but returns a [`Bevy2dUtilError::InvalidGridDimensions`] 
+    /// if the grid would have invalid dimensions (see [`HexGrid::validate_dimensions()`]), 
+    /// in which case no id is allocated.
+    pub fn try_new(
+        registry: &mut HexGridRegistry,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_width: f32
+    ) -> Result<Self, Bevy2dUtilError> {
+        HexGrid::with_id(0, orientation, columns, rows, hextile_width).validate_dimensions()?;
+
+        Ok(HexGrid::new(registry, orientation, columns, rows, hextile_width))
+    }
+
+    /// Same as [`HexGrid::new()`], with hextiles sized by their radius (the distance from their center 
+    /// to their corners, i.e. half of `hextile_width`) instead of their width.
+    pub fn from_radius(
+        registry: &mut HexGridRegistry,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_radius: f32
+    ) -> Self {
+        HexGrid::new(registry, orientation, columns, rows, hextile_radius * 2.)
+    }
+
+    /// Same as [`HexGrid::from_radius()`], but returns a [`Bevy2dUtilError::InvalidGridDimensions`] 
+    /// if the grid would have invalid dimensions (see [`HexGrid::try_new()`]).
+    pub fn try_from_radius(
+        registry: &mut HexGridRegistry,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_radius: f32
+    ) -> Result<Self, Bevy2dUtilError> {
+        HexGrid::try_new(registry, orientation, columns, rows, hextile_radius * 2.)
+    }
+
+    /// Same as [`HexGrid::new()`], with regular hextiles sized by their pixel height on screen instead of their 
+    /// width: the distance across their flat sides for vertical grids, between their corners for horizontal ones.
+    pub fn from_height(
+        registry: &mut HexGridRegistry,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_height: f32
+    ) -> Self {
+        HexGrid::new(registry, orientation, columns, rows, hextile_width_from_height(orientation, hextile_height))
+    }
+
+    /// Same as [`HexGrid::from_height()`], but returns a [`Bevy2dUtilError::InvalidGridDimensions`] 
+    /// if the grid would have invalid dimensions (see [`HexGrid::try_new()`]).
+    pub fn try_from_height(
+        registry: &mut HexGridRegistry,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_height: f32
+    ) -> Result<Self, Bevy2dUtilError> {
+        HexGrid::try_new(registry, orientation, columns, rows, hextile_width_from_height(orientation, hextile_height))
+    }
 
+    /// Returns a [`HexGrid`] with a user-supplied `id`.
+    /// 
+    /// Stable ids keep referencing the same logical grid in save games, rollback networking, 
+    /// and tests, whatever order grids are created in. Use 
+    /// [`HexGridRegistry::allocate_id()`](super::registry::HexGridRegistry::allocate_id) 
+    /// for ids allocated deterministically per world instead.
+    /// 
+    /// Spawning two grids with the same id reports a [`Bevy2dUtilError::DuplicateGridId`], 
+    /// and only the first one is registered.
+    pub fn with_id(
+        id: u64,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_width: f32
+    ) -> Self {
         HexGrid {
             id,
             orientation,
             columns,
             rows,
-            hextile_width
+            hextile_width,
+            height_override: None,
+            spacing: 1.,
+            tile_gap: 0.,
+            anchor: HexGridAnchor::Center,
+            wrap_x: false,
+            wrap_y: false,
+        }
+    }
+
+    /// Same as [`HexGrid::with_id()`], but returns a [`Bevy2dUtilError::InvalidGridDimensions`] 
+    /// if the grid would have invalid dimensions (see [`HexGrid::validate_dimensions()`]).
+    pub fn try_with_id(
+        id: u64,
+        orientation: HexGridOrientation, 
+        columns: u32, 
+        rows: u32, 
+        hextile_width: f32
+    ) -> Result<Self, Bevy2dUtilError> {
+        let hexgrid = HexGrid::with_id(id, orientation, columns, rows, hextile_width);
+        hexgrid.validate_dimensions()?;
+
+        Ok(hexgrid)
+    }
+
+    /// Returns a [`Bevy2dUtilError::InvalidGridDimensions`] if this [`HexGrid`] has no tiles, more tiles 
+    /// than a `u32` can count, or a hextile width which is not a positive number.
+    pub fn validate_dimensions(&self) -> Result<(), Bevy2dUtilError> {
+        let (columns, rows, hextile_width) = (self.columns, self.rows, self.hextile_width);
+
+        if columns == 0 || rows == 0 || columns.checked_mul(rows).is_none() || !(hextile_width.is_finite() && hextile_width > 0.) {
+            return Err(Bevy2dUtilError::InvalidGridDimensions { columns, rows, hextile_width });
+        }
+        Ok(())
+    }
+
+    /// Returns this [`HexGrid`] with hextiles `hextile_height` pixels tall across their flat sides instead of 
+    /// `hextile_width * HEXTILE_HEIGHT_RATIO`, e.g. squashed hexagons for a stylized pseudo-3D look.
+    pub fn with_hextile_height(mut self, hextile_height: f32) -> Self {
+        self.height_override = Some(hextile_height);
+        self
+    }
+
+    /// Returns this [`HexGrid`] with the distances between the centers of tiles scaled by `spacing`, 
+    /// e.g. `1.1` to spread the tiles apart.
+    pub fn with_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns this [`HexGrid`] with seams `tile_gap` pixels wide between neighboring tiles, e.g. for board-game styles: 
+    /// tiles are moved apart (see [`HexTile::coord_to_world()`]) while their [`Sprite`]s keep the size of a hextile 
+    /// (see [`HexGrid::hextile_sprite_size()`]).
+    pub fn with_tile_gap(mut self, tile_gap: f32) -> Self {
+        self.tile_gap = tile_gap;
+        self
+    }
+
+    /// Returns this [`HexGrid`] placed relative to its [`Entity`] according to `anchor` instead of centered on it.
+    pub fn with_anchor(mut self, anchor: HexGridAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Returns the offset from the centered layout of the grid to its layout placed according to its [`HexGridAnchor`]
+    pub fn anchor_offset(&self) -> Vec2 {
+        let centered_bounds = || self.centered_bounds();
+
+        match self.anchor {
+            HexGridAnchor::Center => Vec2::ZERO,
+            HexGridAnchor::BottomLeft => -centered_bounds().min,
+            HexGridAnchor::BottomRight => {
+                let bounds = centered_bounds();
+                -Vec2::new(bounds.max.x, bounds.min.y)
+            },
+            HexGridAnchor::TopLeft => {
+                let bounds = centered_bounds();
+                -Vec2::new(bounds.min.x, bounds.max.y)
+            },
+            HexGridAnchor::TopRight => -centered_bounds().max,
+            HexGridAnchor::Offset(offset) => offset,
+        }
+    }
+
+    /// Returns the pixel height of the hextiles across their flat sides: `hextile_width * HEXTILE_HEIGHT_RATIO` 
+    /// for regular hexagons, unless overridden by [`HexGrid::with_hextile_height()`].
+    pub fn hextile_height(&self) -> f32 {
+        self.height_override.unwrap_or(self.hextile_width * HEXTILE_HEIGHT_RATIO)
+    }
+
+    /// Returns the distances between the centers of neighboring columns (`x`) and rows (`y`) of tiles
+    pub fn tile_step(&self) -> Vec2 {
+        let (width, height) = (self.hextile_width, self.hextile_height());
+
+        // Moving rows apart by the gap across the flat sides, columns must move apart by as much across the slanted sides
+        let slanted_side_normal = Vec2::new(height / 2., width / 4.).length();
+        let gap = Vec2::new(self.tile_gap * (2. * slanted_side_normal - width / 4.) / height, self.tile_gap);
+
+        let step = Vec2::new(width * 0.75, height) * self.spacing + gap;
+
+        match self.orientation {
+            HexGridOrientation::Vertical => step,
+            HexGridOrientation::Horizontal => step.yx(),
+        }
+    }
+
+    /// Returns the 6 corners of a hextile relative to its center, in the same order as [`hexagon_corners()`], 
+    /// taking the height override of the grid into account.
+    pub fn hextile_corners(&self) -> [Vec2; 6] {
+        let scale = self.hextile_scale();
+
+        hexagon_corners(self.orientation, self.hextile_width).map(|corner| corner * scale)
+    }
+
+    /// Returns the scale turning a regular hexagon of `hextile_width` into a hextile of this grid
+    pub(crate) fn hextile_scale(&self) -> Vec2 {
+        let flat_scale = self.hextile_height() / (self.hextile_width * HEXTILE_HEIGHT_RATIO);
+
+        match self.orientation {
+            HexGridOrientation::Vertical => Vec2::new(1., flat_scale),
+            HexGridOrientation::Horizontal => Vec2::new(flat_scale, 1.),
+        }
+    }
+
+    /// Returns this [`HexGrid`] wrapping around horizontally (`wrap_x`) and/or vertically (`wrap_y`): 
+    /// neighbor queries, pathfinding and world positioning then treat the map as a cylinder or torus.
+    /// 
+    /// The axis along which columns (vertical grids) or rows (horizontal grids) alternate 
+    /// should have an even amount of them, so that the shifted ones keep alternating across the seam.
+    pub fn with_wrapping(mut self, wrap_x: bool, wrap_y: bool) -> Self {
+        self.wrap_x = wrap_x;
+        self.wrap_y = wrap_y;
+        self
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the tile at possibly out of bounds coordinates, 
+    /// wrapped around the axes along which the grid wraps, or `None` if they lie outside of the grid.
+    pub fn wrap_coord(&self, x: i64, y: i64) -> Option<(u32, u32)> {
+        let x = if self.wrap_x { x.rem_euclid(self.columns.max(1) as i64) } else { x };
+        let y = if self.wrap_y { y.rem_euclid(self.rows.max(1) as i64) } else { y };
+
+        if x < 0 || y < 0 || x >= self.columns as i64 || y >= self.rows as i64 {
+            return None;
+        }
+
+        Some((x as u32, y as u32))
+    }
+
+    /// Returns the local distance after which the layout repeats itself along the axes the grid 
+    /// wraps around, `0` along the other ones.
+    pub fn wrap_period(&self) -> Vec2 {
+        // Horizontal grids are the transpose of vertical ones
+        let (straight, alternating, wrap_straight, wrap_alternating, step) = match self.orientation {
+            HexGridOrientation::Vertical => (self.rows, self.columns, self.wrap_y, self.wrap_x, self.tile_step()),
+            HexGridOrientation::Horizontal => (self.columns, self.rows, self.wrap_x, self.wrap_y, self.tile_step().yx()),
+        };
+        let period = Vec2::new(
+            if wrap_alternating { alternating as f32 * step.x } else { 0. },
+            if wrap_straight { straight as f32 * step.y } else { 0. },
+        );
+
+        match self.orientation {
+            HexGridOrientation::Vertical => period,
+            HexGridOrientation::Horizontal => period.yx(),
+        }
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the neighbors of the tile at `coord`, 
+    /// like [`HexTile::get_neighbors()`] but across the seams of a wrapping grid.
+    pub fn neighbors(&self, (x, y): (u32, u32)) -> Vec<(u32, u32)> {
+        if !self.wrap_x && !self.wrap_y {
+            return HexTile::new(x, y).get_neighbors(self.columns, self.rows, self.orientation);
+        }
+        let layout = OffsetLayout::from_orientation(self.orientation);
+        let axial = AxialCoord::from_offset(x as i32, y as i32, layout);
+
+        let mut neighbors = Vec::with_capacity(6);
+        for direction in AxialCoord::DIRECTIONS {
+            let (neighbor_x, neighbor_y) = (axial + direction).to_offset(layout);
+
+            // Tiny wrapping grids can reach the same neighbor twice, or the tile itself
+            if let Some(neighbor) = self.wrap_coord(neighbor_x as i64, neighbor_y as i64) {
+                if neighbor != (x, y) && !neighbors.contains(&neighbor) {
+                    neighbors.push(neighbor);
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// Returns the amount of steps between two tiles, like [`HexTile::distance()`] 
+    /// but taking the shortest way across the seams of a wrapping grid.
+    pub fn tile_distance(&self, a: (u32, u32), b: (u32, u32)) -> u32 {
+        let layout = OffsetLayout::from_orientation(self.orientation);
+        let from = AxialCoord::from_offset(a.0 as i32, a.1 as i32, layout);
+
+        let shifts_x: &[i32] = if self.wrap_x { &[-1, 0, 1] } else { &[0] };
+        let shifts_y: &[i32] = if self.wrap_y { &[-1, 0, 1] } else { &[0] };
+
+        let mut distance = u32::MAX;
+        for &shift_x in shifts_x {
+            for &shift_y in shifts_y {
+                let to = AxialCoord::from_offset(
+                    b.0 as i32 + shift_x * self.columns as i32,
+                    b.1 as i32 + shift_y * self.rows as i32,
+                    layout,
+                );
+                distance = distance.min(from.distance(to));
+            }
+        }
+        distance
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the tile with the given order, 
+    /// the inverse of [`HexTile::coord_to_order()`], or `None` if no tile of the grid has that order.
+    /// 
+    /// e.g. if `columns = 5` then `6 -> (col: 0, row: 1)`.
+    pub fn order_to_coord(&self, order: u32) -> Option<(u32, u32)> {
+        if order == 0 || self.columns == 0 {
+            return None;
+        }
+
+        let (x, y) = ((order - 1) % self.columns, (order - 1) / self.columns);
+
+        (y < self.rows).then_some((x, y))
+    }
+
+    /// Iterates over the `x` and `y` hexgrid coordinates of every tile of the grid in tile order 
+    /// (see [`HexTile::coord_to_order()`]): row by row from row `0`, each row from column `0`.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (u32, u32)> + use<> {
+        let columns = self.columns;
+
+        (0..self.rows).flat_map(move |y| (0..columns).map(move |x| (x, y)))
+    }
+
+    /// Iterates over the `x` and `y` hexgrid coordinates of the tiles of row `y` from column `0`, 
+    /// empty if the row is outside of the grid.
+    pub fn iter_row(&self, y: u32) -> impl Iterator<Item = (u32, u32)> + use<> {
+        let columns = if y < self.rows { self.columns } else { 0 };
+
+        (0..columns).map(move |x| (x, y))
+    }
+
+    /// Iterates over the `x` and `y` hexgrid coordinates of the tiles of column `x` from row `0`, 
+    /// empty if the column is outside of the grid.
+    pub fn iter_column(&self, x: u32) -> impl Iterator<Item = (u32, u32)> + use<> {
+        let rows = if x < self.columns { self.rows } else { 0 };
+
+        (0..rows).map(move |y| (x, y))
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the tile containing a position in world coordinates, 
+    /// or `None` if the position is outside of the grid, e.g. to find the tile under the cursor.
+    /// 
+    /// Positions beyond the seams of a wrapping grid land on the tiles they wrap around to.
+    /// 
+    /// `grid_transform` is the [`GlobalTransform`] of the [`Entity`] containing this [`HexGrid`],
+    /// which is assumed not to be rotated nor scaled.
+    pub fn world_to_coord(&self, grid_transform: &GlobalTransform, world_pos: Vec2) -> Option<(u32, u32)> {
+        let (col, row) = self.local_to_offset_coord(world_pos - grid_transform.translation().truncate());
+
+        self.wrap_coord(col, row)
+    }
+
+    /// Returns the offset coordinates of the hexagon containing a position relative to the grid [`Entity`], 
+    /// continuing the layout of the grid beyond its bounds
+    pub(crate) fn local_to_offset_coord(&self, local_pos: Vec2) -> (i64, i64) {
+        let origin = HexTile::new(0, 0).coord_to_world(self);
+
+        // Horizontal grids are the transpose of vertical ones
+        let (offset, step) = match self.orientation {
+            HexGridOrientation::Vertical => (local_pos - origin, self.tile_step()),
+            HexGridOrientation::Horizontal => ((local_pos - origin).yx(), self.tile_step().yx()),
+        };
+
+        // Fractional axial coordinates of "odd columns shifted up" flat-top hexagons
+        let q = offset.x / step.x;
+        let r = offset.y / step.y - q / 2.;
+
+        let (col, row) = AxialCoord::round(q, r).to_offset(OffsetLayout::OddQ);
+
+        match self.orientation {
+            HexGridOrientation::Vertical => (col as i64, row as i64),
+            HexGridOrientation::Horizontal => (row as i64, col as i64),
+        }
+    }
+
+    /// Returns the rectangle covered by all of the hexagons of the grid, relative to the grid [`Entity`]
+    pub fn local_bounds(&self) -> Rect {
+        let centered_bounds = self.centered_bounds();
+        if centered_bounds.is_empty() {
+            return centered_bounds;
+        }
+
+        let offset = self.anchor_offset();
+        Rect::from_corners(centered_bounds.min + offset, centered_bounds.max + offset)
+    }
+
+    /// Same as [`HexGrid::local_bounds()`] for a grid centered on its [`Entity`]
+    fn centered_bounds(&self) -> Rect {
+        let corners = self.hextile_corners();
+
+        // The outermost hexagons all lie in the first or last two columns and rows (odd ones being shifted)
+        let edge_indices = |count: u32| [0, 1, count.saturating_sub(2), count.saturating_sub(1)].into_iter().filter(move |&index| index < count);
+
+        let mut bounds = Rect::EMPTY;
+        for col in edge_indices(self.columns) {
+            for row in edge_indices(self.rows) {
+                let center = HexTile::new(col, row).centered_coord_to_world(self);
+
+                for corner in corners {
+                    bounds = bounds.union_point(center + corner);
+                }
+            }
+        }
+
+        bounds
+    }
+
+    /// Returns the axis-aligned rectangle in world coordinates covered by all of the hexagons of the grid, 
+    /// e.g. for camera clamping or culling.
+    /// 
+    /// `grid_transform` is the [`GlobalTransform`] of the [`Entity`] containing this [`HexGrid`], if it is 
+    /// rotated the rectangle encloses the rotated grid.
+    pub fn world_bounds(&self, grid_transform: &GlobalTransform) -> Rect {
+        let local_bounds = self.local_bounds();
+        if local_bounds.is_empty() {
+            return Rect::from_center_size(grid_transform.translation().truncate(), Vec2::ZERO);
+        }
+
+        [
+            local_bounds.min,
+            Vec2::new(local_bounds.max.x, local_bounds.min.y),
+            local_bounds.max,
+            Vec2::new(local_bounds.min.x, local_bounds.max.y),
+        ]
+            .into_iter()
+            .map(|corner| grid_transform.transform_point(corner.extend(0.)).truncate())
+            .fold(Rect::EMPTY, |bounds, corner| bounds.union_point(corner))
+    }
+
+    /// Returns the size of the [`Sprite`] of its hextiles: `hextile_width` wide for vertical grids, 
+    /// `hextile_width` tall for horizontal ones (whose textures are expected to be pointy-top hexagons).
+    pub fn hextile_sprite_size(&self) -> Vec2 {
+        let size = Vec2::new(self.hextile_width, self.hextile_height());
+
+        match self.orientation {
+            HexGridOrientation::Vertical => size,
+            HexGridOrientation::Horizontal => size.yx(),
         }
     }
 
@@ -219,14 +750,53 @@ impl HexGrid {
     /// The [`HexTile`] containing children entities of this [`Entity`] will not 
     /// be immediately spawned with a [`Sprite`] component.
     /// 
-    /// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+    /// Sends a [`HexTileSpawned`] event for every tile, then a [`HexGridSpawned`] event once all of 
+    /// the tiles have been spawned (if the events have been registered, e.g. by the [`GridsPlugin`](super::GridsPlugin)).
+    /// 
+    /// Reports a [`Bevy2dUtilError::InvalidGridDimensions`] instead of spawning anything if the 
+    /// [`HexGrid`] has invalid dimensions (see [`HexGrid::validate_dimensions()`]).
     /// 
-    /// Theoretically works just as well for the construction of horizontal hexgrids {untested!}.
+    /// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
     pub fn build_spawn_hexgrid_entity_system( self , hexgrid_translation: Vec3) -> impl FnMut( &mut World ) {
+        self.build_spawn_with_data_system(hexgrid_translation, |_, _| ())
+    }
+
+    /// Same as [`HexGrid::build_spawn_hexgrid_entity_system()`], but additionally inserts the 
+    /// component (or bundle) returned by `data` for the `x` and `y` hexgrid coordinates of each 
+    /// [`HexTile`] on its [`Entity`] as it is spawned, e.g. a terrain type or movement cost, 
+    /// instead of attaching it in a second pass.
+    /// 
+    /// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+    pub fn build_spawn_with_data_system<T: Bundle>(
+        self,
+        hexgrid_translation: Vec3,
+        data: impl Fn(u32, u32) -> T + Send + Sync + 'static,
+    ) -> impl FnMut( &mut World ) {
+        self.build_spawn_with_bundle_system(hexgrid_translation, move |hextile| data(hextile.x, hextile.y))
+    }
+
+    /// Same as [`HexGrid::build_spawn_hexgrid_entity_system()`], but additionally spawns each 
+    /// [`HexTile`] [`Entity`] with the [`Bundle`] returned by `bundle` for it, so extra components 
+    /// (colliders, markers, pickables, ...) are attached during the spawn instead of by patching 
+    /// the children afterwards.
+    /// 
+    /// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+    pub fn build_spawn_with_bundle_system<B: Bundle>(
+        self,
+        hexgrid_translation: Vec3,
+        bundle: impl Fn(&HexTile) -> B + Send + Sync + 'static,
+    ) -> impl FnMut( &mut World ) {
 
         move |    
             world: &mut World
         | {
+            if let Err(error) = self.validate_dimensions() {
+                report_in_world(world, error);
+                return;
+            }
+
+            let _span = info_span!("hexgrid_spawn", grid_id = self.id, tiles = self.columns * self.rows).entered();
+
             // Spawn the HexGrid containing Entity with the specified translation and get its id
             let parent_grid = world.spawn((
                 self.clone(),
@@ -236,29 +806,183 @@ impl HexGrid {
             ))
             .id();
 
+            let mut spawned_tiles = Vec::with_capacity((self.columns * self.rows) as usize);
+
             // Spawn (columns * rows) * HexTile containing entities
             world.entity_mut(parent_grid).with_children(|parent_builder| {
                 for col in 0..self.columns {
                     for row in 0..self.rows {
 
+                        let hextile = HexTile::new(col,row);
+                        let extra_bundle = bundle(&hextile);
+
                         // Spawn the HexTile entities as children of the HexGrid
-                        parent_builder.spawn(
-                            HexTile::new(col,row).get_bundle(
-                                self.orientation,
-                                self.columns,
-                                self.rows,
-                                self.hextile_width
-                            )
-                        );
+                        let tile_entity = parent_builder.spawn((
+                            hextile.get_bundle(&self),
+                            extra_bundle,
+                        ))
+                        .id();
+
+                        spawned_tiles.push(HexTileSpawned { grid_entity: parent_grid, tile_entity, coord: (col, row) });
                     }
                 }
             });
+
+            if world.contains_resource::<Events<HexTileSpawned>>() {
+                world.send_event_batch(spawned_tiles);
+            }
+            if world.contains_resource::<Events<HexGridSpawned>>() {
+                world.send_event(HexGridSpawned { grid_id: self.id, grid_entity: parent_grid });
+            }
         }
     }
+
+    /// Builds an 'exclusive' [`System`] closure which spawns the same entities as 
+    /// [`HexGrid::build_spawn_hexgrid_entity_system()`], but over several frames: the [`HexGrid`] 
+    /// containing [`Entity`] on the first run, then at most `tiles_per_frame` [`HexTile`]s per run, 
+    /// so huge maps don't cause a hitch on level load.
+    /// 
+    /// Sends a [`HexTileSpawned`] event for every tile as it is spawned, and a [`HexGridSpawned`] event once 
+    /// all of the tiles have been spawned (if the events have been registered), after which the system does nothing. Stops early if the grid is despawned meanwhile.
+    /// 
+    /// Reports a [`Bevy2dUtilError::InvalidGridDimensions`] once instead of spawning anything if the 
+    /// [`HexGrid`] has invalid dimensions (see [`HexGrid::validate_dimensions()`]).
+    /// 
+    /// Should be added to the [`Update`] schedule, e.g. with a run condition on the loading state.
+    pub fn build_spawn_hexgrid_incrementally_system(self, hexgrid_translation: Vec3, tiles_per_frame: u32) -> impl FnMut( &mut World ) {
+        let dimensions_error = self.validate_dimensions().err();
+        let mut dimensions_reported = false;
+
+        let tile_count = self.columns.saturating_mul(self.rows);
+        let tiles_per_frame = tiles_per_frame.max(1);
+
+        let mut parent_grid: Option<Entity> = None;
+        let mut spawned_tiles = 0;
+
+        move | world: &mut World | {
+            if let Some(error) = &dimensions_error {
+                if !dimensions_reported {
+                    report_in_world(world, error.clone());
+                    dimensions_reported = true;
+                }
+                return;
+            }
+
+            if spawned_tiles >= tile_count && parent_grid.is_some() {
+                return;
+            }
+
+            let parent_grid = *parent_grid.get_or_insert_with(|| {
+                world.spawn((
+                    self.clone(),
+                    Transform::from_translation(hexgrid_translation),
+                    Visibility::Hidden,
+                ))
+                .id()
+            });
+
+            let Ok(mut grid_entity) = world.get_entity_mut(parent_grid) else {
+                spawned_tiles = tile_count;
+                return;
+            };
+
+            let _span = info_span!("hexgrid_spawn_incremental", grid_id = self.id, tiles = spawned_tiles).entered();
+
+            let batch_end = (spawned_tiles + tiles_per_frame).min(tile_count);
+
+            let mut batch = Vec::with_capacity((batch_end - spawned_tiles) as usize);
+
+            // Same order as `build_spawn_hexgrid_entity_system`: column by column
+            grid_entity.with_children(|parent_builder| {
+                for tile in spawned_tiles..batch_end {
+                    let coord = (tile / self.rows, tile % self.rows);
+                    let tile_entity = parent_builder.spawn(
+                        HexTile::new(coord.0, coord.1).get_bundle(&self)
+                    )
+                    .id();
+
+                    batch.push(HexTileSpawned { grid_entity: parent_grid, tile_entity, coord });
+                }
+            });
+            spawned_tiles = batch_end;
+
+            if world.contains_resource::<Events<HexTileSpawned>>() {
+                world.send_event_batch(batch);
+            }
+
+            if spawned_tiles >= tile_count && world.contains_resource::<Events<HexGridSpawned>>() {
+                world.send_event(HexGridSpawned { grid_id: self.id, grid_entity: parent_grid });
+            }
+        }
+    }
+
+    /// Builds an 'exclusive' [`System`] closure which despawns the [`Entity`] containing the [`HexGrid`] 
+    /// with the given id together with all of its [`HexTile`] children, e.g. when tearing down a level.
+    /// 
+    /// The [`HexGridRegistry`](super::registry::HexGridRegistry) and [`HexTileIndex`](super::registry::HexTileIndex) 
+    /// are updated as the entities are despawned, the [`HoveredHexTile`](super::picking::HoveredHexTile) is cleared 
+    /// if it was on this grid, and streamed chunks of the grid are forgotten.
+    /// 
+    /// Reports a [`Bevy2dUtilError::GridNotFound`] if no such grid is spawned.
+    /// 
+    /// Can be used as a one-shot system.
+    pub fn build_despawn_hexgrid_entity_system(grid_id: u64) -> impl FnMut(&mut World) {
+        move | world: &mut World | {
+            let grid_entity = match world.get_resource::<HexGridRegistry>() {
+                Some(registry) => registry.get(grid_id),
+                None => world
+                    .query::<(Entity, &HexGrid)>()
+                    .iter(world)
+                    .find(|(_, hexgrid)| hexgrid.id == grid_id)
+                    .map(|(grid_entity, _)| grid_entity),
+            };
+
+            let Some(grid_entity) = grid_entity else {
+                report_in_world(world, Bevy2dUtilError::GridNotFound { grid_id });
+                return;
+            };
+
+            // Despawns the tiles along with the grid
+            world.despawn(grid_entity);
+
+            if let Some(mut hovered_hextile) = world.get_resource_mut::<HoveredHexTile>() {
+                if hovered_hextile.hovered.is_some_and(|pick| pick.grid_id == grid_id) {
+                    hovered_hextile.hovered = None;
+                }
+            }
+
+            #[cfg(feature = "streaming")]
+            if let Some(mut streams) = world.get_resource_mut::<super::streaming::HexChunkStreams>() {
+                streams.forget_grid(grid_id);
+            }
+        }
+    }
+}
+
+/// An [`Event`] sent once a [`HexGrid`] [`Entity`] and all of its [`HexTile`] children have been spawned.
+#[derive(Event, Clone, Copy)]
+pub struct HexGridSpawned {
+    pub grid_id: u64,
+    pub grid_entity: Entity,
 }
 
-/// Allows either a single path, or multiple paths towards images to be used for textures.
+/// An [`Event`] sent for every [`HexTile`] [`Entity`] spawned by the grid spawning systems (including the 
+/// incremental, tilemap and streaming ones), so other systems can attach colliders, AI data, etc. 
+/// without scanning the children of the grid afterwards.
+/// 
+/// Sent before the [`HexGridSpawned`] event of the grid. Observers of `Trigger<OnAdd, HexTile>` 
+/// can be used instead to react as soon as each tile is spawned.
+#[derive(Event, Clone, Copy)]
+pub struct HexTileSpawned {
+    pub grid_entity: Entity,
+    pub tile_entity: Entity,
+    pub coord: (u32, u32),
+}
+
+/// Allows either a single path, or multiple paths towards images to be used for textures, 
+/// or indices into a single sprite sheet described by a [`TextureAtlasLayout`], optionally animated.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TileTextures {
     /// Must contain path towards desired image
     Single(String),
@@ -268,7 +992,206 @@ pub enum TileTextures {
     /// `.0` contains a vector with a collection of tuples in which `.1` is a 
     /// path towards a texture image, and `.0` is a vector with a collection of 
     /// ranges in hexgrid tile order notation (see [`HexTile::coord_to_order()`])
-    Multiple(Vec<(Vec<(u32, u32)>, String)>, String)
+    Multiple(Vec<(Vec<(u32, u32)>, String)>, String),
+
+    /// Like [`TileTextures::Multiple`], with the textured regions given directly in `x` and `y` 
+    /// hexgrid coordinates (see [`TileRegion`]) rather than in tile order notation.
+    /// 
+    /// In `.1` it contains the default texture path, to which all tiles outside of the regions in `.0` will be set
+    Regions(Vec<(Vec<TileRegion>, String)>, String),
+
+    /// Every tile picks one of the texture paths in `.0` at random, each with a probability 
+    /// proportional to its weight, e.g. `vec![("grass.png".into(), 3.0), ("dirt.png".into(), 1.0)]`.
+    /// 
+    /// The pick only depends on the seed in `.1` and the tile coordinates, so the same seed always yields the same board.
+    Random(Vec<(String, f32)>, u64),
+
+    /// Every tile takes the texture returned by the closure for its `x` and `y` hexgrid coordinates, 
+    /// so textures can be driven by the caller's own world data (noise, saved maps...).
+    /// 
+    /// Cannot be serialized, as it holds a closure.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Procedural(Arc<dyn Fn(u32, u32) -> TextureChoice + Send + Sync>),
+
+    /// Every tile draws the same texture of a sprite sheet.
+    /// 
+    /// Cannot be serialized, as it holds a [`Handle`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AtlasSingle {
+        /// Path towards the sprite sheet image
+        image: String,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    },
+
+    /// Like [`TileTextures::Multiple`], with atlas indices into a single sprite sheet instead of 
+    /// image paths, so all tiles are drawn from one image (and batched together).
+    /// 
+    /// Cannot be serialized, as it holds a [`Handle`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AtlasMultiple {
+        /// Path towards the sprite sheet image
+        image: String,
+        layout: Handle<TextureAtlasLayout>,
+
+        /// Ranges in hexgrid tile order notation (see [`HexTile::coord_to_order()`]) and the atlas index of their tiles
+        ranges: Vec<(Vec<(u32, u32)>, usize)>,
+
+        /// The atlas index of tiles in none of the `ranges`
+        default_index: usize,
+    },
+
+    /// Like [`TileTextures::AtlasMultiple`], with the tiles in `ranges` animated through frames 
+    /// of the sprite sheet by a [`TileAnimation`] (e.g. water or lava), and the other tiles static.
+    /// 
+    /// Cannot be serialized, as it holds a [`Handle`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AtlasAnimated {
+        /// Path towards the sprite sheet image
+        image: String,
+        layout: Handle<TextureAtlasLayout>,
+
+        /// Ranges in hexgrid tile order notation (see [`HexTile::coord_to_order()`]) and the animation of their tiles
+        ranges: Vec<(Vec<(u32, u32)>, TileAnimation)>,
+
+        /// The atlas index of tiles in none of the `ranges`
+        default_index: usize,
+    },
+}
+
+/// A set of tiles of a [`HexGrid`], in `x` and `y` hexgrid coordinates, used by [`TileTextures::Regions`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TileRegion {
+    /// A single tile
+    Tile(u32, u32),
+
+    /// Every tile whose coordinates lie between `min` and `max`, both included
+    Rect { min: (u32, u32), max: (u32, u32) },
+}
+
+impl TileRegion {
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        match *self {
+            TileRegion::Tile(tile_x, tile_y) => x == tile_x && y == tile_y,
+            TileRegion::Rect { min, max } => x >= min.0 && x <= max.0 && y >= min.1 && y <= max.1,
+        }
+    }
+}
+
+/// The texture [`TileTextures`] assigns to a single tile.
+#[derive(Clone, PartialEq, Debug)]
+pub enum TileTexture<'a> {
+    /// Path towards an image
+    Image(Cow<'a, str>),
+
+    /// Path towards a sprite sheet image, and the index of the texture in its atlas
+    Atlas { image: Cow<'a, str>, layout: Cow<'a, Handle<TextureAtlasLayout>>, index: usize },
+}
+
+/// A texture returned by the closure of [`TileTextures::Procedural`], e.g. `TileTexture::Image("grass.png".into())`
+pub type TextureChoice = TileTexture<'static>;
+
+impl TileTextures {
+    /// Returns the texture assigned to `hextile`, in a grid with the given amount of `columns`.
+    /// 
+    /// When the tile lies in several ranges or regions, the last one wins.
+    pub fn texture_for_tile(&self, hextile: &HexTile, columns: u32) -> TileTexture<'_> {
+        let order_pos = hextile.coord_to_order(columns);
+        let in_ranges = |ranges: &Vec<(u32, u32)>| ranges.iter().any(|&(start, end)| order_pos >= start && order_pos <= end);
+
+        match self {
+            TileTextures::Single(path) => TileTexture::Image(path.into()),
+            TileTextures::Multiple(ranges_and_paths, default_path) => TileTexture::Image(ranges_and_paths
+                .iter()
+                .rev()
+                .find(|(ranges, _)| in_ranges(ranges))
+                .map(|(_, path)| path.as_str())
+                .unwrap_or(default_path)
+                .into()),
+            TileTextures::Regions(regions_and_paths, default_path) => TileTexture::Image(regions_and_paths
+                .iter()
+                .rev()
+                .find(|(regions, _)| regions.iter().any(|region| region.contains(hextile.x, hextile.y)))
+                .map(|(_, path)| path.as_str())
+                .unwrap_or(default_path)
+                .into()),
+            TileTextures::Random(paths_and_weights, seed) => {
+                let mut state = seed ^ (hextile.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ (hextile.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+                if state == 0 {
+                    state = 0x2545_F491_4F6C_DD1D;
+                }
+                next_random(&mut state);
+
+                let total_weight: f32 = paths_and_weights.iter().map(|(_, weight)| weight.max(0.)).sum();
+                let mut pick = next_random(&mut state) * total_weight;
+
+                TileTexture::Image(paths_and_weights
+                    .iter()
+                    .find(|(_, weight)| {
+                        pick -= weight.max(0.);
+                        pick < 0.
+                    })
+                    .or(paths_and_weights.last())
+                    .map_or("", |(path, _)| path.as_str())
+                    .into())
+            },
+            TileTextures::Procedural(choose_texture) => choose_texture(hextile.x, hextile.y),
+            TileTextures::AtlasSingle { image, layout, index } => TileTexture::Atlas { image: image.into(), layout: Cow::Borrowed(layout), index: *index },
+            TileTextures::AtlasMultiple { image, layout, ranges, default_index } => TileTexture::Atlas {
+                image: image.into(),
+                layout: Cow::Borrowed(layout),
+                index: ranges
+                    .iter()
+                    .rev()
+                    .find(|(ranges, _)| in_ranges(ranges))
+                    .map_or(*default_index, |&(_, index)| index),
+            },
+            TileTextures::AtlasAnimated { image, layout, ranges, default_index } => TileTexture::Atlas {
+                image: image.into(),
+                layout: Cow::Borrowed(layout),
+                index: ranges
+                    .iter()
+                    .rev()
+                    .find(|(ranges, _)| in_ranges(ranges))
+                    .and_then(|(_, animation)| animation.frames.first().copied())
+                    .unwrap_or(*default_index),
+            },
+        }
+    }
+
+    /// Returns the [`TileAnimation`] assigned to `hextile`, in a grid with the given amount of `columns`, if any.
+    /// 
+    /// When the tile lies in several ranges, the last one wins.
+    pub fn animation_for_tile(&self, hextile: &HexTile, columns: u32) -> Option<&TileAnimation> {
+        let TileTextures::AtlasAnimated { ranges, .. } = self else { return None; };
+        let order_pos = hextile.coord_to_order(columns);
+
+        ranges
+            .iter()
+            .rev()
+            .find(|(ranges, _)| ranges.iter().any(|&(start, end)| order_pos >= start && order_pos <= end))
+            .map(|(_, animation)| animation)
+    }
+}
+
+impl TileTexture<'_> {
+    /// Returns a [`Sprite`] of the given size drawing this texture
+    pub fn to_sprite(&self, asset_server: &AssetServer, size: Vec2) -> Sprite {
+        match self {
+            TileTexture::Image(path) => Sprite {
+                custom_size: Some(size),
+                image: asset_server.load(&**path),
+                ..Default::default()
+            },
+            TileTexture::Atlas { image, layout, index } => Sprite {
+                custom_size: Some(size),
+                image: asset_server.load(&**image),
+                texture_atlas: Some(TextureAtlas { layout: layout.clone().into_owned(), index: *index }),
+                ..Default::default()
+            },
+        }
+    }
 }
 
 /// Builds a [`System`] closure which inserts or rewrites a [`Sprite`] component configured
@@ -283,8 +1206,6 @@ pub enum TileTextures {
 /// 
 /// Can be added to the [`Startup`] schedule if set to run after the entities have 
 /// been spawned, also can be used as a one-shot system.
-/// 
-/// Theoretically works just as well for the construction of horizontal hexgrids {untested!}.
 pub fn build_change_hexgrid_textures_system(
     textures_configs: TileTextures,
     grid_id: u64,
@@ -292,7 +1213,8 @@ pub fn build_change_hexgrid_textures_system(
     Commands,
     Res<AssetServer>,
     Query<(&Children, &HexGrid)>,
-    Query<&HexTile>
+    Query<&HexTile>,
+    ErrorReporter
 ) {
 
     let textures_configs = textures_configs.clone();
@@ -301,57 +1223,39 @@ pub fn build_change_hexgrid_textures_system(
         mut commands: Commands,
         asset_server: Res<AssetServer>, 
         children_query: Query<(&Children, &HexGrid)>,
-        hextile_query: Query<&HexTile>
+        hextile_query: Query<&HexTile>,
+        mut error_reporter: ErrorReporter
     | {
+        let _span = info_span!("hexgrid_apply_textures", grid_id).entered();
+
+        let mut grid_found = false;
+
         for (children, hexgrid) in &children_query {
 
             if hexgrid.id != grid_id {
                 continue;
             }
-
-            let mut texture = match &textures_configs {
-                TileTextures::Single(path) => asset_server.load(path),
-                TileTextures::Multiple( _ , default_path ) => asset_server.load(default_path)
-            };
+            grid_found = true;
 
             for &child in children {
                 if let Ok(hextile) = hextile_query.get(child){
                 
-                    let order_pos = hextile.coord_to_order(hexgrid.columns);
+                    let texture = textures_configs.texture_for_tile(hextile, hexgrid.columns);
 
-                    if let TileTextures::Multiple(ranges_and_paths, _ ) = &textures_configs {
+                    let mut tile_commands = commands.entity(child);
+                    tile_commands.insert(texture.to_sprite(&asset_server, hexgrid.hextile_sprite_size()));
 
-                        if let Some((_, path)) = ranges_and_paths
-                        .iter()
-                        .find(|(ranges, _ )| ranges
-                            .iter()
-                            .any(|&(start, end)| order_pos >= start && order_pos <= end)) {
-                            texture = asset_server.load(path);
-                        }
-
-
-                        for (ranges, path) in ranges_and_paths {
-                            for range in ranges {
-                                if order_pos >= range.0 && order_pos <= range.1 {
-                                    texture = asset_server.load(path); 
-                                }
-                            }
-                        }
-                    }
-
-                    commands.entity(child).insert((
-                        Sprite {
-                            custom_size: Some(Vec2::new(
-                                hexgrid.hextile_width, 
-                                hexgrid.hextile_width * 0.866
-                            )),
-                            image: texture.clone(),
-                            ..Default::default()
-                        },
-                    ));
+                    match textures_configs.animation_for_tile(hextile, hexgrid.columns) {
+                        Some(animation) => tile_commands.insert(animation.clone()),
+                        None => tile_commands.remove::<TileAnimation>(),
+                    };
                 }
             }
         } 
+
+        if !grid_found {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+        }
     }
 }
 
@@ -361,12 +1265,13 @@ pub fn build_change_hexgrid_textures_system(
 /// 
 /// Used for changing the spites of specieif [`HexTile`] containing entities. Only tiles at the specified coordinates will be altered 
 /// 
+/// Reports a [`Bevy2dUtilError::GridNotFound`] if no such grid is spawned, and a 
+/// [`Bevy2dUtilError::TileOutOfBounds`] for every coordinate outside of it.
+/// 
 /// (If you need to change the texture of all [`HexTile`] entities that are children of a given [`HexGrid`] entity, use
 /// [`build_change_hexgrid_textures_system()`]).
 /// 
 /// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
-/// 
-/// Theoretically works just as well for the construction of horizontal hexgrids {untested!}.
 pub fn build_change_hextile_textures_system(
     grid_id: u64,
     texture_path: &str,
@@ -376,6 +1281,7 @@ pub fn build_change_hextile_textures_system(
     Res<AssetServer>, 
     Query<(&Children, &HexGrid)>,
     Query<&HexTile>,
+    ErrorReporter,
 ) {
     let texture_path = texture_path.to_string();
 
@@ -383,22 +1289,34 @@ pub fn build_change_hextile_textures_system(
         mut commands: Commands,
         asset_server: Res<AssetServer>, 
         children_query: Query<(&Children, &HexGrid)>,
-        hextile_query: Query<&HexTile>
+        hextile_query: Query<&HexTile>,
+        mut error_reporter: ErrorReporter
     | {
+        let _span = info_span!("hextile_apply_textures", grid_id, tiles = hextiles_coords.len()).entered();
+
         let texture =  asset_server.load(&texture_path);
 
+        let mut grid_found = false;
+
         for (children, hexgrid) in &children_query {
 
             if hexgrid.id != grid_id {
                 continue;
             }
+            grid_found = true;
+
+            for &(x, y) in &hextiles_coords {
+                if x >= hexgrid.columns || y >= hexgrid.rows {
+                    error_reporter.report(Bevy2dUtilError::TileOutOfBounds { grid_id, coord: (x, y) });
+                }
+            }
 
             for &child in children {
                 if let Ok(hextile) = hextile_query.get(child) {
                     if hextiles_coords.contains(&(hextile.x, hextile.y)) {
                         commands.entity(child).insert((
                             Sprite {
-                                custom_size: Some(Vec2::new(hexgrid.hextile_width, hexgrid.hextile_width * 0.866)),
+                                custom_size: Some(hexgrid.hextile_sprite_size()),
                                 image: texture.clone(),
                                 ..Default::default()
                             },
@@ -407,5 +1325,312 @@ pub fn build_change_hextile_textures_system(
                 }
             }
         }
+
+        if !grid_found {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+        }
+    }
+}
+
+/// Reports a [`Bevy2dUtilError::TextureNotFound`] for every image used by a [`HexTile`] [`Sprite`] which failed to load
+pub(crate) fn report_failed_tile_textures(
+    mut failed_reader: EventReader<AssetLoadFailedEvent<Image>>,
+    hextile_sprites: Query<&Sprite, With<HexTile>>,
+    mut error_reporter: ErrorReporter,
+) {
+    for failed in failed_reader.read() {
+        if hextile_sprites.iter().any(|sprite| sprite.image.id() == failed.id) {
+            error_reporter.report(Bevy2dUtilError::TextureNotFound {
+                path: failed.path.to_string(),
+                reason: failed.error.to_string(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIENTATIONS: [HexGridOrientation; 2] = [HexGridOrientation::Vertical, HexGridOrientation::Horizontal];
+
+    #[test]
+    fn world_to_coord_round_trips_tile_centers() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 7, 5, 64.);
+            let grid_transform = GlobalTransform::from_translation(Vec3::new(120., -45., 0.));
+
+            for x in 0..hexgrid.columns {
+                for y in 0..hexgrid.rows {
+                    let center = HexTile::new(x, y).coord_to_world(&hexgrid) 
+                        + grid_transform.translation().truncate();
+
+                    // Points within the circle inscribed in the hexagon belong to it
+                    for step in 0..12 {
+                        let offset = Vec2::from_angle(step as f32 * std::f32::consts::TAU / 12.) * hexgrid.hextile_width * 0.866 * 0.45;
+                        assert_eq!(hexgrid.world_to_coord(&grid_transform, center + offset), Some((x, y)), "({x}, {y})");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn world_to_coord_rejects_positions_outside_of_the_grid() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 4, 4, 32.);
+            let center = |x, y| HexTile::new(x, y).coord_to_world(&hexgrid);
+
+            assert_eq!(hexgrid.world_to_coord(&GlobalTransform::IDENTITY, center(3, 3) + Vec2::splat(64.)), None);
+            assert_eq!(hexgrid.world_to_coord(&GlobalTransform::IDENTITY, center(0, 0) - Vec2::splat(64.)), None);
+        }
+    }
+
+    #[test]
+    fn try_with_id_rejects_invalid_dimensions() {
+        assert!(HexGrid::try_with_id(0, HexGridOrientation::Vertical, 3, 2, 32.).is_ok());
+
+        for (columns, rows, hextile_width) in [(0, 2, 32.), (3, 0, 32.), (u32::MAX, 2, 32.), (3, 2, 0.), (3, 2, -32.), (3, 2, f32::NAN)] {
+            let error = HexGrid::try_with_id(0, HexGridOrientation::Vertical, columns, rows, hextile_width).err();
+
+            assert!(matches!(error, Some(Bevy2dUtilError::InvalidGridDimensions { .. })), "{columns} x {rows}, {hextile_width}");
+        }
+    }
+
+    #[test]
+    fn spawning_an_invalid_hexgrid_reports_and_spawns_nothing() {
+        let mut world = World::new();
+        world.init_resource::<Events<Bevy2dUtilError>>();
+
+        let mut spawn_hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 0, 4, 32.).build_spawn_hexgrid_entity_system(Vec3::ZERO);
+        spawn_hexgrid(&mut world);
+
+        assert_eq!(world.query::<&HexGrid>().iter(&world).count(), 0);
+        assert_eq!(world.query::<&HexTile>().iter(&world).count(), 0);
+
+        let errors: Vec<Bevy2dUtilError> = world.resource_mut::<Events<Bevy2dUtilError>>().drain().collect();
+        assert_eq!(errors, vec![Bevy2dUtilError::InvalidGridDimensions { columns: 0, rows: 4, hextile_width: 32. }]);
+    }
+
+    #[test]
+    fn incremental_spawning_is_budgeted_per_run() {
+        let mut world = World::new();
+        world.init_resource::<Events<Bevy2dUtilError>>();
+
+        let mut spawn_hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 3, 3, 32.).build_spawn_hexgrid_incrementally_system(Vec3::ZERO, 4);
+        for expected_tiles in [4, 8, 9, 9] {
+            spawn_hexgrid(&mut world);
+            assert_eq!(world.query::<&HexTile>().iter(&world).count(), expected_tiles);
+        }
+        assert_eq!(world.query::<&HexGrid>().iter(&world).count(), 1);
+
+        let mut spawn_invalid_hexgrid = HexGrid::with_id(1, HexGridOrientation::Vertical, 3, 3, 0.).build_spawn_hexgrid_incrementally_system(Vec3::ZERO, 4);
+        spawn_invalid_hexgrid(&mut world);
+        spawn_invalid_hexgrid(&mut world);
+
+        assert_eq!(world.query::<&HexGrid>().iter(&world).count(), 1);
+        assert_eq!(world.resource_mut::<Events<Bevy2dUtilError>>().drain().count(), 1);
+    }
+
+    #[test]
+    fn wrapping_grids_connect_their_seams() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 6, 4, 32.).with_wrapping(true, true);
+
+            assert_eq!(hexgrid.wrap_coord(-1, 4), Some((5, 0)));
+            assert_eq!(hexgrid.wrap_coord(13, -5), Some((1, 3)));
+
+            for x in 0..hexgrid.columns {
+                for y in 0..hexgrid.rows {
+                    let neighbors = hexgrid.neighbors((x, y));
+
+                    assert_eq!(neighbors.len(), 6, "({x}, {y})");
+                    assert!(neighbors.iter().all(|&neighbor| hexgrid.tile_distance((x, y), neighbor) == 1));
+                    assert!(neighbors.iter().all(|&neighbor| hexgrid.neighbors(neighbor).contains(&(x, y))));
+                }
+            }
+
+            assert_eq!(hexgrid.tile_distance((0, 0), (5, 0)), 1);
+            assert_eq!(hexgrid.find_path((0, 1), (5, 1), &HashSet::new()).map(|path| path.len()), Some(2));
+        }
+    }
+
+    #[test]
+    fn non_wrapping_axes_keep_their_edges() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 6, 4, 32.).with_wrapping(true, false);
+
+        assert_eq!(hexgrid.wrap_coord(-1, 0), Some((5, 0)));
+        assert_eq!(hexgrid.wrap_coord(0, -1), None);
+        assert_eq!(hexgrid.wrap_coord(0, 4), None);
+        assert_eq!(hexgrid.neighbors((0, 0)), vec![(1, 0), (5, 0), (0, 1)]);
+        assert_eq!(hexgrid.tile_distance((0, 0), (0, 3)), 3);
+        assert_eq!(hexgrid.wrap_period(), Vec2::new(6. * 32. * 0.75, 0.));
+    }
+
+    #[test]
+    fn world_to_coord_wraps_positions_beyond_the_seams() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 6, 4, 32.).with_wrapping(true, true);
+            let period = hexgrid.wrap_period();
+
+            for (x, y) in [(0, 0), (5, 3), (2, 1)] {
+                let center = HexTile::new(x, y).coord_to_world(&hexgrid);
+
+                for shift in [Vec2::new(period.x, 0.), Vec2::new(0., -period.y), period] {
+                    assert_eq!(hexgrid.world_to_coord(&GlobalTransform::IDENTITY, center + shift), Some((x, y)), "({x}, {y}) + {shift}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn regions_assign_textures_by_coordinates() {
+        let textures = TileTextures::Regions(vec![
+            (vec![TileRegion::Rect { min: (1, 1), max: (3, 2) }], "sand.png".to_string()),
+            (vec![TileRegion::Tile(2, 2), TileRegion::Tile(0, 4)], "rock.png".to_string()),
+        ], "grass.png".to_string());
+        let texture_at = |x, y| textures.texture_for_tile(&HexTile::new(x, y), 5);
+
+        assert_eq!(texture_at(1, 1), TileTexture::Image("sand.png".into()));
+        assert_eq!(texture_at(3, 2), TileTexture::Image("sand.png".into()));
+        assert_eq!(texture_at(0, 4), TileTexture::Image("rock.png".into()));
+        assert_eq!(texture_at(4, 2), TileTexture::Image("grass.png".into()));
+        assert_eq!(texture_at(1, 3), TileTexture::Image("grass.png".into()));
+
+        // The last matching region wins
+        assert_eq!(texture_at(2, 2), TileTexture::Image("rock.png".into()));
+    }
+
+    #[test]
+    fn random_textures_are_seeded_and_weighted() {
+        fn board(textures: &TileTextures) -> Vec<TileTexture<'_>> {
+            (0..40).flat_map(|x| (0..40).map(move |y| textures.texture_for_tile(&HexTile::new(x, y), 40))).collect()
+        }
+        let weights = vec![("grass.png".to_string(), 3.), ("dirt.png".to_string(), 1.), ("lava.png".to_string(), 0.)];
+        let (seeded, reseeded, other_seed) = (
+            TileTextures::Random(weights.clone(), 7),
+            TileTextures::Random(weights.clone(), 7),
+            TileTextures::Random(weights, 8),
+        );
+
+        assert_eq!(board(&seeded), board(&reseeded));
+        assert_ne!(board(&seeded), board(&other_seed));
+
+        let board = board(&seeded);
+        let grass = board.iter().filter(|&texture| *texture == TileTexture::Image("grass.png".into())).count();
+        assert!(!board.contains(&TileTexture::Image("lava.png".into())));
+        assert!((1100..1300).contains(&grass), "{grass} grass tiles out of 1600");
+    }
+
+    #[test]
+    fn local_bounds_enclose_every_hexagon() {
+        for orientation in ORIENTATIONS {
+            for (columns, rows) in [(1, 1), (2, 3), (7, 5), (6, 1)] {
+                let hexgrid = HexGrid::with_id(0, orientation, columns, rows, 32.);
+                let corners = hexgrid.hextile_corners();
+
+                let mut expected = Rect::EMPTY;
+                for x in 0..columns {
+                    for y in 0..rows {
+                        let center = HexTile::new(x, y).coord_to_world(&hexgrid);
+                        for corner in corners {
+                            expected = expected.union_point(center + corner);
+                        }
+                    }
+                }
+
+                assert_eq!(hexgrid.local_bounds(), expected, "{columns} x {rows}");
+            }
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn world_bounds_follow_the_grid_transform() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 7, 5, 32.);
+        let local_bounds = hexgrid.local_bounds();
+
+        let translation = Vec3::new(100., -20., 3.);
+        let world_bounds = hexgrid.world_bounds(&GlobalTransform::from_translation(translation));
+        assert_eq!(world_bounds.center(), local_bounds.center() + translation.truncate());
+        assert_eq!(world_bounds.size(), local_bounds.size());
+
+        let rotated_bounds = hexgrid.world_bounds(&GlobalTransform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)));
+        assert!(rotated_bounds.size().abs_diff_eq(local_bounds.size().yx(), 1e-3));
+    }
+
+    #[test]
+    fn radius_and_height_constructors_size_the_hextiles() {
+        let mut registry = HexGridRegistry::default();
+
+        assert_eq!(HexGrid::from_radius(&mut registry, HexGridOrientation::Vertical, 3, 3, 20.).hextile_width, 40.);
+
+        let vertical = HexGrid::from_height(&mut registry, HexGridOrientation::Vertical, 3, 3, 50.);
+        assert!((vertical.hextile_height() - 50.).abs() < 1e-4);
+        assert!((vertical.hextile_sprite_size().y - 50.).abs() < 1e-4);
+
+        let horizontal = HexGrid::from_height(&mut registry, HexGridOrientation::Horizontal, 3, 3, 50.);
+        assert_eq!(horizontal.hextile_sprite_size().y, 50.);
+
+        assert!(HexGrid::try_from_radius(&mut registry, HexGridOrientation::Vertical, 3, 3, -1.).is_err());
+        assert!(HexGrid::try_from_height(&mut registry, HexGridOrientation::Horizontal, 3, 0, 50.).is_err());
+        assert_eq!(HexGrid::try_from_height(&mut registry, HexGridOrientation::Horizontal, 3, 3, 50.).map(|hexgrid| hexgrid.id), Ok(4));
+    }
+
+    #[test]
+    fn anchors_move_the_grid_onto_its_entity() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 7, 5, 32.);
+            let centered_bounds = hexgrid.local_bounds();
+            let anchored = |anchor| hexgrid.clone().with_anchor(anchor);
+
+            assert!(anchored(HexGridAnchor::BottomLeft).local_bounds().min.abs_diff_eq(Vec2::ZERO, 1e-3));
+            assert!(anchored(HexGridAnchor::TopRight).local_bounds().max.abs_diff_eq(Vec2::ZERO, 1e-3));
+
+            let bottom_right = anchored(HexGridAnchor::BottomRight).local_bounds();
+            assert!(Vec2::new(bottom_right.max.x, bottom_right.min.y).abs_diff_eq(Vec2::ZERO, 1e-3));
+            let top_left = anchored(HexGridAnchor::TopLeft).local_bounds();
+            assert!(Vec2::new(top_left.min.x, top_left.max.y).abs_diff_eq(Vec2::ZERO, 1e-3));
+
+            // Tiles and world positions move along with the bounds
+            let offset = Vec2::new(40., -25.);
+            let shifted = anchored(HexGridAnchor::Offset(offset));
+            assert_eq!(shifted.local_bounds().size(), centered_bounds.size());
+            for (x, y) in [(0, 0), (6, 4), (3, 2)] {
+                let center = HexTile::new(x, y).coord_to_world(&shifted);
+
+                assert!(center.abs_diff_eq(HexTile::new(x, y).coord_to_world(&hexgrid) + offset, 1e-3));
+                assert_eq!(shifted.world_to_coord(&GlobalTransform::IDENTITY, center), Some((x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn order_to_coord_inverts_coord_to_order() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 5, 3, 32.);
+
+        for x in 0..hexgrid.columns {
+            for y in 0..hexgrid.rows {
+                let order = HexTile::new(x, y).coord_to_order(hexgrid.columns);
+                assert_eq!(hexgrid.order_to_coord(order), Some((x, y)));
+            }
+        }
+
+        assert_eq!(hexgrid.order_to_coord(6), Some((0, 1)));
+        assert_eq!(hexgrid.order_to_coord(0), None);
+        assert_eq!(hexgrid.order_to_coord(16), None);
+    }
+
+    #[test]
+    fn coordinate_iterators_follow_the_tile_order() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 3, 2, 32.);
+
+        let coords: Vec<(u32, u32)> = hexgrid.iter_coords().collect();
+        assert_eq!(coords, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        assert!(coords.iter().enumerate().all(|(index, &(x, y))| HexTile::new(x, y).coord_to_order(3) == index as u32 + 1));
+
+        assert_eq!(hexgrid.iter_row(1).collect::<Vec<_>>(), vec![(0, 1), (1, 1), (2, 1)]);
+        assert_eq!(hexgrid.iter_column(2).collect::<Vec<_>>(), vec![(2, 0), (2, 1)]);
+        assert_eq!(hexgrid.iter_row(2).count(), 0);
+        assert_eq!(hexgrid.iter_column(3).count(), 0);
+    }
+}
@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+
+use super::{
+    picking::{
+        update_hovered_hextile, 
+        HexTilePick, 
+        HoveredHexTile
+    }, 
+    selection::{
+        on_hextile_deselected, 
+        on_hextile_selected, 
+        select_clicked_hextiles, 
+        HexTileSelectionChanged, 
+        HexTileSelectionConfigs, 
+        HexTileSelectionOverlay, 
+        SelectedTile
+    }
+};
+
+/// A [`Plugin`] converting mouse button presses over a [`HexTile`](super::hexgrid::HexTile) 
+/// into [`HexTileClicked`] events, and cursor movement between tiles into [`HexTileHoverEnter`] 
+/// and [`HexTileHoverExit`] events, using the tile under the cursor kept in the [`HoveredHexTile`] resource.
+/// 
+/// If `hover_highlight` is set, it is inserted as a resource and applied to the hovered tile's [`Sprite`].
+/// 
+/// If `selection` is set, it is inserted as a resource and clicks select tiles, marking them 
+/// with [`SelectedTile`] and sending [`HexTileSelectionChanged`] events.
+/// 
+/// Requires the [`GridsPlugin`](super::GridsPlugin).
+#[derive(Clone, Default)]
+pub struct HexGridInteractionPlugin {
+    pub hover_highlight: Option<HexTileHoverHighlight>,
+    pub selection: Option<HexTileSelectionConfigs>,
+}
+
+impl Plugin for HexGridInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HexTileClicked>();
+        app.add_event::<HexTileHoverEnter>();
+        app.add_event::<HexTileHoverExit>();
+        app.add_event::<HexTileSelectionChanged>();
+
+        app.register_type::<SelectedTile>();
+        app.register_type::<HexTileSelectionOverlay>();
+
+        if let Some(hover_highlight) = &self.hover_highlight {
+            app.insert_resource(hover_highlight.clone());
+        }
+        if let Some(selection) = &self.selection {
+            app.insert_resource(selection.clone());
+        }
+
+        app.add_observer(on_hextile_selected);
+        app.add_observer(on_hextile_deselected);
+
+        app.add_systems(Update, (
+            (send_hextile_clicks, select_clicked_hextiles.run_if(resource_exists::<HexTileSelectionConfigs>)).chain(),
+            (send_hextile_hover_events, highlight_hovered_hextile).chain(),
+        ).after(update_hovered_hextile));
+    }
+}
+
+/// A [`Resource`] configuring how the [`HexGridInteractionPlugin`] highlights the hovered tile's [`Sprite`].
+/// 
+/// The original [`Sprite`] is restored once the cursor leaves the tile. Insert, change 
+/// or remove it at runtime to toggle the highlight.
+/// 
+/// Tinting takes over the `color` of the [`Sprite`], and so is overridden by the 
+/// [`HexLightingPlugin`](super::lighting::HexLightingPlugin), swap the texture instead when using both.
+#[derive(Resource, Clone)]
+pub enum HexTileHoverHighlight {
+    /// Multiplies the color of the hovered tile by the given color
+    Tint(Color),
+
+    /// Replaces the image of the hovered tile by the image at the given path
+    Swap(String),
+}
+
+/// An [`Event`] sent by the [`HexGridInteractionPlugin`] when the cursor enters a tile.
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
+pub struct HexTileHoverEnter {
+    pub pick: HexTilePick,
+}
+
+/// An [`Event`] sent by the [`HexGridInteractionPlugin`] when the cursor leaves a tile, 
+/// before the [`HexTileHoverEnter`] of the next one.
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
+pub struct HexTileHoverExit {
+    pub pick: HexTilePick,
+}
+
+/// The [`Sprite`] state of the highlighted tile before the highlight was applied
+#[derive(Component, Clone)]
+struct HoverHighlighted {
+    color: Color,
+    image: Handle<Image>,
+}
+
+/// An [`Event`] sent by the [`HexGridInteractionPlugin`] when a mouse button is pressed over a tile.
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
+pub struct HexTileClicked {
+    /// The `id` of the [`HexGrid`](super::hexgrid::HexGrid) containing the tile
+    pub grid_id: u64,
+
+    /// The `x` and `y` hexgrid coordinates of the tile
+    pub coord: (u32, u32),
+
+    pub button: MouseButton,
+
+    /// The [`Entity`] containing the [`HexTile`](super::hexgrid::HexTile)
+    pub entity: Entity,
+}
+
+impl HexTileClicked {
+    /// Returns the clicked tile as a [`HexTilePick`]
+    pub fn pick(&self) -> HexTilePick {
+        HexTilePick { grid_id: self.grid_id, coord: self.coord, entity: self.entity }
+    }
+}
+
+/// Sends a [`HexTileClicked`] event for every mouse button pressed this frame over the [`HoveredHexTile`]
+fn send_hextile_clicks(
+    hovered_hextile: Res<HoveredHexTile>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut click_writer: EventWriter<HexTileClicked>,
+) {
+    let Some(pick) = hovered_hextile.hovered else { return; };
+
+    for &button in mouse_buttons.get_just_pressed() {
+        click_writer.write(HexTileClicked {
+            grid_id: pick.grid_id,
+            coord: pick.coord,
+            button,
+            entity: pick.entity,
+        });
+    }
+}
+
+/// Sends [`HexTileHoverExit`] and [`HexTileHoverEnter`] events when the [`HoveredHexTile`] changes
+fn send_hextile_hover_events(
+    hovered_hextile: Res<HoveredHexTile>,
+    mut previous: Local<Option<HexTilePick>>,
+    mut exit_writer: EventWriter<HexTileHoverExit>,
+    mut enter_writer: EventWriter<HexTileHoverEnter>,
+) {
+    if *previous == hovered_hextile.hovered {
+        return;
+    }
+
+    if let Some(pick) = *previous {
+        exit_writer.write(HexTileHoverExit { pick });
+    }
+    if let Some(pick) = hovered_hextile.hovered {
+        enter_writer.write(HexTileHoverEnter { pick });
+    }
+
+    *previous = hovered_hextile.hovered;
+}
+
+/// Restores the [`Sprite`] of tiles the cursor left, and applies the [`HexTileHoverHighlight`] to the hovered tile
+fn highlight_hovered_hextile(
+    mut commands: Commands,
+    hover_highlight: Option<Res<HexTileHoverHighlight>>,
+    hovered_hextile: Res<HoveredHexTile>,
+    asset_server: Res<AssetServer>,
+    mut sprites: Query<(Entity, &mut Sprite, Option<&HoverHighlighted>)>,
+) {
+    let highlight_changed = hover_highlight.as_ref().is_none_or(|hover_highlight| hover_highlight.is_changed());
+    let hovered_entity = hovered_hextile.hovered.map(|pick| pick.entity);
+
+    for (tile_entity, mut sprite, original) in &mut sprites {
+        let Some(original) = original else { continue; };
+
+        if highlight_changed || hovered_entity != Some(tile_entity) {
+            sprite.color = original.color;
+            sprite.image = original.image.clone();
+            commands.entity(tile_entity).remove::<HoverHighlighted>();
+        }
+    }
+
+    let (Some(hover_highlight), Some(hovered_entity)) = (hover_highlight, hovered_entity) else { return; };
+    let Ok((_, mut sprite, original)) = sprites.get_mut(hovered_entity) else { return; };
+
+    // Still highlighted, as a changed highlight was restored above
+    if original.is_some() && !highlight_changed {
+        return;
+    }
+
+    commands.entity(hovered_entity).insert(HoverHighlighted {
+        color: sprite.color,
+        image: sprite.image.clone(),
+    });
+
+    match hover_highlight.as_ref() {
+        HexTileHoverHighlight::Tint(tint) => {
+            let (color, tint) = (sprite.color.to_linear(), tint.to_linear());
+            sprite.color = LinearRgba::new(color.red * tint.red, color.green * tint.green, color.blue * tint.blue, color.alpha * tint.alpha).into();
+        }
+        HexTileHoverHighlight::Swap(path) => sprite.image = asset_server.load(path),
+    }
+}
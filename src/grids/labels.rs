@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// A debug [`Plugin`] spawning a [`Text2d`] label on every [`HexTile`], showing its `x` and `y`
+/// hexgrid coordinates and its order (see [`HexTile::coord_to_order()`]).
+/// 
+/// Inserts its `configs` as a [`Resource`], toggle its `rc` field at runtime (e.g. with the
+/// `toggle_key` or [`toggle_hextile_labels`]) to show or hide the labels.
+#[derive(Clone, Default)]
+pub struct HexTileLabelsPlugin {
+    pub configs: HexTileLabelConfigs,
+}
+
+impl Plugin for HexTileLabelsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexTileLabelConfigs>();
+        app.register_type::<HexTileLabel>();
+
+        app.insert_resource(self.configs.clone());
+
+        app.add_systems(Update, (
+            toggle_hextile_labels_by_key,
+            spawn_hextile_labels,
+            update_hextile_label_visibility
+        ).chain());
+    }
+}
+
+/// Configurations of the labels spawned by the [`HexTileLabelsPlugin`]
+#[derive(Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct HexTileLabelConfigs {
+    /// Whether the labels are shown
+    pub rc: bool,
+
+    /// Key toggling `rc` when pressed, `None` to only toggle it manually
+    pub toggle_key: Option<KeyCode>,
+
+    pub font_size: f32,
+    pub color: Color,
+
+    /// Whether the order of the tile is shown below its coordinates
+    pub show_order: bool,
+
+    /// `z` of the labels relative to their tile, high enough to be drawn above decorations and overlays
+    pub z_offset: f32,
+}
+
+impl Default for HexTileLabelConfigs {
+    fn default() -> Self {
+        HexTileLabelConfigs {
+            rc: true,
+            toggle_key: Some(KeyCode::F3),
+            font_size: 12.,
+            color: Color::WHITE,
+            show_order: true,
+            z_offset: 10.,
+        }
+    }
+}
+
+/// A [`Component`] marking a label [`Entity`] spawned by the [`HexTileLabelsPlugin`].
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexTileLabel;
+
+/// [`System`] which shows or hides the labels of the [`HexTileLabelsPlugin`].
+pub fn toggle_hextile_labels(mut label_configs: ResMut<HexTileLabelConfigs>) {
+    label_configs.rc = !label_configs.rc;
+}
+
+fn toggle_hextile_labels_by_key(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut label_configs: ResMut<HexTileLabelConfigs>,
+) {
+    if label_configs.toggle_key.is_some_and(|key| keys.just_pressed(key)) {
+        label_configs.rc = !label_configs.rc;
+    }
+}
+
+/// Spawns a label on every new [`HexTile`], and rewrites the label of tiles whose coordinates changed
+fn spawn_hextile_labels(
+    mut commands: Commands,
+    label_configs: Res<HexTileLabelConfigs>,
+    changed_hextiles: Query<(Entity, &HexTile, &ChildOf, Option<&Children>), Changed<HexTile>>,
+    hexgrids: Query<&HexGrid>,
+    mut labels: Query<&mut Text2d, With<HexTileLabel>>,
+) {
+    for (tile_entity, hextile, child_of, children) in &changed_hextiles {
+        let Ok(hexgrid) = hexgrids.get(child_of.parent()) else { continue; };
+
+        let text = if label_configs.show_order {
+            format!("({}, {})\n#{}", hextile.x, hextile.y, hextile.coord_to_order(hexgrid.columns))
+        } else {
+            format!("({}, {})", hextile.x, hextile.y)
+        };
+
+        let existing_label = children.and_then(|children| children.into_iter().copied().find(|&child| labels.contains(child)));
+
+        match existing_label {
+            Some(label_entity) => {
+                if let Ok(mut label_text) = labels.get_mut(label_entity) {
+                    label_text.0 = text;
+                }
+            },
+            None => {
+                commands.entity(tile_entity).with_child((
+                    HexTileLabel,
+                    Text2d::new(text),
+                    TextFont {
+                        font_size: label_configs.font_size,
+                        ..default()
+                    },
+                    TextColor(label_configs.color),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                    Transform::from_xyz(0., 0., label_configs.z_offset),
+                    if label_configs.rc { Visibility::Inherited } else { Visibility::Hidden },
+                ));
+            },
+        }
+    }
+}
+
+/// Shows or hides every label whenever the [`HexTileLabelConfigs`] change
+fn update_hextile_label_visibility(
+    label_configs: Res<HexTileLabelConfigs>,
+    mut labels: Query<&mut Visibility, With<HexTileLabel>>,
+) {
+    if !label_configs.is_changed() {
+        return;
+    }
+
+    let visibility = if label_configs.rc { Visibility::Inherited } else { Visibility::Hidden };
+
+    for mut label_visibility in &mut labels {
+        label_visibility.set_if_neq(visibility);
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// A [`Component`] configuring the `z` of the [`Transform`] of every [`HexTile`] child of 
+/// the [`HexGrid`] [`Entity`] it is inserted on, so overlapping tile sprites, other grids and 
+/// unit sprites layer predictably.
+/// 
+/// Each tile is placed at `base_z + (rows - 1 - y) * row_step`, plus its [`HexTileZOffset`] if any, 
+/// relative to the grid: with a positive `row_step`, tiles lower on the screen are drawn over 
+/// the ones above them (e.g. for tall tiles whose sprites overlap the row behind).
+/// 
+/// Kept up to date by the [`GridsPlugin`](super::GridsPlugin). Without it tiles stay at `z = 0`.
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexGridLayering {
+    pub base_z: f32,
+    pub row_step: f32,
+}
+
+impl HexGridLayering {
+    /// Returns the `z` of a [`HexTile`] with the given [`HexTileZOffset`] relative to its grid.
+    pub fn tile_z(&self, hexgrid: &HexGrid, hextile: &HexTile, offset: Option<&HexTileZOffset>) -> f32 {
+        let row_from_bottom = hexgrid.rows.saturating_sub(1).saturating_sub(hextile.y);
+
+        self.base_z + row_from_bottom as f32 * self.row_step + offset.map_or(0., |offset| offset.0)
+    }
+}
+
+/// A [`Component`] raising (or lowering) a [`HexTile`] [`Entity`] above the others of its grid, 
+/// e.g. for a mountain tile whose sprite overlaps its neighbors.
+/// 
+/// Added to the `z` given by the [`HexGridLayering`] of its grid, or to `0` without one.
+#[derive(Component, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct HexTileZOffset(pub f32);
+
+/// Sets the `z` of tiles which were spawned, whose [`HexTileZOffset`] changed, or whose grid's [`HexGridLayering`] changed
+pub(crate) fn update_hextile_z_order(
+    hexgrids: Query<(&HexGrid, Option<Ref<HexGridLayering>>)>,
+    mut removed_layerings: RemovedComponents<HexGridLayering>,
+    mut removed_offsets: RemovedComponents<HexTileZOffset>,
+    mut hextiles: Query<(Entity, Ref<HexTile>, Option<Ref<HexTileZOffset>>, &mut Transform, &ChildOf)>,
+) {
+    let removed_layerings: Vec<Entity> = removed_layerings.read().collect();
+    let removed_offsets: Vec<Entity> = removed_offsets.read().collect();
+
+    for (tile_entity, hextile, offset, mut transform, child_of) in &mut hextiles {
+        let Ok((hexgrid, layering)) = hexgrids.get(child_of.parent()) else { continue; };
+
+        let changed = hextile.is_changed()
+            || offset.as_ref().is_some_and(|offset| offset.is_changed())
+            || layering.as_ref().is_some_and(|layering| layering.is_changed())
+            || removed_offsets.contains(&tile_entity)
+            || removed_layerings.contains(&child_of.parent());
+
+        if !changed {
+            continue;
+        }
+
+        let z = match (&layering, &offset) {
+            (Some(layering), _) => layering.tile_z(hexgrid, &hextile, offset.as_deref()),
+            (None, Some(offset)) => offset.0,
+            // Tiles which were never layered keep their `z`
+            (None, None) if !removed_offsets.contains(&tile_entity) && !removed_layerings.contains(&child_of.parent()) => continue,
+            (None, None) => 0.,
+        };
+
+        if transform.translation.z != z {
+            transform.translation.z = z;
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+
+/// A [`Plugin`] which tints the [`Sprite`] of every [`HexTile`] by a global [`HexAmbientLight`]
+/// plus the [`HexLightSource`]s in range, e.g. for day-night cycles and torch-lit dungeons.
+/// 
+/// Only tiles affected by a change are recomputed: all of them when the ambient light changes,
+/// and the ones in range of a light source when it is added, changed or removed.
+/// 
+/// Takes over the `color` of tile [`Sprite`]s, which is reapplied whenever a tile's [`Sprite`] is replaced.
+#[derive(Clone, Default)]
+pub struct HexLightingPlugin;
+
+impl Plugin for HexLightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexAmbientLight>();
+        app.register_type::<HexLightSource>();
+        app.register_type::<HexTileLight>();
+
+        app.init_resource::<HexAmbientLight>();
+        app.init_resource::<HexLightSources>();
+
+        app.add_systems(PostUpdate, update_hextile_lighting);
+    }
+}
+
+/// A [`Resource`] holding the light every [`HexTile`] receives regardless of light sources.
+/// 
+/// Change `color` over time for a day-night cycle (e.g. white at noon, dark blue at night).
+#[derive(Resource, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct HexAmbientLight {
+    pub color: Color,
+}
+
+impl Default for HexAmbientLight {
+    fn default() -> Self {
+        HexAmbientLight { color: Color::WHITE }
+    }
+}
+
+/// A [`Component`] turning the [`HexTile`] [`Entity`] it is inserted on into a light source.
+/// 
+/// Lights up tiles up to `radius` steps away, with a linear falloff over the hex distance
+/// (see [`HexTile::distance()`]).
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexLightSource {
+    pub color: Color,
+    pub intensity: f32,
+    pub radius: u32,
+}
+
+impl HexLightSource {
+    /// Returns the light received by a tile `distance` steps away from this light source.
+    pub fn light_at(&self, distance: u32) -> LinearRgba {
+        if distance > self.radius {
+            return LinearRgba::NONE;
+        }
+        let falloff = 1. - distance as f32 / (self.radius + 1) as f32;
+
+        self.color.to_linear() * (self.intensity * falloff)
+    }
+}
+
+/// A [`Component`] holding the light last computed for a [`HexTile`], as applied to its [`Sprite`] color.
+#[derive(Component, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct HexTileLight(pub Color);
+
+/// Last known `(grid_id, x, y, radius)` of every light source, as removed lights can no longer be queried
+#[derive(Resource, Default)]
+struct HexLightSources {
+    lights: HashMap<Entity, (u64, u32, u32, u32)>,
+}
+
+/// Adds the tiles of the grid within `radius` steps of `(x, y)` to `dirty`
+fn mark_tiles_in_range(
+    dirty: &mut HashSet<Entity>,
+    hexgrid: &HexGrid,
+    tile_index: &HexTileIndex,
+    (x, y, radius): (u32, u32, u32),
+) {
+    for (col, row) in hexgrid.tiles_in_range((x, y), radius) {
+        if let Some(tile_entity) = tile_index.get_tile_entity(hexgrid.id, col, row) {
+            dirty.insert(tile_entity);
+        }
+    }
+}
+
+/// Recomputes the light of the tiles affected by changes to the ambient light or light sources
+fn update_hextile_lighting(
+    mut commands: Commands,
+    ambient: Res<HexAmbientLight>,
+    mut light_sources: ResMut<HexLightSources>,
+    tile_index: Res<HexTileIndex>,
+    registry: Res<HexGridRegistry>,
+    hexgrids: Query<&HexGrid>,
+    changed_lights: Query<(Entity, &HexLightSource), Changed<HexLightSource>>,
+    lights: Query<&HexLightSource>,
+    mut removed_lights: RemovedComponents<HexLightSource>,
+    mut hextiles: ParamSet<(
+        Query<(Entity, &Sprite, Option<&HexTileLight>), (With<HexTile>, Changed<Sprite>)>,
+        Query<(Entity, &mut Sprite), With<HexTile>>,
+    )>,
+) {
+    let hexgrid_of = |grid_id: u64| registry.get(grid_id).and_then(|grid_entity| hexgrids.get(grid_entity).ok());
+
+    let mut dirty = HashSet::new();
+
+    for light_entity in removed_lights.read() {
+        if let Some((grid_id, x, y, radius)) = light_sources.lights.remove(&light_entity) {
+            if let Some(hexgrid) = hexgrid_of(grid_id) {
+                mark_tiles_in_range(&mut dirty, hexgrid, &tile_index, (x, y, radius));
+            }
+        }
+    }
+
+    for (light_entity, light) in &changed_lights {
+        let Some((grid_id, x, y)) = tile_index.get_tile_key(light_entity) else { continue; };
+        let Some(hexgrid) = hexgrid_of(grid_id) else { continue; };
+
+        if let Some((_, old_x, old_y, old_radius)) = light_sources.lights.insert(light_entity, (grid_id, x, y, light.radius)) {
+            mark_tiles_in_range(&mut dirty, hexgrid, &tile_index, (old_x, old_y, old_radius));
+        }
+        mark_tiles_in_range(&mut dirty, hexgrid, &tile_index, (x, y, light.radius));
+    }
+
+    // Sprites which were replaced (e.g. by a texture change) lost their light
+    for (tile_entity, sprite, tile_light) in &hextiles.p0() {
+        if tile_light.is_none_or(|tile_light| tile_light.0 != sprite.color) {
+            dirty.insert(tile_entity);
+        }
+    }
+
+    let full_update = ambient.is_changed();
+    if dirty.is_empty() && !full_update {
+        return;
+    }
+
+    let ambient_color = ambient.color.to_linear();
+
+    for (tile_entity, mut sprite) in &mut hextiles.p1() {
+        if !full_update && !dirty.contains(&tile_entity) {
+            continue;
+        }
+        let Some((grid_id, x, y)) = tile_index.get_tile_key(tile_entity) else { continue; };
+        let Some(hexgrid) = hexgrid_of(grid_id) else { continue; };
+
+        let tile = HexTile::new(x, y);
+        let mut light = ambient_color;
+
+        for (&light_entity, &(light_grid_id, light_x, light_y, _)) in &light_sources.lights {
+            if light_grid_id != grid_id {
+                continue;
+            }
+            let Ok(light_source) = lights.get(light_entity) else { continue; };
+
+            let received = light_source.light_at(tile.distance(&HexTile::new(light_x, light_y), hexgrid.orientation));
+            light.red += received.red;
+            light.green += received.green;
+            light.blue += received.blue;
+        }
+
+        let color = Color::LinearRgba(LinearRgba::new(
+            light.red.min(1.),
+            light.green.min(1.),
+            light.blue.min(1.),
+            ambient_color.alpha,
+        ));
+
+        if sprite.color != color {
+            sprite.color = color;
+        }
+        commands.entity(tile_entity).insert(HexTileLight(color));
+    }
+}
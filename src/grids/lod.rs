@@ -0,0 +1,426 @@
+use bevy::{
+    asset::RenderAssetUsages, 
+    prelude::*, 
+    render::mesh::{
+        Indices, 
+        PrimitiveTopology
+    }
+};
+
+use std::collections::{
+    hash_map::Entry, 
+    HashMap, 
+    HashSet
+};
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    registry::HexTileIndex
+};
+use crate::MainCameraFilter;
+
+/// A [`Plugin`] swapping the tiles of the grids with a [`HexGridLod`] for a single flat-colored mesh
+/// when the main camera zooms far out, so zoomed-out maps render in one draw call.
+/// 
+/// Every tile becomes a hexagon of the average color of its [`Sprite`] (the sampled image tinted by
+/// the sprite `color`, or the sprite `color` alone while the image is not loaded). The mesh is rebuilt
+/// when the sprite of a tile, or an image drawn by a tile, changes while it is shown.
+/// 
+/// Like the [`HexCullingPlugin`](super::culling::HexCullingPlugin), this takes over the [`Visibility`]
+/// of tiles while the mesh is shown, giving them their previous [`Visibility`] back afterwards. Both are 
+/// kept from fighting over it by the culling pausing on grids whose mesh is shown (see [`HexGridLod::is_active()`]), 
+/// though tiles despawned by [`HexCullingMode::Despawn`](super::culling::HexCullingMode::Despawn) are missing from the mesh.
+#[derive(Clone, Default)]
+pub struct HexLodPlugin;
+
+impl Plugin for HexLodPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexGridLodMesh>();
+
+        app.add_systems(PostUpdate, update_hexgrid_lod);
+    }
+}
+
+/// A [`Component`] enabling the level-of-detail mesh (see [`HexLodPlugin`]) on the
+/// [`HexGrid`] [`Entity`] it is inserted on.
+#[derive(Component, Clone)]
+pub struct HexGridLod {
+    /// `OrthographicProjection.scale` from which the tiles are replaced by the mesh
+    pub zoom_threshold: f32,
+
+    /// How far below `zoom_threshold` the scale goes back before the tiles are shown again,
+    /// so zooming around the threshold does not swap them every frame
+    pub hysteresis: f32,
+
+    /// `z` of the mesh relative to the grid
+    pub z_offset: f32,
+
+    mesh_entity: Option<Entity>,
+
+    /// Tiles hidden while the mesh is shown, with their previous visibility
+    hidden: HashMap<Entity, Visibility>,
+}
+
+impl Default for HexGridLod {
+    fn default() -> Self {
+        HexGridLod {
+            zoom_threshold: 4.,
+            hysteresis: 0.25,
+            z_offset: 0.,
+            mesh_entity: None,
+            hidden: HashMap::new(),
+        }
+    }
+}
+
+impl HexGridLod {
+    pub fn new(zoom_threshold: f32) -> Self {
+        HexGridLod { zoom_threshold, ..Default::default() }
+    }
+
+    /// Returns whether the tiles are currently replaced by the mesh
+    pub fn is_active(&self) -> bool {
+        self.mesh_entity.is_some()
+    }
+}
+
+/// A [`Component`] marking the [`Entity`] holding the level-of-detail mesh of a grid.
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexGridLodMesh {
+    pub grid_id: u64,
+}
+
+/// Returns the average color of the part of an image drawn by a [`Sprite`], sampled on a coarse grid
+fn average_sprite_color(sprite: &Sprite, images: &Assets<Image>, layouts: &Assets<TextureAtlasLayout>) -> Option<LinearRgba> {
+    const SAMPLES: u32 = 8;
+
+    let image = images.get(&sprite.image)?;
+    let rect = match &sprite.texture_atlas {
+        Some(texture_atlas) => texture_atlas.texture_rect(layouts)?,
+        None => URect::from_corners(UVec2::ZERO, image.size()),
+    };
+    if rect.is_empty() {
+        return None;
+    }
+
+    // Colors are weighted by their alpha, so transparent corners around the hexagon do not count
+    let mut sum = Vec4::ZERO;
+    for sample_x in 0..SAMPLES {
+        for sample_y in 0..SAMPLES {
+            let x = rect.min.x + (sample_x * 2 + 1) * rect.width() / (SAMPLES * 2);
+            let y = rect.min.y + (sample_y * 2 + 1) * rect.height() / (SAMPLES * 2);
+            let Ok(color) = image.get_color_at(x, y) else { continue; };
+
+            let color = color.to_linear();
+            sum += Vec4::new(color.red, color.green, color.blue, 1.) * color.alpha;
+        }
+    }
+
+    (sum.w > 0.).then(|| LinearRgba::new(sum.x / sum.w, sum.y / sum.w, sum.z / sum.w, 1.))
+}
+
+/// Returns a [`Mesh`] of a hexagon of the color of every `(x, y, color)` tile, positioned relative to the grid [`Entity`]
+fn lod_mesh(hexgrid: &HexGrid, tiles: &[(u32, u32, LinearRgba)]) -> Mesh {
+    let corners = hexgrid.hextile_corners();
+
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(tiles.len() * 7);
+    let mut colors: Vec<[f32; 4]> = Vec::with_capacity(tiles.len() * 7);
+    let mut indices: Vec<u32> = Vec::with_capacity(tiles.len() * 18);
+
+    for &(x, y, color) in tiles {
+        let center = HexTile::new(x, y).coord_to_world(hexgrid);
+        let first = positions.len() as u32;
+
+        positions.push(center.extend(0.).to_array());
+        positions.extend(corners.map(|corner| (center + corner).extend(0.).to_array()));
+        colors.extend([color.to_f32_array(); 7]);
+        indices.extend((0..6u32).flat_map(|corner| [first, first + corner + 1, first + (corner + 1) % 6 + 1]));
+    }
+
+    let normals = vec![[0., 0., 1.]; positions.len()];
+    let uvs = vec![[0., 0.]; positions.len()];
+
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Shows the mesh of the grids zoomed out past their threshold in place of their tiles, and the tiles back when zoomed in
+fn update_hexgrid_lod(
+    mut commands: Commands,
+    camera: Query<&Projection, MainCameraFilter>,
+    mut hexgrids: Query<(Entity, &HexGrid, &mut HexGridLod)>,
+    changed_sprites: Query<&ChildOf, (With<HexTile>, Changed<Sprite>)>,
+    mut hextiles: Query<(&mut Visibility, Option<&Sprite>), With<HexTile>>,
+    tile_index: Res<HexTileIndex>,
+    images: Res<Assets<Image>>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut image_events: EventReader<AssetEvent<Image>>,
+    mut image_colors: Local<HashMap<AssetId<Image>, LinearRgba>>,
+) {
+    // Average colors of images which were loaded, modified or removed since are stale
+    let changed_images: HashSet<AssetId<Image>> = image_events
+        .read()
+        .filter_map(|event| match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } | AssetEvent::Removed { id } | AssetEvent::LoadedWithDependencies { id } => Some(id),
+            AssetEvent::Unused { .. } => None,
+        })
+        .collect();
+    for id in &changed_images {
+        image_colors.remove(id);
+    }
+
+    let Ok(Projection::Orthographic(ortho)) = camera.single() else { return; };
+
+    for (grid_entity, hexgrid, mut lod) in &mut hexgrids {
+        let zoomed_out = if lod.is_active() {
+            ortho.scale >= lod.zoom_threshold - lod.hysteresis
+        } else {
+            ortho.scale >= lod.zoom_threshold
+        };
+
+        if !zoomed_out {
+            if let Some(mesh_entity) = lod.mesh_entity.take() {
+                commands.entity(mesh_entity).despawn();
+
+                for (tile_entity, previous) in std::mem::take(&mut lod.hidden) {
+                    if let Ok((mut visibility, _)) = hextiles.get_mut(tile_entity) {
+                        *visibility = previous;
+                    }
+                }
+            }
+            continue;
+        }
+
+        let tiles_changed = changed_sprites.iter().any(|child_of| child_of.parent() == grid_entity);
+        let images_changed = !changed_images.is_empty() && tile_index.iter_grid(hexgrid.id).any(|(_, _, tile_entity)| {
+            hextiles.get(tile_entity).is_ok_and(|(_, sprite)| sprite.is_some_and(|sprite| changed_images.contains(&sprite.image.id())))
+        });
+        if lod.is_active() && !tiles_changed && !images_changed {
+            continue;
+        }
+
+        let mut tiles = Vec::new();
+        for (x, y, tile_entity) in tile_index.iter_grid(hexgrid.id) {
+            let Ok((mut visibility, sprite)) = hextiles.get_mut(tile_entity) else { continue; };
+            let Some(sprite) = sprite else { continue; };
+
+            let tint = sprite.color.to_linear();
+            // Images of atlases are sampled per texture, so they are not cached
+            let cached_color = image_colors.get(&sprite.image.id()).copied().filter(|_| sprite.texture_atlas.is_none());
+            let image_color = cached_color.or_else(|| {
+                let color = average_sprite_color(sprite, &images, &layouts);
+                if let (Some(color), None) = (color, &sprite.texture_atlas) {
+                    image_colors.insert(sprite.image.id(), color);
+                }
+                color
+            });
+            let color = image_color.map_or(tint, |image_color| {
+                LinearRgba::new(image_color.red * tint.red, image_color.green * tint.green, image_color.blue * tint.blue, tint.alpha)
+            });
+            tiles.push((x, y, color));
+
+            if let Entry::Vacant(entry) = lod.hidden.entry(tile_entity) {
+                entry.insert(*visibility);
+                *visibility = Visibility::Hidden;
+            }
+        }
+
+        // Sorted so the mesh is the same for the same tiles
+        tiles.sort_unstable_by_key(|&(x, y, _)| (y, x));
+        let mesh = meshes.add(lod_mesh(hexgrid, &tiles));
+
+        match lod.mesh_entity {
+            Some(mesh_entity) => { commands.entity(mesh_entity).insert(Mesh2d(mesh)); },
+            None => {
+                let mesh_entity = commands.spawn((
+                    HexGridLodMesh { grid_id: hexgrid.id },
+                    Mesh2d(mesh),
+                    MeshMaterial2d(materials.add(ColorMaterial::default())),
+                    Transform::from_xyz(0., 0., lod.z_offset),
+                    // The grid itself is hidden, only its children are visible
+                    Visibility::Visible,
+                    ChildOf(grid_entity),
+                ))
+                .id();
+                lod.mesh_entity = Some(mesh_entity);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        asset::AssetPlugin, 
+        render::{
+            mesh::VertexAttributeValues, 
+            render_resource::{
+                Extent3d, 
+                TextureDimension, 
+                TextureFormat
+            }
+        }
+    };
+
+    use super::*;
+    use crate::grids::{
+        culling::{
+            HexCullingMode, 
+            HexCullingPlugin, 
+            HexGridCulling
+        }, 
+        hexgrid::HexGridOrientation, 
+        registry::{
+            index_hextile, 
+            register_hexgrid, 
+            unindex_hextile, 
+            unregister_hexgrid, 
+            HexGridRegistry
+        }
+    };
+
+    fn image(color: [u8; 4]) -> Image {
+        Image::new_fill(
+            Extent3d { width: 2, height: 2, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            &color,
+            TextureFormat::Rgba8Unorm,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    /// Spawns an 8x8 grid with a [`HexGridLod`] (threshold `2`), whose tiles all draw the same red image, 
+    /// and a camera seeing a 96 pixels wide square around the origin
+    fn app() -> (App, Handle<Image>) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), HexLodPlugin));
+        app.init_asset::<Image>();
+        app.init_asset::<Mesh>();
+        app.init_asset::<ColorMaterial>();
+        app.init_asset::<TextureAtlasLayout>();
+        app.init_resource::<HexGridRegistry>();
+        app.init_resource::<HexTileIndex>();
+        app.add_observer(register_hexgrid);
+        app.add_observer(unregister_hexgrid);
+        app.add_observer(index_hextile);
+        app.add_observer(unindex_hextile);
+
+        app.world_mut().spawn((
+            Camera2d,
+            Projection::Orthographic(OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(96.)),
+                ..OrthographicProjection::default_2d()
+            }),
+        ));
+
+        let mut spawn_hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 8, 8, 32.).build_spawn_hexgrid_entity_system(Vec3::ZERO);
+        spawn_hexgrid(app.world_mut());
+
+        let grid_entity = app.world().resource::<HexGridRegistry>().get(0).unwrap();
+        app.world_mut().entity_mut(grid_entity).insert(HexGridLod::new(2.));
+
+        let red = app.world_mut().resource_mut::<Assets<Image>>().add(image([255, 0, 0, 255]));
+        let mut hextiles = app.world_mut().query_filtered::<Entity, With<HexTile>>();
+        for tile_entity in hextiles.iter(app.world()).collect::<Vec<_>>() {
+            app.world_mut().entity_mut(tile_entity).insert(Sprite::from_image(red.clone()));
+        }
+
+        (app, red)
+    }
+
+    fn zoom(app: &mut App, scale: f32) {
+        let mut projections = app.world_mut().query_filtered::<&mut Projection, With<Camera2d>>();
+        let Projection::Orthographic(ortho) = &mut *projections.single_mut(app.world_mut()).unwrap() else { unreachable!() };
+        ortho.scale = scale;
+
+        app.update();
+        app.update();
+    }
+
+    fn hexgrid_lod(app: &mut App) -> &HexGridLod {
+        let mut lods = app.world_mut().query::<&HexGridLod>();
+        lods.single(app.world()).unwrap()
+    }
+
+    fn visibilities(app: &mut App) -> Vec<Visibility> {
+        let mut visibilities = app.world_mut().query_filtered::<&Visibility, With<HexTile>>();
+        visibilities.iter(app.world()).copied().collect()
+    }
+
+    /// Returns the distinct vertex colors of the level-of-detail mesh
+    fn mesh_colors(app: &mut App) -> Vec<[f32; 4]> {
+        let mut lod_meshes = app.world_mut().query_filtered::<&Mesh2d, With<HexGridLodMesh>>();
+        let mesh_handle = lod_meshes.single(app.world()).unwrap().0.clone();
+
+        let Some(VertexAttributeValues::Float32x4(colors)) = app.world().resource::<Assets<Mesh>>().get(&mesh_handle).unwrap().attribute(Mesh::ATTRIBUTE_COLOR) else {
+            panic!("the mesh has no vertex colors");
+        };
+        let mut distinct_colors = colors.clone();
+        distinct_colors.dedup();
+        distinct_colors
+    }
+
+    #[test]
+    fn tiles_are_swapped_for_the_mesh_past_the_threshold() {
+        let (mut app, _) = app();
+        zoom(&mut app, 1.);
+        assert!(!hexgrid_lod(&mut app).is_active());
+
+        zoom(&mut app, 2.);
+        assert!(hexgrid_lod(&mut app).is_active());
+        assert!(visibilities(&mut app).iter().all(|&visibility| visibility == Visibility::Hidden));
+        assert_eq!(mesh_colors(&mut app), vec![[1., 0., 0., 1.]]);
+
+        // Within the hysteresis the mesh is kept
+        zoom(&mut app, 1.9);
+        assert!(hexgrid_lod(&mut app).is_active());
+
+        zoom(&mut app, 1.);
+        assert!(!hexgrid_lod(&mut app).is_active());
+        assert!(visibilities(&mut app).iter().all(|&visibility| visibility == Visibility::Visible));
+        assert_eq!(app.world_mut().query::<&HexGridLodMesh>().iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn modified_images_are_sampled_again() {
+        let (mut app, red) = app();
+        zoom(&mut app, 3.);
+        assert_eq!(mesh_colors(&mut app), vec![[1., 0., 0., 1.]]);
+
+        *app.world_mut().resource_mut::<Assets<Image>>().get_mut(&red).unwrap() = image([0, 0, 255, 255]);
+        zoom(&mut app, 3.);
+        assert_eq!(mesh_colors(&mut app), vec![[0., 0., 1., 1.]]);
+    }
+
+    #[test]
+    fn culling_pauses_while_the_mesh_is_shown() {
+        let (mut app, _) = app();
+        app.add_plugins(HexCullingPlugin);
+        let grid_entity = app.world().resource::<HexGridRegistry>().get(0).unwrap();
+        app.world_mut().entity_mut(grid_entity).insert(HexGridCulling::new(HexCullingMode::Hide, 0.));
+
+        zoom(&mut app, 1.);
+        let culled = visibilities(&mut app).iter().filter(|&&visibility| visibility == Visibility::Hidden).count();
+        assert!(culled > 0 && culled < 64);
+
+        // The culling does not show tiles in view again while the mesh is shown
+        zoom(&mut app, 3.);
+        assert!(visibilities(&mut app).iter().all(|&visibility| visibility == Visibility::Hidden));
+        zoom(&mut app, 3.);
+        assert!(visibilities(&mut app).iter().all(|&visibility| visibility == Visibility::Hidden));
+
+        zoom(&mut app, 1.);
+        let hidden = visibilities(&mut app).iter().filter(|&&visibility| visibility == Visibility::Hidden).count();
+        assert_eq!(hidden, culled);
+    }
+}
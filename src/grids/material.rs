@@ -0,0 +1,139 @@
+use bevy::{
+    prelude::*, 
+    sprite::Material2d
+};
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    mesh::filled_hexagon_mesh
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+impl HexGrid {
+    /// Builds an 'exclusive' [`System`] closure which spawns this [`HexGrid`] like 
+    /// [`HexGrid::build_spawn_hexgrid_entity_system()`], but with every [`HexTile`] rendered as a 
+    /// hexagon [`Mesh2d`] with a [`ColorMaterial`] of the color returned by `tile_color` for it, 
+    /// instead of a quad [`Sprite`] with a hexagon texture.
+    /// 
+    /// Flat-colored boards then need no image assets, and tile edges are exact at any zoom. 
+    /// All tiles share one mesh, and tiles of the same color share one material, so they batch well.
+    /// 
+    /// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+    pub fn build_spawn_hexgrid_mesh_system(
+        self,
+        hexgrid_translation: Vec3,
+        tile_color: impl Fn(&HexTile) -> Color + Send + Sync + 'static,
+    ) -> impl FnMut(&mut World) {
+        move | world: &mut World | {
+            let mesh = world
+                .resource_mut::<Assets<Mesh>>()
+                .add(filled_hexagon_mesh(self.orientation, self.hextile_width, None).scaled_by(self.hextile_scale().extend(1.)));
+
+            // Colors are not hashable, so materials are shared by their 8 bit sRGBA value
+            let mut materials_by_color: HashMap<[u8; 4], Handle<ColorMaterial>> = HashMap::new();
+            let mut tile_materials: HashMap<(u32, u32), Handle<ColorMaterial>> = HashMap::new();
+
+            let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+            for col in 0..self.columns {
+                for row in 0..self.rows {
+                    let color = tile_color(&HexTile::new(col, row));
+                    let material = materials_by_color
+                        .entry(color.to_srgba().to_u8_array())
+                        .or_insert_with(|| materials.add(ColorMaterial::from_color(color)))
+                        .clone();
+
+                    tile_materials.insert((col, row), material);
+                }
+            }
+
+            let mut spawn_hexgrid = self.clone().build_spawn_with_bundle_system(hexgrid_translation, move |hextile| (
+                Mesh2d(mesh.clone()),
+                MeshMaterial2d(tile_materials[&(hextile.x, hextile.y)].clone()),
+            ));
+            spawn_hexgrid(world);
+        }
+    }
+}
+
+/// Builds a [`System`] closure which renders [`HexTile`] children of the [`HexGrid`] specified 
+/// by id with a hexagon [`Mesh2d`] and a [`MeshMaterial2d`] of the given (custom) [`Material2d`], 
+/// instead of a [`Sprite`] (which is removed).
+/// 
+/// `hextiles_coords` restricts the change to the tiles at those coordinates, `None` changes all 
+/// tiles of the grid. All changed tiles share one mesh and one material handle, so they batch well; 
+/// mutate the material asset to animate every tile at once (scrolling water, dissolve reveals, palette swaps...).
+/// 
+/// The [`Material2dPlugin`](bevy::sprite::Material2dPlugin) of `M` must be added to the app.
+/// 
+/// Can be added to the [`Startup`] schedule if set to run after the entities have 
+/// been spawned, also can be used as a one-shot system.
+pub fn build_change_hexgrid_materials_system<M: Material2d>(
+    grid_id: u64,
+    material: M,
+    hextiles_coords: Option<HashSet<(u32, u32)>>,
+) -> impl FnMut(
+    Commands,
+    ResMut<Assets<Mesh>>,
+    ResMut<Assets<M>>,
+    Query<(&Children, &HexGrid)>,
+    Query<&HexTile>,
+    ErrorReporter,
+) {
+    let mut material_handle: Option<Handle<M>> = None;
+
+    move |
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<M>>,
+        children_query: Query<(&Children, &HexGrid)>,
+        hextile_query: Query<&HexTile>,
+        mut error_reporter: ErrorReporter
+    | {
+        let _span = info_span!("hexgrid_apply_materials", grid_id).entered();
+
+        let material_handle = material_handle
+            .get_or_insert_with(|| materials.add(material.clone()))
+            .clone();
+
+        let mut grid_found = false;
+
+        for (children, hexgrid) in &children_query {
+            if hexgrid.id != grid_id {
+                continue;
+            }
+            grid_found = true;
+
+            let mesh_handle = meshes.add(filled_hexagon_mesh(hexgrid.orientation, hexgrid.hextile_width, None).scaled_by(hexgrid.hextile_scale().extend(1.)));
+
+            for &child in children {
+                let Ok(hextile) = hextile_query.get(child) else { continue; };
+
+                if hextiles_coords.as_ref().is_some_and(|coords| !coords.contains(&(hextile.x, hextile.y))) {
+                    continue;
+                }
+
+                commands.entity(child)
+                    .remove::<Sprite>()
+                    .insert((
+                        Mesh2d(mesh_handle.clone()),
+                        MeshMaterial2d(material_handle.clone()),
+                    ));
+            }
+        }
+
+        if !grid_found {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{
+    asset::RenderAssetUsages, 
+    prelude::*, 
+    render::mesh::{
+        Indices, 
+        PrimitiveTopology
+    }
+};
+
+use super::hexgrid::{
+    HexGridOrientation, 
+    HEXTILE_HEIGHT_RATIO
+};
+
+/// Returns the 6 corners of a hexagon centered on the origin, in counter-clockwise order.
+/// 
+/// `hextile_width` is the corner-to-corner size of the hexagon, matching the `hextile_width` of 
+/// a [`HexGrid`](super::hexgrid::HexGrid): for [`HexGridOrientation::Vertical`] the hexagon spans 
+/// `hextile_width` horizontally and `hextile_width * HEXTILE_HEIGHT_RATIO` vertically (like the tile sprites), 
+/// for [`HexGridOrientation::Horizontal`] the other way around.
+/// 
+/// For the corners of the (possibly squashed) hextiles of a grid, use [`HexGrid::hextile_corners()`](super::hexgrid::HexGrid::hextile_corners).
+pub fn hexagon_corners(orientation: HexGridOrientation, hextile_width: f32) -> [Vec2; 6] {
+    let half_width = hextile_width / 2.;
+    let half_height = hextile_width * HEXTILE_HEIGHT_RATIO / 2.;
+
+    match orientation {
+        HexGridOrientation::Vertical => [
+            Vec2::new(half_width, 0.),
+            Vec2::new(half_width / 2., half_height),
+            Vec2::new(-half_width / 2., half_height),
+            Vec2::new(-half_width, 0.),
+            Vec2::new(-half_width / 2., -half_height),
+            Vec2::new(half_width / 2., -half_height),
+        ],
+        HexGridOrientation::Horizontal => [
+            Vec2::new(half_height, half_width / 2.),
+            Vec2::new(0., half_width),
+            Vec2::new(-half_height, half_width / 2.),
+            Vec2::new(-half_height, -half_width / 2.),
+            Vec2::new(0., -half_width),
+            Vec2::new(half_height, -half_width / 2.),
+        ],
+    }
+}
+
+/// Returns the normalized UV [`Rect`] of a texture inside a [`TextureAtlasLayout`], to be 
+/// passed as `uv_rect` to the mesh generators so the hexagon samples only that texture.
+pub fn atlas_uv_rect(layout: &TextureAtlasLayout, index: usize) -> Option<Rect> {
+    let texture_rect = layout.textures.get(index)?.as_rect();
+    let atlas_size = layout.size.as_vec2();
+
+    Some(Rect::from_corners(texture_rect.min / atlas_size, texture_rect.max / atlas_size))
+}
+
+/// Maps a point of the hexagon to UV coordinates, the bounding box of the hexagon covering `uv_rect`
+fn point_uv(point: Vec2, corners: &[Vec2; 6], uv_rect: Rect) -> [f32; 2] {
+    let min = corners.iter().copied().reduce(Vec2::min).unwrap_or_default();
+    let max = corners.iter().copied().reduce(Vec2::max).unwrap_or_default();
+    let size = (max - min).max(Vec2::splat(f32::EPSILON));
+
+    // Image V coordinates grow downwards
+    let normalized = Vec2::new((point.x - min.x) / size.x, 1. - (point.y - min.y) / size.y);
+    (uv_rect.min + normalized * uv_rect.size()).to_array()
+}
+
+/// Builds a [`Mesh`] from 2d points, with normals facing the camera and UVs covering `uv_rect`
+fn build_mesh(points: Vec<Vec2>, indices: Vec<u32>, corners: &[Vec2; 6], uv_rect: Option<Rect>) -> Mesh {
+    let uv_rect = uv_rect.unwrap_or(Rect::new(0., 0., 1., 1.));
+
+    let positions: Vec<[f32; 3]> = points.iter().map(|point| point.extend(0.).to_array()).collect();
+    let normals = vec![[0., 0., 1.]; points.len()];
+    let uvs: Vec<[f32; 2]> = points.iter().map(|&point| point_uv(point, corners, uv_rect)).collect();
+
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Returns a filled hexagon [`Mesh`] (a center vertex and 6 triangles) to be used with [`Mesh2d`].
+/// 
+/// `uv_rect` is the normalized area of the texture the hexagon's bounding box maps to, e.g. the 
+/// result of [`atlas_uv_rect()`]. `None` maps it to the whole texture.
+pub fn filled_hexagon_mesh(orientation: HexGridOrientation, hextile_width: f32, uv_rect: Option<Rect>) -> Mesh {
+    let corners = hexagon_corners(orientation, hextile_width);
+
+    let mut points = vec![Vec2::ZERO];
+    points.extend(corners);
+
+    let indices = (0..6u32)
+        .flat_map(|corner| [0, corner + 1, (corner + 1) % 6 + 1])
+        .collect();
+
+    build_mesh(points, indices, &corners, uv_rect)
+}
+
+/// Returns a hexagon outline [`Mesh`] (a ring `thickness` pixels thick, inset from the tile edges) 
+/// to be used with [`Mesh2d`].
+/// 
+/// `uv_rect` works as in [`filled_hexagon_mesh()`].
+pub fn outline_hexagon_mesh(orientation: HexGridOrientation, hextile_width: f32, thickness: f32, uv_rect: Option<Rect>) -> Mesh {
+    let corners = hexagon_corners(orientation, hextile_width);
+
+    // Distance from the center to the middle of an edge
+    let apothem = hextile_width * HEXTILE_HEIGHT_RATIO / 2.;
+    let inner_scale = (1. - thickness / apothem).max(0.);
+
+    let mut points: Vec<Vec2> = corners.to_vec();
+    points.extend(corners.iter().map(|&corner| corner * inner_scale));
+
+    let indices = (0..6u32)
+        .flat_map(|corner| {
+            let next = (corner + 1) % 6;
+            [corner, next, next + 6, corner, next + 6, corner + 6]
+        })
+        .collect();
+
+    build_mesh(points, indices, &corners, uv_rect)
+}
@@ -1 +1,152 @@
-pub mod hexgrid;
\ No newline at end of file
+pub mod anchor;
+pub mod animation;
+pub mod batching;
+pub mod borders;
+pub mod commands;
+pub mod coords;
+pub mod culling;
+pub mod decoration;
+pub mod diagnostics;
+pub mod edges;
+pub mod fog;
+pub mod fov;
+pub mod generation;
+pub mod gizmos;
+pub mod hexgrid;
+pub mod interaction;
+pub mod labels;
+pub mod layering;
+pub mod lighting;
+pub mod lod;
+pub mod material;
+pub mod mesh;
+pub mod overlap;
+pub mod overlay;
+pub mod pathfinding;
+#[cfg(feature = "snapshot")]
+pub mod persistence;
+#[cfg(feature = "physics")]
+pub mod physics;
+pub mod picking;
+pub mod placement;
+pub mod regions;
+pub mod registry;
+pub mod selection;
+pub mod shapes;
+#[cfg(feature = "streaming")]
+pub mod streaming;
+pub mod texture_layers;
+pub mod texture_snapshot;
+#[cfg(feature = "ecs_tilemap")]
+pub mod tilemap_backend;
+pub mod tint;
+#[cfg(feature = "tiled")]
+pub mod tmx;
+
+use bevy::{
+    prelude::*, 
+    transform::TransformSystem
+};
+
+use crate::error::Bevy2dUtilError;
+use anchor::{
+    update_hextile_attachments, 
+    HexAnchor, 
+    HexAttachment
+};
+use animation::{
+    animate_hextiles, 
+    TileAnimation
+};
+use borders::HexRegionBorder;
+use hexgrid::{
+    report_failed_tile_textures, 
+    HexGrid, 
+    HexGridAnchor, 
+    HexGridOrientation, 
+    HexGridSpawned, 
+    HexTile, 
+    HexTileSpawned
+};
+use layering::{
+    update_hextile_z_order, 
+    HexGridLayering, 
+    HexTileZOffset
+};
+use pathfinding::MovementCost;
+use picking::{
+    update_hovered_hextile, 
+    HoveredHexTile
+};
+use placement::{
+    PlacementConfirmed, 
+    PlacementGhost
+};
+use registry::{
+    index_hextile, 
+    register_hexgrid, 
+    reindex_changed_hextiles, 
+    unindex_hextile, 
+    unregister_hexgrid, 
+    HexGridRegistry, 
+    HexTileIndex
+};
+use texture_layers::HexTileTextureLayer;
+
+/// A [`Plugin`] that wires up the grid subsystem: it registers the grid types for reflection, 
+/// maintains the [`HexGridRegistry`] and [`HexTileIndex`] resources, registers the grid events 
+/// and keeps the [`HoveredHexTile`] resource and the [`HexAttachment`]s up to date.
+#[derive(Clone, Default)]
+pub struct GridsPlugin;
+
+impl Plugin for GridsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexGrid>();
+        app.register_type::<HexTile>();
+        app.register_type::<HexGridOrientation>();
+        app.register_type::<HexGridAnchor>();
+        app.register_type::<HexAnchor>();
+        app.register_type::<HexAttachment>();
+        app.register_type::<HoveredHexTile>();
+        app.register_type::<HexGridLayering>();
+        app.register_type::<HexTileZOffset>();
+        app.register_type::<HexTileTextureLayer>();
+        app.register_type::<TileAnimation>();
+        app.register_type::<MovementCost>();
+        app.register_type::<PlacementGhost>();
+        app.register_type::<HexRegionBorder>();
+
+        app.init_resource::<HexGridRegistry>();
+        app.init_resource::<HexTileIndex>();
+        app.init_resource::<HoveredHexTile>();
+
+        app.add_event::<Bevy2dUtilError>();
+        app.add_event::<HexGridSpawned>();
+        app.add_event::<HexTileSpawned>();
+        app.add_event::<PlacementConfirmed>();
+
+        app.add_observer(register_hexgrid);
+        app.add_observer(unregister_hexgrid);
+        app.add_observer(index_hextile);
+        app.add_observer(unindex_hextile);
+
+        app.add_systems(Update, (update_hovered_hextile, report_failed_tile_textures, update_hextile_attachments, animate_hextiles));
+        app.add_systems(PostUpdate, (
+            reindex_changed_hextiles, 
+            update_hextile_z_order.before(TransformSystem::TransformPropagate)
+        ));
+
+        #[cfg(feature = "ecs_tilemap")]
+        if !app.is_plugin_added::<bevy_ecs_tilemap::TilemapPlugin>() {
+            app.add_plugins(bevy_ecs_tilemap::TilemapPlugin);
+        }
+    }
+}
+
+/// Small deterministic xorshift generator, so seeded features do not depend on an external crate
+pub(crate) fn next_random(state: &mut u64) -> f32 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    (*state >> 40) as f32 / (1u64 << 24) as f32
+}
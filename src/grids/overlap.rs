@@ -0,0 +1,302 @@
+use bevy::prelude::*;
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// Returns the `x` and `y` hexgrid coordinates of every tile of the [`HexGrid`] overlapped by an
+/// axis-aligned rectangle in world coordinates (e.g. the bounding box of a free-moving [`Entity`]),
+/// for tile-based triggers and terrain effects of entities which are not bound to the grid.
+/// 
+/// `grid_transform` is the [`GlobalTransform`] of the [`Entity`] containing the [`HexGrid`],
+/// which is assumed not to be rotated nor scaled.
+pub fn tiles_overlapping_aabb(hexgrid: &HexGrid, grid_transform: &GlobalTransform, aabb: Rect) -> Vec<(u32, u32)> {
+    let grid_translation = grid_transform.translation().truncate();
+    let local_aabb = Rect::from_corners(aabb.min - grid_translation, aabb.max - grid_translation);
+    let corners = hexgrid.hextile_corners();
+
+    candidate_tiles(hexgrid, local_aabb)
+        .filter(|&(col, row)| {
+            let center = HexTile::new(col, row).coord_to_world(hexgrid);
+            hexagon_overlaps_aabb(&corners.map(|corner| corner + center), local_aabb)
+        })
+        .collect()
+}
+
+/// Returns the `x` and `y` hexgrid coordinates of every tile of the [`HexGrid`] overlapped by a
+/// circle in world coordinates (e.g. the collision radius of a free-moving [`Entity`]).
+/// 
+/// `grid_transform` is the [`GlobalTransform`] of the [`Entity`] containing the [`HexGrid`],
+/// which is assumed not to be rotated nor scaled.
+pub fn tiles_overlapping_circle(hexgrid: &HexGrid, grid_transform: &GlobalTransform, center: Vec2, radius: f32) -> Vec<(u32, u32)> {
+    let local_center = center - grid_transform.translation().truncate();
+    let bounds = Rect::from_center_half_size(local_center, Vec2::splat(radius));
+    let corners = hexgrid.hextile_corners();
+
+    candidate_tiles(hexgrid, bounds)
+        .filter(|&(col, row)| {
+            let tile_center = HexTile::new(col, row).coord_to_world(hexgrid);
+            hexagon_overlaps_circle(&corners.map(|corner| corner + tile_center), local_center, radius)
+        })
+        .collect()
+}
+
+/// Returns the `x` and `y` hexgrid coordinates of the tile of the [`HexGrid`] containing a point 
+/// in world coordinates, if any.
+/// 
+/// `grid_transform` is the [`GlobalTransform`] of the [`Entity`] containing the [`HexGrid`],
+/// which is assumed not to be rotated nor scaled.
+pub fn tile_at_world_position(hexgrid: &HexGrid, grid_transform: &GlobalTransform, point: Vec2) -> Option<(u32, u32)> {
+    hexgrid.world_to_coord(grid_transform, point)
+}
+
+/// Returns the `x` and `y` hexgrid coordinates of the tile of the `target` [`HexGrid`] containing 
+/// the center of the tile at `coord` of the `source` [`HexGrid`], if any, e.g. to find which terrain 
+/// tile lies under a tile of a finer overlay grid, or to carry a coordinate over to an adjacent map section.
+/// 
+/// The transforms are the [`GlobalTransform`]s of the entities containing the grids,
+/// which are assumed not to be rotated nor scaled. Grids may differ in size, tile width and orientation.
+pub fn translate_tile_coord(
+    source: &HexGrid,
+    source_transform: &GlobalTransform,
+    coord: (u32, u32),
+    target: &HexGrid,
+    target_transform: &GlobalTransform,
+) -> Option<(u32, u32)> {
+    let center = HexTile::new(coord.0, coord.1).coord_to_world(source)
+        + source_transform.translation().truncate();
+
+    tile_at_world_position(target, target_transform, center)
+}
+
+/// Returns the `x` and `y` hexgrid coordinates of every tile of the `target` [`HexGrid`] overlapped 
+/// by the tile at `coord` of the `source` [`HexGrid`], e.g. the fine overlay tiles covering a coarse terrain tile.
+/// 
+/// The transforms are the [`GlobalTransform`]s of the entities containing the grids,
+/// which are assumed not to be rotated nor scaled. Grids may differ in size, tile width and orientation.
+pub fn tiles_overlapping_tile(
+    source: &HexGrid,
+    source_transform: &GlobalTransform,
+    coord: (u32, u32),
+    target: &HexGrid,
+    target_transform: &GlobalTransform,
+) -> Vec<(u32, u32)> {
+    // The source tile, relative to the center of the target grid
+    let offset = source_transform.translation().truncate() - target_transform.translation().truncate();
+    let source_center = HexTile::new(coord.0, coord.1).coord_to_world(source) + offset;
+    let source_hexagon = source.hextile_corners().map(|corner| corner + source_center);
+
+    let bounds = source_hexagon
+        .iter()
+        .fold(Rect::from_center_size(source_center, Vec2::ZERO), |bounds, &corner| bounds.union_point(corner));
+    let target_corners = target.hextile_corners();
+
+    candidate_tiles(target, bounds)
+        .filter(|&(col, row)| {
+            let center = HexTile::new(col, row).coord_to_world(target);
+            convex_polygons_overlap(&target_corners.map(|corner| corner + center), &source_hexagon)
+        })
+        .collect()
+}
+
+/// Iterates over the coordinates of the tiles whose bounding box may overlap a rectangle relative to the grid [`Entity`]
+fn candidate_tiles(hexgrid: &HexGrid, local_bounds: Rect) -> impl Iterator<Item = (u32, u32)> {
+    let origin = HexTile::new(0, 0).coord_to_world(hexgrid);
+
+    // Distances between neighboring columns and rows
+    let Vec2 { x: column_step, y: row_step } = hexgrid.tile_step();
+
+    // One extra tile on every side covers the half-tile offsets of odd columns or rows and the tile extents
+    let index_range = |min: f32, max: f32, origin: f32, step: f32, count: u32| {
+        let first = (((min - origin) / step).floor() as i64 - 1).max(0);
+        let last = (((max - origin) / step).ceil() as i64 + 1).min(count as i64 - 1);
+        first..=last
+    };
+
+    let columns = index_range(local_bounds.min.x, local_bounds.max.x, origin.x, column_step, hexgrid.columns);
+    let rows = index_range(local_bounds.min.y, local_bounds.max.y, origin.y, row_step, hexgrid.rows);
+
+    columns.flat_map(move |col| rows.clone().map(move |row| (col as u32, row as u32)))
+}
+
+/// Separating axis test between a hexagon and an axis-aligned rectangle
+fn hexagon_overlaps_aabb(hexagon: &[Vec2; 6], aabb: Rect) -> bool {
+    let aabb_corners = [aabb.min, Vec2::new(aabb.max.x, aabb.min.y), aabb.max, Vec2::new(aabb.min.x, aabb.max.y)];
+
+    convex_polygons_overlap(hexagon, &aabb_corners)
+}
+
+/// Separating axis test between two convex polygons with corners in order
+fn convex_polygons_overlap(a: &[Vec2], b: &[Vec2]) -> bool {
+    let project = |points: &[Vec2], axis: Vec2| {
+        points.iter().fold((f32::MAX, f32::MIN), |(min, max), point| {
+            let projection = point.dot(axis);
+            (min.min(projection), max.max(projection))
+        })
+    };
+
+    // The normals of the edges of both polygons
+    let edge_normals = |points: &[Vec2]| (0..points.len())
+        .map(|index| (points[(index + 1) % points.len()] - points[index]).perp())
+        .collect::<Vec<Vec2>>();
+
+    edge_normals(a).into_iter().chain(edge_normals(b)).all(|axis| {
+        let (a_min, a_max) = project(a, axis);
+        let (b_min, b_max) = project(b, axis);
+        a_min <= b_max && b_min <= a_max
+    })
+}
+
+/// Whether a circle overlaps a convex hexagon with counter-clockwise corners
+fn hexagon_overlaps_circle(hexagon: &[Vec2; 6], center: Vec2, radius: f32) -> bool {
+    let mut inside = true;
+
+    for index in 0..6 {
+        let start = hexagon[index];
+        let end = hexagon[(index + 1) % 6];
+        let edge = end - start;
+
+        // The center is on the outer side of this edge
+        if edge.perp_dot(center - start) < 0. {
+            inside = false;
+        }
+
+        let closest = start + edge * ((center - start).dot(edge) / edge.length_squared()).clamp(0., 1.);
+        if closest.distance_squared(center) <= radius * radius {
+            return true;
+        }
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::grids::hexgrid::HexGridOrientation;
+
+    fn hexgrid() -> HexGrid {
+        HexGrid::with_id(0, HexGridOrientation::Vertical, 6, 5, 64.)
+    }
+
+    fn grid_transform() -> GlobalTransform {
+        GlobalTransform::from_translation(Vec3::new(-80., 35., 0.))
+    }
+
+    fn coords(hexgrid: &HexGrid) -> Vec<(u32, u32)> {
+        (0..hexgrid.columns).flat_map(|x| (0..hexgrid.rows).map(move |y| (x, y))).collect()
+    }
+
+    fn tile_center(hexgrid: &HexGrid, (x, y): (u32, u32)) -> Vec2 {
+        HexTile::new(x, y).coord_to_world(hexgrid) 
+            + grid_transform().translation().truncate()
+    }
+
+    #[test]
+    fn small_shapes_at_a_tile_center_only_overlap_that_tile() {
+        let hexgrid = hexgrid();
+
+        for coord in coords(&hexgrid) {
+            let center = tile_center(&hexgrid, coord);
+
+            assert_eq!(tiles_overlapping_aabb(&hexgrid, &grid_transform(), Rect::from_center_size(center, Vec2::splat(4.))), vec![coord]);
+            assert_eq!(tiles_overlapping_circle(&hexgrid, &grid_transform(), center, 2.), vec![coord]);
+        }
+    }
+
+    #[test]
+    fn large_shapes_overlap_every_tile() {
+        let hexgrid = hexgrid();
+        let every_tile: HashSet<(u32, u32)> = coords(&hexgrid).into_iter().collect();
+
+        let bounds = coords(&hexgrid)
+            .into_iter()
+            .map(|coord| Rect::from_center_size(tile_center(&hexgrid, coord), Vec2::splat(hexgrid.hextile_width)))
+            .reduce(|bounds, tile_bounds| bounds.union(tile_bounds))
+            .unwrap();
+
+        let overlapped: HashSet<(u32, u32)> = tiles_overlapping_aabb(&hexgrid, &grid_transform(), bounds).into_iter().collect();
+        assert_eq!(overlapped, every_tile);
+
+        let radius = bounds.size().length();
+        let overlapped: HashSet<(u32, u32)> = tiles_overlapping_circle(&hexgrid, &grid_transform(), bounds.center(), radius).into_iter().collect();
+        assert_eq!(overlapped, every_tile);
+    }
+
+    #[test]
+    fn shapes_outside_of_the_grid_overlap_nothing() {
+        let hexgrid = hexgrid();
+        let far_away = tile_center(&hexgrid, (5, 4)) + Vec2::splat(500.);
+
+        assert!(tiles_overlapping_aabb(&hexgrid, &grid_transform(), Rect::from_center_size(far_away, Vec2::splat(10.))).is_empty());
+        assert!(tiles_overlapping_circle(&hexgrid, &grid_transform(), far_away, 10.).is_empty());
+    }
+
+    #[test]
+    fn shapes_between_tiles_overlap_both_of_them() {
+        let hexgrid = hexgrid();
+        let (a, b) = (tile_center(&hexgrid, (2, 2)), tile_center(&hexgrid, (2, 3)));
+
+        let overlapped: HashSet<(u32, u32)> = tiles_overlapping_circle(&hexgrid, &grid_transform(), (a + b) / 2., 2.).into_iter().collect();
+        assert_eq!(overlapped, HashSet::from([(2, 2), (2, 3)]));
+    }
+
+    #[test]
+    fn tile_at_world_position_finds_the_tile_under_a_point() {
+        let hexgrid = hexgrid();
+
+        for coord in coords(&hexgrid) {
+            assert_eq!(tile_at_world_position(&hexgrid, &grid_transform(), tile_center(&hexgrid, coord)), Some(coord));
+        }
+        assert_eq!(tile_at_world_position(&hexgrid, &grid_transform(), tile_center(&hexgrid, (5, 4)) + Vec2::splat(500.)), None);
+    }
+
+    #[test]
+    fn translating_between_identical_grids_keeps_the_coordinates() {
+        let hexgrid = hexgrid();
+
+        for coord in coords(&hexgrid) {
+            assert_eq!(translate_tile_coord(&hexgrid, &grid_transform(), coord, &hexgrid, &grid_transform()), Some(coord));
+        }
+    }
+
+    #[test]
+    fn translating_to_a_shifted_grid_follows_the_offset() {
+        let hexgrid = hexgrid();
+
+        // Moving a grid by one column step and one row shifts its coordinates by one column
+        let step = tile_center(&hexgrid, (2, 2)) - tile_center(&hexgrid, (1, 1));
+        let shifted_transform = GlobalTransform::from_translation(grid_transform().translation() + step.extend(0.));
+
+        assert_eq!(translate_tile_coord(&hexgrid, &grid_transform(), (2, 2), &hexgrid, &shifted_transform), Some((1, 1)));
+        assert_eq!(translate_tile_coord(&hexgrid, &grid_transform(), (0, 0), &hexgrid, &shifted_transform), None);
+    }
+
+    #[test]
+    fn a_tile_overlaps_itself_and_at_most_its_neighbors() {
+        let hexgrid = hexgrid();
+
+        for coord in coords(&hexgrid) {
+            let overlapped = tiles_overlapping_tile(&hexgrid, &grid_transform(), coord, &hexgrid, &grid_transform());
+            let neighbors = HexTile::new(coord.0, coord.1).get_neighbors(hexgrid.columns, hexgrid.rows, hexgrid.orientation);
+
+            assert!(overlapped.contains(&coord));
+            assert!(overlapped.iter().all(|tile| *tile == coord || neighbors.contains(tile)));
+        }
+    }
+
+    #[test]
+    fn a_coarse_tile_overlaps_the_fine_tiles_under_it() {
+        let coarse = HexGrid::with_id(0, HexGridOrientation::Vertical, 3, 3, 128.);
+        let fine = HexGrid::with_id(1, HexGridOrientation::Vertical, 9, 9, 32.);
+
+        let overlapped = tiles_overlapping_tile(&coarse, &grid_transform(), (1, 1), &fine, &grid_transform());
+        let under_center = translate_tile_coord(&coarse, &grid_transform(), (1, 1), &fine, &grid_transform()).unwrap();
+
+        assert!(overlapped.contains(&under_center));
+        assert!(overlapped.len() > 1);
+    }
+}
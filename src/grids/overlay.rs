@@ -0,0 +1,223 @@
+use bevy::{
+    asset::embedded_asset, 
+    prelude::*, 
+    render::render_resource::{
+        AsBindGroup, 
+        ShaderRef, 
+        ShaderType
+    }, 
+    sprite::{
+        AlphaMode2d, 
+        Material2d, 
+        Material2dPlugin
+    }
+};
+
+use super::hexgrid::{
+    HexGrid, 
+    HexGridOrientation, 
+    HexTile
+};
+use crate::{
+    error::{
+        Bevy2dUtilError, 
+        ErrorReporter
+    }, 
+    MainCameraFilter
+};
+
+/// A [`Plugin`] for drawing the lines of a whole [`HexGrid`] with a single shader, rather than 
+/// per-tile outline sprites, so the lines can be toggled cheaply even on huge maps.
+/// 
+/// Spawn overlays with [`build_spawn_hexgrid_overlay_system`].
+#[derive(Clone, Default)]
+pub struct HexGridOverlayPlugin;
+
+impl Plugin for HexGridOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "shaders/hex_grid_overlay.wgsl");
+
+        app.register_type::<HexGridOverlay>();
+
+        app.add_plugins(Material2dPlugin::<HexGridOverlayMaterial>::default());
+        app.add_systems(Update, update_hexgrid_overlay_zoom);
+    }
+}
+
+/// Configurations of a hexgrid overlay
+#[derive(Clone, Copy)]
+pub struct HexGridOverlayConfigs {
+    pub color: Color,
+
+    /// Line thickness in screen pixels, kept constant while zooming
+    pub thickness: f32,
+
+    /// `OrthographicProjection.scale` at which the lines start fading out
+    pub fade_start: f32,
+
+    /// `OrthographicProjection.scale` at which the lines are fully faded out
+    pub fade_end: f32,
+}
+
+impl Default for HexGridOverlayConfigs {
+    fn default() -> Self {
+        HexGridOverlayConfigs {
+            color: Color::srgba(0., 0., 0., 0.6),
+            thickness: 1.5,
+            fade_start: 2.,
+            fade_end: 4.,
+        }
+    }
+}
+
+/// Uniform data of the [`HexGridOverlayMaterial`], mirrored by the overlay shader
+#[derive(ShaderType, Clone, Copy, Debug)]
+pub struct HexGridOverlaySettings {
+    pub color: Vec4,
+    /// Size of the overlay quad
+    pub size: Vec2,
+    /// Position of the center of tile `(0, 0)` relative to the bottom-left corner of the quad
+    pub origin: Vec2,
+    /// Distances between the centers of neighboring columns and rows (see [`HexGrid::tile_step()`])
+    pub tile_step: Vec2,
+    pub hextile_width: f32,
+    pub hextile_height: f32,
+    pub thickness: f32,
+    pub zoom: f32,
+    pub fade_start: f32,
+    pub fade_end: f32,
+    /// `0` for vertical, `1` for horizontal
+    pub orientation: u32,
+    pub columns: u32,
+    pub rows: u32,
+}
+
+/// The [`Material2d`] drawing the lines of a [`HexGrid`] over a single quad
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct HexGridOverlayMaterial {
+    #[uniform(0)]
+    pub settings: HexGridOverlaySettings,
+}
+
+impl Material2d for HexGridOverlayMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "embedded://bevy2d_utilities/grids/shaders/hex_grid_overlay.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
+
+/// A [`Component`] marking the overlay [`Entity`] of the [`HexGrid`] with the given id, 
+/// spawned as a child of the grid.
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexGridOverlay {
+    pub grid_id: u64,
+}
+
+/// Returns the `(center, size)` of the rectangle covering all tiles of a [`HexGrid`], 
+/// relative to the grid, and the position of tile `(0, 0)`.
+fn overlay_rect(hexgrid: &HexGrid) -> (Vec2, Vec2, Vec2) {
+    let bounds = hexgrid.local_bounds();
+
+    (bounds.center(), bounds.size(), HexTile::new(0, 0).coord_to_world(hexgrid))
+}
+
+/// Builds a [`System`] closure which spawns a shader-drawn line overlay over the [`HexGrid`] 
+/// specified by id, as a child [`Entity`] with a [`HexGridOverlay`] component placed above the tiles.
+/// 
+/// Requires the [`HexGridOverlayPlugin`].
+/// 
+/// Can be added to the [`Startup`] schedule if set to run after the grid has 
+/// been spawned, also can be used as a one-shot system.
+pub fn build_spawn_hexgrid_overlay_system(grid_id: u64, overlay_configs: HexGridOverlayConfigs) -> impl FnMut(
+    Commands,
+    ResMut<Assets<Mesh>>,
+    ResMut<Assets<HexGridOverlayMaterial>>,
+    Query<(Entity, &HexGrid)>,
+    ErrorReporter,
+) {
+    move |
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<HexGridOverlayMaterial>>,
+        hexgrids: Query<(Entity, &HexGrid)>,
+        mut error_reporter: ErrorReporter
+    | {
+        let Some((grid_entity, hexgrid)) = hexgrids.iter().find(|(_, hexgrid)| hexgrid.id == grid_id) else {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return;
+        };
+
+        let (center, size, origin) = overlay_rect(hexgrid);
+
+        let material = materials.add(HexGridOverlayMaterial {
+            settings: HexGridOverlaySettings {
+                color: overlay_configs.color.to_linear().to_vec4(),
+                size,
+                origin: origin - (center - size / 2.),
+                tile_step: hexgrid.tile_step(),
+                hextile_width: hexgrid.hextile_width,
+                hextile_height: hexgrid.hextile_height(),
+                thickness: overlay_configs.thickness,
+                zoom: 1.,
+                fade_start: overlay_configs.fade_start,
+                fade_end: overlay_configs.fade_end,
+                orientation: match hexgrid.orientation {
+                    HexGridOrientation::Vertical => 0,
+                    HexGridOrientation::Horizontal => 1,
+                },
+                columns: hexgrid.columns,
+                rows: hexgrid.rows,
+            },
+        });
+
+        commands.spawn((
+            HexGridOverlay { grid_id },
+            Mesh2d(meshes.add(Rectangle::from_size(size))),
+            MeshMaterial2d(material),
+            Transform::from_translation(center.extend(1.)),
+            // The grid itself is hidden, only its children are visible
+            Visibility::Visible,
+            ChildOf(grid_entity),
+        ));
+    }
+}
+
+/// Builds a [`System`] closure which shows or hides the overlay of the [`HexGrid`] specified by id.
+/// 
+/// Can be used as a one-shot system, or run on a key press.
+pub fn build_toggle_hexgrid_overlay_system(grid_id: u64) -> impl FnMut(Query<(&HexGridOverlay, &mut Visibility)>) {
+    move | mut overlays: Query<(&HexGridOverlay, &mut Visibility)> | {
+        for (overlay, mut visibility) in &mut overlays {
+            if overlay.grid_id == grid_id {
+                *visibility = match *visibility {
+                    Visibility::Hidden => Visibility::Visible,
+                    _ => Visibility::Hidden,
+                };
+            }
+        }
+    }
+}
+
+/// Feeds the main camera's `OrthographicProjection.scale` to the overlay materials, 
+/// so the lines keep their screen thickness and fade out when zoomed out
+fn update_hexgrid_overlay_zoom(
+    camera: Query<&Projection, MainCameraFilter>,
+    overlays: Query<&MeshMaterial2d<HexGridOverlayMaterial>, With<HexGridOverlay>>,
+    mut materials: ResMut<Assets<HexGridOverlayMaterial>>,
+) {
+    let Ok(Projection::Orthographic(ortho)) = camera.single() else { return; };
+
+    for overlay_material in &overlays {
+        let Some(material) = materials.get(&overlay_material.0) else { continue; };
+
+        if material.settings.zoom != ortho.scale {
+            if let Some(material) = materials.get_mut(&overlay_material.0) {
+                material.settings.zoom = ortho.scale;
+            }
+        }
+    }
+}
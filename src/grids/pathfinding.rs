@@ -0,0 +1,304 @@
+use bevy::prelude::*;
+
+use std::{
+    cmp::Reverse, 
+    collections::{
+        BinaryHeap, 
+        HashMap, 
+        HashSet, 
+        VecDeque
+    }
+};
+
+use super::{
+    hexgrid::HexGrid, 
+    registry::HexTileIndex
+};
+
+/// A [`Component`] holding the cost of entering the [`HexTile`](super::hexgrid::HexTile) [`Entity`] 
+/// it is inserted on (e.g. `1` for plains, `2` for forests, `3` for hills), for [`HexGrid::find_weighted_path()`].
+/// 
+/// Tiles without one are usually treated as costing `1`, see [`collect_movement_costs()`].
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MovementCost(pub u32);
+
+/// Returns the [`MovementCost`] of every tile of the [`HexGrid`] with the given id which has one, 
+/// by `x` and `y` hexgrid coordinates, to be looked up by the cost closure of [`HexGrid::find_weighted_path()`].
+pub fn collect_movement_costs(
+    grid_id: u64,
+    tile_index: &HexTileIndex,
+    movement_costs: &Query<&MovementCost>,
+) -> HashMap<(u32, u32), u32> {
+    tile_index
+        .iter_grid(grid_id)
+        .filter_map(|(x, y, tile_entity)| movement_costs.get(tile_entity).ok().map(|cost| ((x, y), cost.0)))
+        .collect()
+}
+
+impl HexGrid {
+    /// Returns the shortest path from `start` to `goal` (both included) as `x` and `y` hexgrid coordinates,
+    /// moving from neighbor to neighbor (see [`HexGrid::neighbors()`]) and never entering a tile in `impassable`.
+    /// 
+    /// Returns `None` if either end is outside of the grid or impassable, or if no path exists.
+    pub fn find_path(
+        &self,
+        start: (u32, u32),
+        goal: (u32, u32),
+        impassable: &HashSet<(u32, u32)>,
+    ) -> Option<Vec<(u32, u32)>> {
+        if impassable.contains(&start) {
+            return None;
+        }
+
+        self.find_weighted_path(start, goal, |tile| (!impassable.contains(&tile)).then_some(1))
+            .map(|(path, _)| path)
+    }
+
+    /// Returns the cheapest path from `start` to `goal` (both included) as `x` and `y` hexgrid coordinates, 
+    /// together with its total cost, moving from neighbor to neighbor (see [`HexGrid::neighbors()`]).
+    /// 
+    /// `cost` returns the cost of entering a tile, or `None` if it is impassable (see [`MovementCost`] 
+    /// and [`collect_movement_costs()`]). Costs below `1` are counted as `1`, so the hex distance 
+    /// stays an admissible heuristic. The cost of `start` itself is never paid.
+    /// 
+    /// Returns `None` if either end is outside of the grid, `goal` is impassable, or if no path exists.
+    pub fn find_weighted_path(
+        &self,
+        start: (u32, u32),
+        goal: (u32, u32),
+        cost: impl Fn((u32, u32)) -> Option<u32>,
+    ) -> Option<(Vec<(u32, u32)>, u32)> {
+        let in_bounds = |(x, y): (u32, u32)| x < self.columns && y < self.rows;
+        if !in_bounds(start) || !in_bounds(goal) || cost(goal).is_none() {
+            return None;
+        }
+
+        let heuristic = |tile: (u32, u32)| self.tile_distance(tile, goal);
+
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<(u32, u32), (u32, u32)> = HashMap::new();
+        let mut costs: HashMap<(u32, u32), u32> = HashMap::from([(start, 0)]);
+
+        open.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, path_cost, current))) = open.pop() {
+            if current == goal {
+                let mut path = vec![current];
+                let mut step = current;
+                while let Some(&previous) = came_from.get(&step) {
+                    path.push(previous);
+                    step = previous;
+                }
+                path.reverse();
+                return Some((path, path_cost));
+            }
+
+            // A cheaper way to this tile was queued after this entry
+            if costs.get(&current).is_some_and(|&best| path_cost > best) {
+                continue;
+            }
+
+            for neighbor in self.neighbors(current) {
+                let Some(step_cost) = cost(neighbor) else { continue; };
+
+                let neighbor_cost = path_cost + step_cost.max(1);
+                if costs.get(&neighbor).is_none_or(|&best| neighbor_cost < best) {
+                    costs.insert(neighbor, neighbor_cost);
+                    came_from.insert(neighbor, current);
+                    open.push(Reverse((neighbor_cost + heuristic(neighbor), neighbor_cost, neighbor)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns every tile reachable from `start` by spending at most `movement_points`, by `x` and `y` 
+    /// hexgrid coordinates, with the movement points remaining once there (`start` included, with all of them), 
+    /// e.g. to preview the moves of a unit in a turn-based game.
+    /// 
+    /// `cost` returns the cost of entering a tile, or `None` if it is impassable, counted like 
+    /// in [`HexGrid::find_weighted_path()`], so the cheapest path to any returned tile fits the budget.
+    /// 
+    /// Returns an empty map if `start` is outside of the grid.
+    pub fn reachable_tiles(
+        &self,
+        start: (u32, u32),
+        movement_points: u32,
+        cost: impl Fn((u32, u32)) -> Option<u32>,
+    ) -> HashMap<(u32, u32), u32> {
+        if start.0 >= self.columns || start.1 >= self.rows {
+            return HashMap::new();
+        }
+
+        let mut remaining = HashMap::from([(start, movement_points)]);
+        let mut open = BinaryHeap::from([(movement_points, start)]);
+
+        // Dijkstra, popping the tiles with the most movement points left first
+        while let Some((points, current)) = open.pop() {
+            // A cheaper way to this tile was queued after this entry
+            if remaining.get(&current).is_some_and(|&best| points < best) {
+                continue;
+            }
+
+            for neighbor in self.neighbors(current) {
+                let Some(step_cost) = cost(neighbor) else { continue; };
+                let Some(neighbor_points) = points.checked_sub(step_cost.max(1)) else { continue; };
+
+                if remaining.get(&neighbor).is_none_or(|&best| neighbor_points > best) {
+                    remaining.insert(neighbor, neighbor_points);
+                    open.push((neighbor_points, neighbor));
+                }
+            }
+        }
+
+        remaining
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the connected region of tiles reachable from `start` 
+    /// through tiles for which `is_passable` holds, `start` included, in breadth-first order.
+    /// 
+    /// Returns an empty region if `start` is outside of the grid or not passable itself. 
+    /// Useful for territory detection and "can this unit reach that area" checks.
+    pub fn flood_fill(&self, start: (u32, u32), is_passable: impl Fn((u32, u32)) -> bool) -> Vec<(u32, u32)> {
+        if start.0 >= self.columns || start.1 >= self.rows || !is_passable(start) {
+            return Vec::new();
+        }
+
+        let mut region = vec![start];
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some((x, y)) = queue.pop_front() {
+            for neighbor in self.neighbors((x, y)) {
+                if !visited.contains(&neighbor) && is_passable(neighbor) {
+                    visited.insert(neighbor);
+                    region.push(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        region
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::hexgrid::{
+        HexGridOrientation, 
+        HexTile
+    };
+
+    fn hexgrid() -> HexGrid {
+        HexGrid::with_id(0, HexGridOrientation::Vertical, 5, 5, 64.)
+    }
+
+    fn distance(a: (u32, u32), b: (u32, u32)) -> u32 {
+        HexTile::new(a.0, a.1).distance(&HexTile::new(b.0, b.1), HexGridOrientation::Vertical)
+    }
+
+    #[test]
+    fn path_on_an_open_grid_is_as_long_as_the_distance() {
+        let hexgrid = hexgrid();
+
+        for goal in hexgrid.iter_coords() {
+            let path = hexgrid.find_path((0, 0), goal, &HashSet::new()).unwrap();
+
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(&goal));
+            assert_eq!(path.len() as u32, distance((0, 0), goal) + 1);
+            assert!(path.windows(2).all(|step| distance(step[0], step[1]) == 1));
+        }
+    }
+
+    #[test]
+    fn path_goes_around_impassable_tiles() {
+        let hexgrid = hexgrid();
+
+        // A wall along the middle column, with a gap in its last row
+        let mut impassable: HashSet<(u32, u32)> = (0..4).map(|y| (2, y)).collect();
+        let path = hexgrid.find_path((0, 0), (4, 0), &impassable).unwrap();
+        assert!(path.iter().all(|tile| !impassable.contains(tile)));
+        assert!(path.contains(&(2, 4)));
+
+        impassable.insert((2, 4));
+        assert_eq!(hexgrid.find_path((0, 0), (4, 0), &impassable), None);
+    }
+
+    #[test]
+    fn path_fails_for_impassable_or_outside_ends() {
+        let hexgrid = hexgrid();
+        let impassable = HashSet::from([(3, 3)]);
+
+        assert_eq!(hexgrid.find_path((3, 3), (0, 0), &impassable), None);
+        assert_eq!(hexgrid.find_path((0, 0), (3, 3), &impassable), None);
+        assert_eq!(hexgrid.find_path((0, 0), (5, 0), &impassable), None);
+    }
+
+    #[test]
+    fn weighted_path_avoids_expensive_tiles() {
+        let hexgrid = hexgrid();
+        let cost = |tile: (u32, u32)| Some(if tile == (2, 1) { 10 } else { 1 });
+
+        // Going around the expensive tile takes one more step, but costs 3 instead of 11
+        let (path, total) = hexgrid.find_weighted_path((2, 0), (2, 2), cost).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(path.len(), 4);
+        assert!(!path.contains(&(2, 1)));
+    }
+
+    #[test]
+    fn weighted_path_cost_is_the_sum_of_the_entered_tiles() {
+        let hexgrid = hexgrid();
+
+        // Costs of 0 are counted as 1, the cost of the start tile is never paid
+        let cost = |(x, y): (u32, u32)| Some((x + 2 * y) % 4);
+        let (path, total) = hexgrid.find_weighted_path((0, 0), (4, 4), cost).unwrap();
+
+        assert_eq!(total, path[1..].iter().map(|&tile| cost(tile).unwrap().max(1)).sum::<u32>());
+
+        let (_, free_total) = hexgrid.find_weighted_path((0, 0), (4, 4), |_| Some(0)).unwrap();
+        assert_eq!(free_total, distance((0, 0), (4, 4)));
+
+        let (path, start_total) = hexgrid.find_weighted_path((0, 0), (0, 0), cost).unwrap();
+        assert_eq!((path, start_total), (vec![(0, 0)], 0));
+    }
+
+    #[test]
+    fn reachable_tiles_spend_the_movement_points() {
+        let hexgrid = hexgrid();
+        let center = (2, 2);
+
+        let reachable = hexgrid.reachable_tiles(center, 2, |_| Some(1));
+        let in_range: HashSet<(u32, u32)> = hexgrid.tiles_in_range(center, 2).into_iter().collect();
+
+        assert_eq!(reachable.keys().copied().collect::<HashSet<_>>(), in_range);
+        assert!(reachable.iter().all(|(&tile, &remaining)| remaining == 2 - distance(center, tile)));
+
+        // Expensive tiles can only be entered with enough points left, impassable ones never
+        let reachable = hexgrid.reachable_tiles(center, 2, |tile| match tile {
+            (2, 1) => Some(3),
+            (2, 3) => None,
+            _ => Some(1),
+        });
+        assert!(!reachable.contains_key(&(2, 1)));
+        assert!(!reachable.contains_key(&(2, 3)));
+        assert_eq!(reachable.get(&center), Some(&2));
+    }
+
+    #[test]
+    fn flood_fill_stays_within_passable_tiles() {
+        let hexgrid = hexgrid();
+        let wall: HashSet<(u32, u32)> = (0..5).map(|y| (2, y)).collect();
+
+        let region = hexgrid.flood_fill((0, 0), |tile| !wall.contains(&tile));
+        assert_eq!(region.len(), 10);
+        assert!(region.iter().all(|&(x, _)| x < 2));
+
+        assert!(hexgrid.flood_fill((2, 0), |tile| !wall.contains(&tile)).is_empty());
+        assert!(hexgrid.flood_fill((5, 0), |_| true).is_empty());
+    }
+}
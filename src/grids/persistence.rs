@@ -0,0 +1,93 @@
+use bevy::{
+    ecs::system::RunSystemOnce, 
+    prelude::*
+};
+use serde::{
+    Deserialize, 
+    Serialize
+};
+
+use std::path::{
+    Path, 
+    PathBuf
+};
+
+use super::hexgrid::{
+    build_change_hexgrid_textures_system, 
+    HexGrid, 
+    TileTextures
+};
+use crate::error::{
+    report_in_world, 
+    Bevy2dUtilError
+};
+
+/// The layout of a [`HexGrid`] and its texture assignment, as stored in a RON file by 
+/// [`save_hexgrid_to_file()`] and read by [`build_spawn_hexgrid_from_file_system()`].
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HexGridFile {
+    pub hexgrid: HexGrid,
+
+    /// `None` spawns the tiles without a [`Sprite`]
+    pub textures: Option<TileTextures>,
+}
+
+/// Writes the layout of a [`HexGrid`] and its texture assignment to a RON file at the given path.
+/// 
+/// Returns a [`Bevy2dUtilError::FileIo`] if the file cannot be written.
+pub fn save_hexgrid_to_file(
+    path: impl AsRef<Path>,
+    hexgrid: &HexGrid,
+    textures: Option<&TileTextures>,
+) -> Result<(), Bevy2dUtilError> {
+    let path = path.as_ref();
+    let file_io_error = |reason: String| Bevy2dUtilError::FileIo { path: path.display().to_string(), reason };
+
+    let file = HexGridFile { hexgrid: hexgrid.clone(), textures: textures.cloned() };
+    let serialized = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+        .map_err(|error| file_io_error(error.to_string()))?;
+
+    std::fs::write(path, serialized).map_err(|error| file_io_error(error.to_string()))
+}
+
+/// Reads a [`HexGridFile`] from a RON file at the given path.
+/// 
+/// Returns a [`Bevy2dUtilError::FileIo`] if the file cannot be read or parsed.
+pub fn load_hexgrid_from_file(path: impl AsRef<Path>) -> Result<HexGridFile, Bevy2dUtilError> {
+    let path = path.as_ref();
+    let file_io_error = |reason: String| Bevy2dUtilError::FileIo { path: path.display().to_string(), reason };
+
+    let serialized = std::fs::read_to_string(path).map_err(|error| file_io_error(error.to_string()))?;
+    ron::from_str(&serialized).map_err(|error| file_io_error(error.to_string()))
+}
+
+/// Builds an 'exclusive' [`System`] closure which reads a [`HexGridFile`] from a RON file at the given path, 
+/// spawns its [`HexGrid`] at the given translation (see [`HexGrid::build_spawn_hexgrid_entity_system()`]) 
+/// and applies its textures to the tiles.
+/// 
+/// Reports a [`Bevy2dUtilError::FileIo`] and spawns nothing if the file cannot be read.
+/// 
+/// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+pub fn build_spawn_hexgrid_from_file_system(path: impl Into<PathBuf>, hexgrid_translation: Vec3) -> impl FnMut(&mut World) {
+    let path = path.into();
+
+    move | world: &mut World | {
+        let file = match load_hexgrid_from_file(&path) {
+            Ok(file) => file,
+            Err(error) => {
+                report_in_world(world, error);
+                return;
+            }
+        };
+        let grid_id = file.hexgrid.id;
+
+        let mut spawn_hexgrid = file.hexgrid.build_spawn_hexgrid_entity_system(hexgrid_translation);
+        spawn_hexgrid(world);
+
+        if let Some(textures) = file.textures {
+            if let Err(error) = world.run_system_once(build_change_hexgrid_textures_system(textures, grid_id)) {
+                warn!("{error}");
+            }
+        }
+    }
+}
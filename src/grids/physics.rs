@@ -0,0 +1,139 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// A [`Plugin`] generating static [`avian2d`] colliders for [`HexTile`]s marked with [`HexTileBlocked`],
+/// so physics objects collide with the map.
+/// 
+/// Contiguous blocked tiles are merged into a single compound collider per region, spawned as a
+/// child of the grid with a [`HexGridCollider`] component. The colliders of a grid are rebuilt
+/// whenever one of its tiles becomes blocked or unblocked, or its [`HexGrid`] is changed 
+/// (e.g. resized or given a different hextile width).
+/// 
+/// Requires the `PhysicsPlugins` of [`avian2d`].
+#[derive(Clone, Default)]
+pub struct HexGridPhysicsPlugin;
+
+impl Plugin for HexGridPhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexTileBlocked>();
+        app.register_type::<HexGridCollider>();
+
+        app.add_systems(Update, rebuild_hexgrid_colliders);
+    }
+}
+
+/// A [`Component`] marking a [`HexTile`] [`Entity`] as impassable for physics objects.
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HexTileBlocked;
+
+/// A [`Component`] marking the [`Entity`] holding the collider of a region of contiguous blocked tiles.
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component)]
+pub struct HexGridCollider {
+    pub grid_id: u64,
+
+    /// The `x` and `y` hexgrid coordinates of the tiles of the region
+    pub tiles: Vec<(u32, u32)>,
+}
+
+/// Returns the regions of contiguous tiles among the given coordinates
+fn contiguous_regions(hexgrid: &HexGrid, tiles: &HashSet<(u32, u32)>) -> Vec<Vec<(u32, u32)>> {
+    let mut visited = HashSet::new();
+    let mut regions = Vec::new();
+
+    // Sorted so regions are built in the same order every time
+    let mut starts: Vec<(u32, u32)> = tiles.iter().copied().collect();
+    starts.sort_unstable();
+
+    for start in starts {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let region = hexgrid.flood_fill(start, |tile| tiles.contains(&tile));
+        visited.extend(region.iter().copied());
+        regions.push(region);
+    }
+
+    regions
+}
+
+/// Rebuilds the colliders of grids in which tiles became blocked or unblocked, or which were changed
+fn rebuild_hexgrid_colliders(
+    mut commands: Commands,
+    added_blocked: Query<&ChildOf, (With<HexTile>, Added<HexTileBlocked>)>,
+    mut removed_blocked: RemovedComponents<HexTileBlocked>,
+    parents: Query<&ChildOf>,
+    blocked_tiles: Query<(&HexTile, &ChildOf), With<HexTileBlocked>>,
+    hexgrids: Query<(Entity, &HexGrid)>,
+    changed_hexgrids: Query<Entity, Changed<HexGrid>>,
+    colliders: Query<(Entity, &ChildOf), With<HexGridCollider>>,
+) {
+    let mut dirty_grids: HashSet<Entity> = added_blocked.iter().map(|child_of| child_of.parent()).collect();
+    dirty_grids.extend(changed_hexgrids.iter());
+    let mut rebuild_all = false;
+
+    for tile_entity in removed_blocked.read() {
+        match parents.get(tile_entity) {
+            Ok(child_of) => { dirty_grids.insert(child_of.parent()); }
+            // The tile was despawned, its grid is unknown
+            Err(_) => rebuild_all = true,
+        }
+    }
+
+    if rebuild_all {
+        dirty_grids.extend(colliders.iter().map(|(_, child_of)| child_of.parent()));
+    }
+    if dirty_grids.is_empty() {
+        return;
+    }
+
+    let mut blocked_per_grid: HashMap<Entity, HashSet<(u32, u32)>> = HashMap::new();
+    for (hextile, child_of) in &blocked_tiles {
+        if dirty_grids.contains(&child_of.parent()) {
+            blocked_per_grid.entry(child_of.parent()).or_default().insert((hextile.x, hextile.y));
+        }
+    }
+
+    for (collider_entity, child_of) in &colliders {
+        if dirty_grids.contains(&child_of.parent()) {
+            commands.entity(collider_entity).despawn();
+        }
+    }
+
+    for grid_entity in dirty_grids {
+        let Ok((_, hexgrid)) = hexgrids.get(grid_entity) else { continue; };
+        let Some(blocked) = blocked_per_grid.get(&grid_entity) else { continue; };
+
+        let Some(hexagon) = Collider::convex_hull(hexgrid.hextile_corners().to_vec()) else { continue; };
+
+        for region in contiguous_regions(hexgrid, blocked) {
+            let shapes = region
+                .iter()
+                .map(|&(x, y)| {
+                    let center = HexTile::new(x, y).coord_to_world(hexgrid);
+                    (Position::from_xy(center.x, center.y), Rotation::default(), hexagon.clone())
+                })
+                .collect();
+
+            commands.spawn((
+                HexGridCollider { grid_id: hexgrid.id, tiles: region },
+                RigidBody::Static,
+                Collider::compound(shapes),
+                Transform::default(),
+                ChildOf(grid_entity),
+            ));
+        }
+    }
+}
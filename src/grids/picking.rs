@@ -0,0 +1,83 @@
+use bevy::{
+    prelude::*, 
+    window::PrimaryWindow
+};
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    registry::HexTileIndex
+};
+use crate::MainCameraFilter;
+
+/// Describes a particular [`HexTile`] picked in the world.
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+pub struct HexTilePick {
+    /// The `id` of the [`HexGrid`] containing the tile
+    pub grid_id: u64,
+
+    /// The `x` and `y` hexgrid coordinates of the tile
+    pub coord: (u32, u32),
+
+    /// The [`Entity`] containing the [`HexTile`]
+    pub entity: Entity,
+}
+
+/// A [`Resource`] holding the [`HexTile`] currently under the cursor of the primary window, if any.
+/// 
+/// Kept up to date by the [`GridsPlugin`](super::GridsPlugin).
+#[derive(Resource, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct HoveredHexTile {
+    pub hovered: Option<HexTilePick>,
+}
+
+/// Returns the cursor position of the primary window in world coordinates, as seen by the main camera.
+pub(crate) fn cursor_world_position(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    cameras: &Query<(&Camera, &GlobalTransform), MainCameraFilter>,
+) -> Option<Vec2> {
+    let window = windows.single().ok()?;
+    let cursor_position = window.cursor_position()?;
+    let (camera, camera_transform) = cameras.single().ok()?;
+
+    camera.viewport_to_world_2d(camera_transform, cursor_position).ok()
+}
+
+/// Updates the [`HoveredHexTile`] resource with the tile containing the cursor, preferring the tile 
+/// whose center is the closest when grids overlap
+pub(crate) fn update_hovered_hextile(
+    mut hovered_hextile: ResMut<HoveredHexTile>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), MainCameraFilter>,
+    hexgrids: Query<(&HexGrid, &GlobalTransform)>,
+    tile_transforms: Query<&GlobalTransform, With<HexTile>>,
+    tile_index: Res<HexTileIndex>,
+) {
+    let mut closest: Option<(f32, HexTilePick)> = None;
+
+    if let Some(cursor_world) = cursor_world_position(&windows, &cameras) {
+        for (hexgrid, grid_transform) in &hexgrids {
+            let Some((x, y)) = hexgrid.world_to_coord(grid_transform, cursor_world) else { continue; };
+            let Some(tile_entity) = tile_index.get_tile_entity(hexgrid.id, x, y) else { continue; };
+            let Ok(tile_transform) = tile_transforms.get(tile_entity) else { continue; };
+
+            let distance = tile_transform.translation().truncate().distance(cursor_world);
+
+            if closest.is_none_or(|(closest_distance, _)| distance < closest_distance) {
+                closest = Some((distance, HexTilePick {
+                    grid_id: hexgrid.id,
+                    coord: (x, y),
+                    entity: tile_entity,
+                }));
+            }
+        }
+    }
+
+    let hovered = closest.map(|(_, pick)| pick);
+    if hovered_hextile.hovered != hovered {
+        hovered_hextile.hovered = hovered;
+    }
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use super::picking::{
+    HexTilePick, 
+    HoveredHexTile
+};
+use crate::input::InputActions;
+
+/// Configurations of the ghost sprite shown by [`build_placement_preview_system()`]
+#[derive(Clone)]
+pub struct PlacementPreviewConfigs {
+    /// Path towards the image of the ghost
+    pub texture: String,
+
+    /// Pixel size of the ghost sprite
+    pub size: Vec2,
+
+    /// Tint of the ghost over tiles where placement is valid
+    pub valid_color: Color,
+
+    /// Tint of the ghost over tiles where placement is invalid
+    pub invalid_color: Color,
+}
+
+impl Default for PlacementPreviewConfigs {
+    fn default() -> Self {
+        PlacementPreviewConfigs {
+            texture: String::new(),
+            size: Vec2::splat(64.),
+            valid_color: Color::srgba(0.3, 1., 0.3, 0.6),
+            invalid_color: Color::srgba(1., 0.3, 0.3, 0.6),
+        }
+    }
+}
+
+/// A [`Component`] marking the ghost [`Entity`] spawned by [`build_placement_preview_system()`].
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct PlacementGhost;
+
+/// An [`Event`] sent by [`build_placement_preview_system()`] when the left mouse button is
+/// pressed over a tile where placement is valid.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlacementConfirmed {
+    pub pick: HexTilePick,
+}
+
+/// Builds a [`System`] closure showing a ghost sprite snapped to the [`HoveredHexTile`], tinted by
+/// whether placement is valid there according to the `is_valid` predicate, and sending a
+/// [`PlacementConfirmed`] event when the left mouse button is pressed over a valid tile.
+/// 
+/// If the [`InputActions`] resource exists, its `confirm` action is used instead of the left mouse button.
+/// 
+/// The ghost is spawned the first time the system runs and hidden while no tile is hovered,
+/// despawn it with [`despawn_placement_ghost`] when leaving the build mode.
+/// 
+/// Should be added to the [`Update`] schedule, e.g. with a run condition on the build mode.
+pub fn build_placement_preview_system(
+    preview_configs: PlacementPreviewConfigs,
+    is_valid: impl Fn(&HexTilePick) -> bool + Send + Sync + 'static,
+) -> impl FnMut(
+    Commands,
+    Res<AssetServer>,
+    Res<HoveredHexTile>,
+    Res<ButtonInput<MouseButton>>,
+    Option<Res<InputActions>>,
+    Query<&GlobalTransform>,
+    Query<(&mut Sprite, &mut Transform, &mut Visibility), With<PlacementGhost>>,
+    EventWriter<PlacementConfirmed>,
+) {
+    move |
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        hovered_hextile: Res<HoveredHexTile>,
+        mouse_buttons: Res<ButtonInput<MouseButton>>,
+        actions: Option<Res<InputActions>>,
+        tile_transforms: Query<&GlobalTransform>,
+        mut ghosts: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<PlacementGhost>>,
+        mut placement_writer: EventWriter<PlacementConfirmed>
+    | {
+        let Ok((mut sprite, mut transform, mut visibility)) = ghosts.single_mut() else {
+            commands.spawn((
+                PlacementGhost,
+                Sprite {
+                    custom_size: Some(preview_configs.size),
+                    image: asset_server.load(&preview_configs.texture),
+                    color: preview_configs.invalid_color,
+                    ..Default::default()
+                },
+                Transform::default(),
+                Visibility::Hidden,
+            ));
+            return;
+        };
+
+        let Some(pick) = hovered_hextile.hovered else {
+            if *visibility != Visibility::Hidden {
+                *visibility = Visibility::Hidden;
+            }
+            return;
+        };
+        let Ok(tile_transform) = tile_transforms.get(pick.entity) else { return; };
+
+        let valid = is_valid(&pick);
+
+        // Drawn above the tile and whatever it carries
+        transform.translation = tile_transform.translation() + Vec3::Z * 10.;
+        sprite.color = if valid { preview_configs.valid_color } else { preview_configs.invalid_color };
+        *visibility = Visibility::Visible;
+
+        let confirmed = match &actions {
+            Some(actions) => actions.confirm,
+            None => mouse_buttons.just_pressed(MouseButton::Left),
+        };
+
+        if valid && confirmed {
+            placement_writer.write(PlacementConfirmed { pick });
+        }
+    }
+}
+
+/// Despawns the ghost of the placement preview, e.g. as a one-shot system when leaving the build mode.
+pub fn despawn_placement_ghost(
+    mut commands: Commands,
+    ghosts: Query<Entity, With<PlacementGhost>>,
+) {
+    for ghost in &ghosts {
+        commands.entity(ghost).despawn();
+    }
+}
@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use std::collections::{
+    HashMap, 
+    HashSet
+};
+
+use super::registry::HexTileIndex;
+
+/// A [`Plugin`] grouping tiles into named regions (e.g. the territory of a faction in a 4X game):
+/// tiles with a [`HexRegion`] component belong to the region of that name in their grid.
+/// 
+/// Keeps the [`HexRegionIndex`] resource up to date to query the region of a tile and the tiles
+/// of a region, and sends a [`HexRegionChanged`] event whenever a tile changes region.
+#[derive(Clone, Default)]
+pub struct HexRegionsPlugin;
+
+impl Plugin for HexRegionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HexRegion>();
+
+        app.init_resource::<HexRegionIndex>();
+
+        app.add_event::<HexRegionChanged>();
+
+        app.add_systems(PostUpdate, update_hexregion_index);
+    }
+}
+
+/// A [`Component`] assigning the [`HexTile`](super::hexgrid::HexTile) [`Entity`] it is inserted on
+/// to the region of the given name in its grid. Remove it to leave the tile without a region.
+#[derive(Component, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(Component)]
+pub struct HexRegion(pub String);
+
+/// An [`Event`] sent when a tile joins, leaves or changes region, after the [`HexRegionIndex`] was updated.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct HexRegionChanged {
+    pub grid_id: u64,
+    pub tile_entity: Entity,
+
+    /// The `x` and `y` hexgrid coordinates of the tile
+    pub coord: (u32, u32),
+
+    /// The region the tile belonged to, `None` if it had none
+    pub previous: Option<String>,
+
+    /// The region the tile belongs to now, `None` if it has none (e.g. it was despawned)
+    pub current: Option<String>,
+}
+
+/// A [`Resource`] mapping tiles to their [`HexRegion`] and regions to their tiles, per grid.
+/// 
+/// Kept up to date by the [`HexRegionsPlugin`] at the end of every frame.
+#[derive(Resource, Default)]
+pub struct HexRegionIndex {
+    regions: HashMap<(u64, u32, u32), String>,
+    tiles: HashMap<(u64, String), HashSet<(u32, u32)>>,
+
+    /// Reverse lookup, as the tile may already be gone when its region is removed
+    keys: HashMap<Entity, (u64, u32, u32)>,
+}
+
+impl HexRegionIndex {
+    /// Returns the name of the region of the tile at `(x, y)` in the [`HexGrid`](super::hexgrid::HexGrid) with the given id.
+    pub fn region_of(&self, grid_id: u64, (x, y): (u32, u32)) -> Option<&str> {
+        self.regions.get(&(grid_id, x, y)).map(String::as_str)
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the tiles of a region of the grid with the given id,
+    /// e.g. to draw its border with [`build_spawn_region_border_system()`](super::borders::build_spawn_region_border_system).
+    pub fn region_tiles(&self, grid_id: u64, region: &str) -> Option<&HashSet<(u32, u32)>> {
+        self.tiles.get(&(grid_id, region.to_string()))
+    }
+
+    /// Iterates over the names of the regions of the grid with the given id, in no particular order.
+    pub fn regions(&self, grid_id: u64) -> impl Iterator<Item = &str> + '_ {
+        self.tiles
+            .keys()
+            .filter(move |(region_grid_id, _)| *region_grid_id == grid_id)
+            .map(|(_, region)| region.as_str())
+    }
+
+    /// Returns the region the tile was in before
+    fn insert(&mut self, tile_entity: Entity, key: (u64, u32, u32), region: String) -> Option<String> {
+        let previous = self.remove(tile_entity).map(|(_, previous)| previous);
+
+        let (grid_id, x, y) = key;
+        self.tiles.entry((grid_id, region.clone())).or_default().insert((x, y));
+        self.regions.insert(key, region);
+        self.keys.insert(tile_entity, key);
+
+        previous
+    }
+
+    /// Returns the key of the tile and the region it was in
+    fn remove(&mut self, tile_entity: Entity) -> Option<((u64, u32, u32), String)> {
+        let key = self.keys.remove(&tile_entity)?;
+        let region = self.regions.remove(&key)?;
+
+        let (grid_id, x, y) = key;
+        let region_key = (grid_id, region);
+        if let Some(region_tiles) = self.tiles.get_mut(&region_key) {
+            region_tiles.remove(&(x, y));
+
+            if region_tiles.is_empty() {
+                self.tiles.remove(&region_key);
+            }
+        }
+
+        Some((key, region_key.1))
+    }
+}
+
+/// Assigns the tiles at the given `x` and `y` hexgrid coordinates of the [`HexGrid`](super::hexgrid::HexGrid)
+/// specified by id to `region`, or removes them from their region if `None`.
+/// 
+/// Coordinates without a spawned tile are skipped, returns the amount of tiles which were assigned.
+pub fn assign_hextiles_to_region(
+    commands: &mut Commands,
+    tile_index: &HexTileIndex,
+    grid_id: u64,
+    tiles: impl IntoIterator<Item = (u32, u32)>,
+    region: Option<&str>,
+) -> usize {
+    let mut assigned = 0;
+
+    for (x, y) in tiles {
+        let Some(tile_entity) = tile_index.get_tile_entity(grid_id, x, y) else { continue; };
+
+        match region {
+            Some(region) => { commands.entity(tile_entity).insert(HexRegion(region.to_string())); },
+            None => { commands.entity(tile_entity).remove::<HexRegion>(); },
+        }
+        assigned += 1;
+    }
+
+    assigned
+}
+
+/// Indexes the [`HexRegion`]s which were inserted, changed or removed, and sends the matching [`HexRegionChanged`] events
+fn update_hexregion_index(
+    mut region_index: ResMut<HexRegionIndex>,
+    changed_regions: Query<(Entity, &HexRegion), Changed<HexRegion>>,
+    mut removed_regions: RemovedComponents<HexRegion>,
+    regions: Query<(), With<HexRegion>>,
+    tile_index: Res<HexTileIndex>,
+    mut region_events: EventWriter<HexRegionChanged>,
+) {
+    for tile_entity in removed_regions.read() {
+        // Removed then inserted again, handled as a change
+        if regions.contains(tile_entity) {
+            continue;
+        }
+        let Some(((grid_id, x, y), previous)) = region_index.remove(tile_entity) else { continue; };
+
+        region_events.write(HexRegionChanged {
+            grid_id,
+            tile_entity,
+            coord: (x, y),
+            previous: Some(previous),
+            current: None,
+        });
+    }
+
+    for (tile_entity, region) in &changed_regions {
+        let Some(key) = tile_index.get_tile_key(tile_entity) else { continue; };
+
+        let previous = region_index.insert(tile_entity, key, region.0.clone());
+        if previous.as_ref() == Some(&region.0) {
+            continue;
+        }
+
+        let (grid_id, x, y) = key;
+        region_events.write(HexRegionChanged {
+            grid_id,
+            tile_entity,
+            coord: (x, y),
+            previous,
+            current: Some(region.0.clone()),
+        });
+    }
+}
@@ -0,0 +1,315 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use super::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// A [`Resource`] mapping the `id` of every spawned [`HexGrid`] to the [`Entity`] containing it, 
+/// and allocating grid ids per world.
+/// 
+/// Kept up to date by the [`GridsPlugin`](super::GridsPlugin) as grids are spawned and despawned.
+#[derive(Resource)]
+pub struct HexGridRegistry {
+    grids: HashMap<u64, Entity>,
+    next_id: u64,
+}
+
+impl Default for HexGridRegistry {
+    fn default() -> Self {
+        HexGridRegistry {
+            grids: HashMap::new(),
+            next_id: 1,
+        }
+    }
+}
+
+impl HexGridRegistry {
+    /// Returns a new grid id, starting at one and incrementing with each call, skipping 
+    /// ids of grids which are already spawned.
+    /// 
+    /// As the counter belongs to this world, the same sequence of calls always yields the 
+    /// same ids, independently of other `App`s.
+    pub fn allocate_id(&mut self) -> u64 {
+        while self.grids.contains_key(&self.next_id) {
+            self.next_id += 1;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Returns the [`Entity`] containing the [`HexGrid`] with the given id, if it is spawned.
+    pub fn get(&self, grid_id: u64) -> Option<Entity> {
+        self.grids.get(&grid_id).copied()
+    }
+
+    /// Iterates over the `(grid_id, grid_entity)` pairs of all spawned grids.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, Entity)> + '_ {
+        self.grids.iter().map(|(&grid_id, &grid_entity)| (grid_id, grid_entity))
+    }
+
+    /// Returns the amount of spawned grids.
+    pub fn len(&self) -> usize {
+        self.grids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.grids.is_empty()
+    }
+}
+
+/// A [`Resource`] mapping `(grid_id, x, y)` to the [`Entity`] containing the corresponding [`HexTile`].
+/// 
+/// Kept up to date by the [`GridsPlugin`](super::GridsPlugin) as tiles are spawned, despawned, 
+/// or have their coordinates changed.
+#[derive(Resource, Default)]
+pub struct HexTileIndex {
+    tiles: HashMap<(u64, u32, u32), Entity>,
+
+    /// Reverse lookup, as the parent [`HexGrid`] may already be gone when a tile is removed
+    keys: HashMap<Entity, (u64, u32, u32)>,
+}
+
+impl HexTileIndex {
+    /// Returns the [`Entity`] containing the [`HexTile`] at `(x, y)` in the [`HexGrid`] with the given id.
+    pub fn get_tile_entity(&self, grid_id: u64, x: u32, y: u32) -> Option<Entity> {
+        self.tiles.get(&(grid_id, x, y)).copied()
+    }
+
+    /// Returns the [`Entity`] containing the [`HexTile`] with the given order in `hexgrid` 
+    /// (see [`HexTile::coord_to_order()`] and [`HexGrid::order_to_coord()`]).
+    pub fn get_tile_entity_by_order(&self, hexgrid: &HexGrid, order: u32) -> Option<Entity> {
+        let (x, y) = hexgrid.order_to_coord(order)?;
+
+        self.get_tile_entity(hexgrid.id, x, y)
+    }
+
+    /// Returns the `(grid_id, x, y)` of a [`HexTile`] containing [`Entity`].
+    pub fn get_tile_key(&self, tile_entity: Entity) -> Option<(u64, u32, u32)> {
+        self.keys.get(&tile_entity).copied()
+    }
+
+    /// Iterates over the `(x, y, tile_entity)` of every indexed tile of the [`HexGrid`] with the given id, in no particular order.
+    pub fn iter_grid(&self, grid_id: u64) -> impl Iterator<Item = (u32, u32, Entity)> + '_ {
+        self.tiles
+            .iter()
+            .filter(move |((tile_grid_id, _, _), _)| *tile_grid_id == grid_id)
+            .map(|(&(_, x, y), &tile_entity)| (x, y, tile_entity))
+    }
+
+    /// Returns the amount of indexed tiles over all grids.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    fn insert(&mut self, key: (u64, u32, u32), tile_entity: Entity) {
+        self.tiles.insert(key, tile_entity);
+        self.keys.insert(tile_entity, key);
+    }
+
+    fn remove(&mut self, tile_entity: Entity) {
+        if let Some(key) = self.keys.remove(&tile_entity) {
+            if self.tiles.get(&key) == Some(&tile_entity) {
+                self.tiles.remove(&key);
+            }
+        }
+    }
+}
+
+/// Observer adding newly spawned [`HexGrid`] entities to the [`HexGridRegistry`]
+pub(crate) fn register_hexgrid(
+    trigger: Trigger<OnAdd, HexGrid>,
+    hexgrids: Query<&HexGrid>,
+    mut registry: ResMut<HexGridRegistry>,
+    mut error_reporter: ErrorReporter,
+) {
+    let Ok(hexgrid) = hexgrids.get(trigger.target()) else { return; };
+
+    match registry.grids.get(&hexgrid.id) {
+        Some(&registered) if registered != trigger.target() => {
+            error_reporter.report(Bevy2dUtilError::DuplicateGridId { grid_id: hexgrid.id });
+        }
+        _ => {
+            registry.grids.insert(hexgrid.id, trigger.target());
+        }
+    }
+}
+
+/// Observer removing despawned [`HexGrid`] entities from the [`HexGridRegistry`]
+pub(crate) fn unregister_hexgrid(
+    trigger: Trigger<OnRemove, HexGrid>,
+    hexgrids: Query<&HexGrid>,
+    mut registry: ResMut<HexGridRegistry>,
+) {
+    if let Ok(hexgrid) = hexgrids.get(trigger.target()) {
+        if registry.grids.get(&hexgrid.id) == Some(&trigger.target()) {
+            registry.grids.remove(&hexgrid.id);
+        }
+    }
+}
+
+/// Observer adding newly spawned [`HexTile`] entities to the [`HexTileIndex`]
+pub(crate) fn index_hextile(
+    trigger: Trigger<OnAdd, HexTile>,
+    hextiles: Query<(&HexTile, &ChildOf)>,
+    hexgrids: Query<&HexGrid>,
+    mut tile_index: ResMut<HexTileIndex>,
+) {
+    let Ok((hextile, child_of)) = hextiles.get(trigger.target()) else { return; };
+    let Ok(hexgrid) = hexgrids.get(child_of.parent()) else { return; };
+
+    tile_index.insert((hexgrid.id, hextile.x, hextile.y), trigger.target());
+}
+
+/// Observer removing despawned [`HexTile`] entities from the [`HexTileIndex`]
+pub(crate) fn unindex_hextile(
+    trigger: Trigger<OnRemove, HexTile>,
+    mut tile_index: ResMut<HexTileIndex>,
+) {
+    tile_index.remove(trigger.target());
+}
+
+/// Moves [`HexTile`] entities whose coordinates were changed to their new key in the [`HexTileIndex`]
+pub(crate) fn reindex_changed_hextiles(
+    changed_hextiles: Query<(Entity, &HexTile, &ChildOf), Changed<HexTile>>,
+    hexgrids: Query<&HexGrid>,
+    mut tile_index: ResMut<HexTileIndex>,
+) {
+    for (tile_entity, hextile, child_of) in &changed_hextiles {
+        let Ok(hexgrid) = hexgrids.get(child_of.parent()) else { continue; };
+        let key = (hexgrid.id, hextile.x, hextile.y);
+
+        // Newly spawned tiles were already indexed by `index_hextile`
+        if tile_index.get_tile_key(tile_entity) != Some(key) {
+            tile_index.remove(tile_entity);
+            tile_index.insert(key, tile_entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::Events;
+
+    use super::*;
+    use crate::grids::hexgrid::HexGridOrientation;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<HexGridRegistry>();
+        app.init_resource::<HexTileIndex>();
+        app.add_event::<Bevy2dUtilError>();
+        app.add_observer(register_hexgrid);
+        app.add_observer(unregister_hexgrid);
+        app.add_observer(index_hextile);
+        app.add_observer(unindex_hextile);
+        app.add_systems(PostUpdate, reindex_changed_hextiles);
+        app
+    }
+
+    /// Spawns a grid with a column of `rows` tiles
+    fn spawn_hexgrid(app: &mut App, grid_id: u64, rows: u32) -> (Entity, Vec<Entity>) {
+        let grid_entity = app.world_mut().spawn(HexGrid::with_id(grid_id, HexGridOrientation::Vertical, 1, rows, 64.)).id();
+        let tile_entities = (0..rows)
+            .map(|y| app.world_mut().spawn((HexTile::new(0, y), ChildOf(grid_entity))).id())
+            .collect();
+
+        (grid_entity, tile_entities)
+    }
+
+    #[test]
+    fn spawned_grids_are_registered_until_despawned() {
+        let mut app = app();
+        let (grid_entity, _) = spawn_hexgrid(&mut app, 7, 2);
+
+        let registry = app.world().resource::<HexGridRegistry>();
+        assert_eq!(registry.get(7), Some(grid_entity));
+        assert_eq!(registry.iter().collect::<Vec<_>>(), vec![(7, grid_entity)]);
+
+        app.world_mut().entity_mut(grid_entity).despawn();
+        assert!(app.world().resource::<HexGridRegistry>().is_empty());
+        assert!(app.world().resource::<HexTileIndex>().is_empty());
+    }
+
+    #[test]
+    fn duplicate_grid_ids_are_reported_and_the_first_grid_kept() {
+        let mut app = app();
+        let (first, _) = spawn_hexgrid(&mut app, 3, 1);
+        let (second, _) = spawn_hexgrid(&mut app, 3, 1);
+
+        assert_eq!(app.world().resource::<HexGridRegistry>().get(3), Some(first));
+        let errors: Vec<Bevy2dUtilError> = app.world_mut().resource_mut::<Events<Bevy2dUtilError>>().drain().collect();
+        assert_eq!(errors, vec![Bevy2dUtilError::DuplicateGridId { grid_id: 3 }]);
+
+        // Despawning the duplicate leaves the registered grid alone
+        app.world_mut().entity_mut(second).despawn();
+        assert_eq!(app.world().resource::<HexGridRegistry>().get(3), Some(first));
+    }
+
+    #[test]
+    fn spawned_tiles_are_indexed_until_despawned() {
+        let mut app = app();
+        let (_, tile_entities) = spawn_hexgrid(&mut app, 1, 3);
+        spawn_hexgrid(&mut app, 2, 2);
+
+        let tile_index = app.world().resource::<HexTileIndex>();
+        assert_eq!(tile_index.len(), 5);
+        assert_eq!(tile_index.get_tile_entity(1, 0, 2), Some(tile_entities[2]));
+        assert_eq!(tile_index.get_tile_key(tile_entities[1]), Some((1, 0, 1)));
+        assert_eq!(tile_index.iter_grid(1).count(), 3);
+        assert_eq!(tile_index.get_tile_entity(2, 0, 2), None);
+
+        app.world_mut().entity_mut(tile_entities[2]).despawn();
+        let tile_index = app.world().resource::<HexTileIndex>();
+        assert_eq!(tile_index.get_tile_entity(1, 0, 2), None);
+        assert_eq!(tile_index.get_tile_key(tile_entities[2]), None);
+        assert_eq!(tile_index.len(), 4);
+    }
+
+    #[test]
+    fn tiles_with_changed_coordinates_are_reindexed() {
+        let mut app = app();
+        let (_, tile_entities) = spawn_hexgrid(&mut app, 1, 2);
+        app.update();
+
+        // Swapping the coordinates of both tiles
+        app.world_mut().get_mut::<HexTile>(tile_entities[0]).unwrap().y = 1;
+        app.world_mut().get_mut::<HexTile>(tile_entities[1]).unwrap().y = 0;
+        app.update();
+
+        let tile_index = app.world().resource::<HexTileIndex>();
+        assert_eq!(tile_index.get_tile_entity(1, 0, 1), Some(tile_entities[0]));
+        assert_eq!(tile_index.get_tile_entity(1, 0, 0), Some(tile_entities[1]));
+        assert_eq!(tile_index.get_tile_key(tile_entities[0]), Some((1, 0, 1)));
+        assert_eq!(tile_index.len(), 2);
+    }
+
+    #[test]
+    fn allocated_ids_are_deterministic_per_world_and_skip_spawned_grids() {
+        let mut app = app();
+        spawn_hexgrid(&mut app, 2, 1);
+
+        let mut registry = app.world_mut().resource_mut::<HexGridRegistry>();
+        let ids: Vec<u64> = (0..3).map(|_| registry.allocate_id()).collect();
+        assert_eq!(ids, vec![1, 3, 4]);
+
+        // A separate world allocates the same ids, whatever happened in this one
+        let mut other_registry = HexGridRegistry::default();
+        assert_eq!(HexGrid::new(&mut other_registry, HexGridOrientation::Vertical, 2, 2, 64.).id, 1);
+        assert!(HexGrid::try_new(&mut other_registry, HexGridOrientation::Vertical, 0, 2, 64.).is_err());
+        assert_eq!(HexGrid::try_new(&mut other_registry, HexGridOrientation::Vertical, 2, 2, 64.).map(|hexgrid| hexgrid.id), Ok(2));
+    }
+}
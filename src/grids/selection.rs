@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    interaction::HexTileClicked, 
+    picking::{
+        HexTilePick, 
+        HoveredHexTile
+    }, 
+    registry::HexTileIndex
+};
+use crate::input::InputActions;
+
+/// A [`Component`] marking a selected [`HexTile`] [`Entity`].
+/// 
+/// Inserted and removed by the [`HexGridInteractionPlugin`](super::interaction::HexGridInteractionPlugin) 
+/// when its `selection` is configured, but can also be inserted or removed by hand, in which case 
+/// the [`HexTileSelectionChanged`] events and the selection overlay follow as well.
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct SelectedTile;
+
+/// A [`Resource`] configuring the tile selection of the [`HexGridInteractionPlugin`](super::interaction::HexGridInteractionPlugin).
+/// 
+/// A click selects the clicked tile alone, a shift-click adds or removes it from the selection 
+/// (if `multi_select` is enabled), and a click outside of any tile clears the selection.
+/// 
+/// If the [`InputActions`] resource exists, its `select` action is used on the hovered tile instead 
+/// of `button`, and its `cancel` action clears the selection.
+#[derive(Resource, Clone)]
+pub struct HexTileSelectionConfigs {
+    /// The mouse button selecting tiles, unless the [`InputActions`] resource exists
+    pub button: MouseButton,
+
+    /// Whether shift-clicks toggle tiles in and out of the selection
+    pub multi_select: bool,
+
+    /// Path towards the image drawn over every selected tile, if any
+    pub overlay_texture: Option<String>,
+
+    /// Tint of the overlay sprite
+    pub overlay_color: Color,
+}
+
+impl Default for HexTileSelectionConfigs {
+    fn default() -> Self {
+        HexTileSelectionConfigs {
+            button: MouseButton::Left,
+            multi_select: true,
+            overlay_texture: None,
+            overlay_color: Color::WHITE,
+        }
+    }
+}
+
+/// An [`Event`] sent when a tile is added to (`selected: true`) or removed from (`selected: false`) the selection.
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
+pub struct HexTileSelectionChanged {
+    pub pick: HexTilePick,
+    pub selected: bool,
+}
+
+/// A [`Component`] marking the overlay [`Entity`] drawn over a [`SelectedTile`], as a child of it.
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HexTileSelectionOverlay;
+
+/// Updates the [`SelectedTile`]s according to the clicks with the selection button, or the `select` and `cancel` actions
+pub(crate) fn select_clicked_hextiles(
+    mut commands: Commands,
+    selection_configs: Res<HexTileSelectionConfigs>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    actions: Option<Res<InputActions>>,
+    hovered_hextile: Res<HoveredHexTile>,
+    mut click_reader: EventReader<HexTileClicked>,
+    selected: Query<Entity, With<SelectedTile>>,
+) {
+    let multi_select = selection_configs.multi_select && keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let (pressed, clicked) = match &actions {
+        Some(actions) => {
+            click_reader.clear();
+
+            if actions.cancel {
+                for selected_entity in &selected {
+                    commands.entity(selected_entity).remove::<SelectedTile>();
+                }
+                return;
+            }
+            (actions.select, hovered_hextile.hovered.filter(|_| actions.select).map(|pick| pick.entity))
+        }
+        None => {
+            let clicked = click_reader
+                .read()
+                .filter(|click| click.button == selection_configs.button)
+                .last()
+                .map(|click| click.entity);
+
+            (mouse_buttons.just_pressed(selection_configs.button), clicked)
+        }
+    };
+
+    match clicked {
+        Some(tile_entity) if multi_select => {
+            if selected.contains(tile_entity) {
+                commands.entity(tile_entity).remove::<SelectedTile>();
+            } else {
+                commands.entity(tile_entity).insert(SelectedTile);
+            }
+        }
+        Some(tile_entity) => {
+            for selected_entity in &selected {
+                if selected_entity != tile_entity {
+                    commands.entity(selected_entity).remove::<SelectedTile>();
+                }
+            }
+            if !selected.contains(tile_entity) {
+                commands.entity(tile_entity).insert(SelectedTile);
+            }
+        }
+        // Clicked outside of every tile
+        None if pressed && !multi_select => {
+            for selected_entity in &selected {
+                commands.entity(selected_entity).remove::<SelectedTile>();
+            }
+        }
+        None => {}
+    }
+}
+
+/// Observer sending a [`HexTileSelectionChanged`] event and spawning the selection overlay of newly selected tiles
+pub(crate) fn on_hextile_selected(
+    trigger: Trigger<OnAdd, SelectedTile>,
+    mut commands: Commands,
+    selection_configs: Option<Res<HexTileSelectionConfigs>>,
+    asset_server: Res<AssetServer>,
+    tile_index: Res<HexTileIndex>,
+    hextiles: Query<&ChildOf, With<HexTile>>,
+    hexgrids: Query<&HexGrid>,
+    mut selection_writer: EventWriter<HexTileSelectionChanged>,
+) {
+    let tile_entity = trigger.target();
+    let Some((grid_id, x, y)) = tile_index.get_tile_key(tile_entity) else { return; };
+
+    selection_writer.write(HexTileSelectionChanged {
+        pick: HexTilePick { grid_id, coord: (x, y), entity: tile_entity },
+        selected: true,
+    });
+
+    let Some(selection_configs) = selection_configs else { return; };
+    let Some(overlay_texture) = &selection_configs.overlay_texture else { return; };
+    let Ok(hexgrid) = hextiles.get(tile_entity).and_then(|child_of| hexgrids.get(child_of.parent())) else { return; };
+
+    commands.spawn((
+        HexTileSelectionOverlay,
+        Sprite {
+            custom_size: Some(hexgrid.hextile_sprite_size()),
+            image: asset_server.load(overlay_texture),
+            color: selection_configs.overlay_color,
+            ..Default::default()
+        },
+        // Drawn above the tile
+        Transform::from_xyz(0., 0., 1.),
+        ChildOf(tile_entity),
+    ));
+}
+
+/// Observer sending a [`HexTileSelectionChanged`] event and despawning the selection overlay of deselected tiles
+pub(crate) fn on_hextile_deselected(
+    trigger: Trigger<OnRemove, SelectedTile>,
+    mut commands: Commands,
+    tile_index: Res<HexTileIndex>,
+    children: Query<&Children>,
+    overlays: Query<(), With<HexTileSelectionOverlay>>,
+    mut selection_writer: EventWriter<HexTileSelectionChanged>,
+) {
+    let tile_entity = trigger.target();
+
+    if let Some((grid_id, x, y)) = tile_index.get_tile_key(tile_entity) {
+        selection_writer.write(HexTileSelectionChanged {
+            pick: HexTilePick { grid_id, coord: (x, y), entity: tile_entity },
+            selected: false,
+        });
+    }
+
+    let Ok(children) = children.get(tile_entity) else { return; };
+    for &child in children {
+        if overlays.contains(child) {
+            commands.entity(child).despawn();
+        }
+    }
+}
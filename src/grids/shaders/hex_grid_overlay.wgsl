@@ -0,0 +1,91 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct HexGridOverlaySettings {
+    color: vec4<f32>,
+    size: vec2<f32>,
+    origin: vec2<f32>,
+    tile_step: vec2<f32>,
+    hextile_width: f32,
+    hextile_height: f32,
+    thickness: f32,
+    zoom: f32,
+    fade_start: f32,
+    fade_end: f32,
+    orientation: u32,
+    columns: u32,
+    rows: u32,
+}
+
+@group(2) @binding(0) var<uniform> settings: HexGridOverlaySettings;
+
+// sqrt(3) / 2, the height of a regular hexagon relative to its width
+const HEIGHT_RATIO: f32 = 0.8660254;
+
+// Center of a tile relative to tile (0, 0), laid out in vertical orientation
+fn cell_center(col: i32, row: i32, column_step: f32, row_step: f32) -> vec2<f32> {
+    let odd = f32(col & 1);
+    return vec2<f32>(f32(col) * column_step, f32(row) * row_step + odd * row_step * 0.5);
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Position relative to the center of tile (0, 0)
+    var p = vec2<f32>(in.uv.x, 1.0 - in.uv.y) * settings.size - settings.origin;
+    var columns = i32(settings.columns);
+    var rows = i32(settings.rows);
+    var step = settings.tile_step;
+
+    // Horizontal grids are the transpose of vertical ones
+    if settings.orientation == 1u {
+        p = p.yx;
+        columns = i32(settings.rows);
+        rows = i32(settings.columns);
+        step = step.yx;
+    }
+
+    let width = settings.hextile_width;
+    let column_step = step.x;
+    let row_step = step.y;
+
+    // Squashed hexagons are measured as regular ones, scaled back along their flat axis
+    let squash = width * HEIGHT_RATIO / settings.hextile_height;
+
+    // The closest tile center is the tile containing the point
+    let base_col = i32(floor(p.x / column_step));
+    var best_distance = 1e20;
+    var best_cell = vec2<i32>(0, 0);
+    var best_offset = vec2<f32>(0.0, 0.0);
+
+    for (var dc = -1; dc <= 1; dc += 1) {
+        let col = base_col + dc;
+        let odd = f32(col & 1);
+        let row = i32(round((p.y - odd * row_step * 0.5) / row_step));
+        let offset = (p - cell_center(col, row, column_step, row_step)) * vec2<f32>(1.0, squash);
+        let distance = dot(offset, offset);
+
+        if distance < best_distance {
+            best_distance = distance;
+            best_cell = vec2<i32>(col, row);
+            best_offset = offset;
+        }
+    }
+
+    if best_cell.x < 0 || best_cell.y < 0 || best_cell.x >= columns || best_cell.y >= rows {
+        discard;
+    }
+
+    // Distance to the closest edge of the (flat-top) hexagon, on either side of it when tiles are spaced apart
+    let d = abs(best_offset);
+    let edge_distance = abs(width * HEIGHT_RATIO * 0.5 - max(d.y, d.x * HEIGHT_RATIO + d.y * 0.5));
+
+    // Thickness is given in screen pixels, one extra pixel is used for antialiasing
+    let half_thickness = settings.thickness * settings.zoom * 0.5;
+    let line = 1.0 - smoothstep(half_thickness, half_thickness + settings.zoom, edge_distance);
+    let fade = 1.0 - smoothstep(settings.fade_start, settings.fade_end, settings.zoom);
+
+    let alpha = settings.color.a * line * fade;
+    if alpha <= 0.0 {
+        discard;
+    }
+    return vec4<f32>(settings.color.rgb, alpha);
+}
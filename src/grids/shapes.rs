@@ -0,0 +1,283 @@
+use super::{
+    coords::{
+        AxialCoord, 
+        CubeCoord, 
+        HexAxis
+    }, 
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }
+};
+
+impl HexGrid {
+    /// Returns the `x` and `y` hexgrid coordinates of every tile at most `radius` steps away from `center` 
+    /// (see [`HexTile::distance()`]), `center` included, leaving out tiles outside of the grid.
+    /// 
+    /// Useful for movement ranges, areas of effect and highlighting.
+    pub fn tiles_in_range(&self, center: (u32, u32), radius: u32) -> Vec<(u32, u32)> {
+        let center_tile = HexTile::new(center.0, center.1);
+        let (x, y) = center;
+
+        let mut tiles = Vec::new();
+        if self.columns == 0 || self.rows == 0 {
+            return tiles;
+        }
+
+        // The bounding box of the range, one row or column larger to cover the offsets of odd columns or rows
+        for col in x.saturating_sub(radius + 1)..=x.saturating_add(radius + 1).min(self.columns.saturating_sub(1)) {
+            for row in y.saturating_sub(radius + 1)..=y.saturating_add(radius + 1).min(self.rows.saturating_sub(1)) {
+                if center_tile.distance(&HexTile::new(col, row), self.orientation) <= radius {
+                    tiles.push((col, row));
+                }
+            }
+        }
+
+        tiles
+    }
+
+    /// Iterates over the `x` and `y` hexgrid coordinates of the tiles exactly `radius` steps away from `center`, 
+    /// going around it clockwise and leaving out tiles outside of the grid.
+    /// 
+    /// A `radius` of `0` only yields `center`.
+    pub fn hex_ring(&self, center: (u32, u32), radius: u32) -> impl Iterator<Item = (u32, u32)> + '_ {
+        let center = HexTile::new(center.0, center.1).to_axial(self.orientation);
+        let radius = radius as i32;
+
+        // Starts at the corner reached by `radius` steps in the fifth direction, then walks along the 6 sides
+        let start = AxialCoord::new(
+            center.q + AxialCoord::DIRECTIONS[4].q * radius, 
+            center.r + AxialCoord::DIRECTIONS[4].r * radius
+        );
+        let side_length = radius.max(1);
+        let sides = if radius == 0 { 1 } else { 6 };
+
+        (0..sides)
+            .flat_map(move |side| (0..side_length).map(move |along| (side, along)))
+            .map(move |(side, along)| {
+                let corner = AxialCoord::DIRECTIONS[..side]
+                    .iter()
+                    .fold(start, |corner, direction| AxialCoord::new(corner.q + direction.q * radius, corner.r + direction.r * radius));
+                let direction = AxialCoord::DIRECTIONS[side];
+                AxialCoord::new(corner.q + direction.q * along, corner.r + direction.r * along)
+            })
+            .filter_map(move |axial| self.axial_to_tile(axial))
+    }
+
+    /// Iterates over the `x` and `y` hexgrid coordinates of the tiles at most `radius` steps away from `center`, 
+    /// starting with `center` and going outwards ring by ring (see [`HexGrid::hex_ring()`]).
+    pub fn hex_spiral(&self, center: (u32, u32), radius: u32) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (0..=radius).flat_map(move |ring| self.hex_ring(center, ring))
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of the tiles crossed by a straight line from the center 
+    /// of tile `a` to the center of tile `b`, both included, in order and without gaps.
+    /// 
+    /// Lines running exactly along the edge between two tiles are nudged to one side consistently. 
+    /// Useful for projectile paths and line-of-sight checks.
+    pub fn hex_line(&self, a: (u32, u32), b: (u32, u32)) -> Vec<(u32, u32)> {
+        let start = HexTile::new(a.0, a.1).to_axial(self.orientation).to_cube();
+        let end = HexTile::new(b.0, b.1).to_axial(self.orientation).to_cube();
+        let steps = start.distance(end);
+
+        // Nudges the line off the edges between tiles by a different amount per axis, so rounding is never a tie. 
+        // Computed in `f64`, as `f32` cannot hold such a small nudge on coordinates past a few tiles
+        let nudge = |cube: CubeCoord| (cube.q as f64 + 1e-6, cube.r as f64 + 2e-6, cube.s as f64 - 3e-6);
+        let (start_q, start_r, start_s) = nudge(start);
+        let (end_q, end_r, end_s) = nudge(end);
+
+        (0..=steps)
+            .filter_map(|step| {
+                let t = if steps == 0 { 0. } else { step as f64 / steps as f64 };
+                let cube = CubeCoord::round_f64(
+                    start_q + (end_q - start_q) * t, 
+                    start_r + (end_r - start_r) * t, 
+                    start_s + (end_s - start_s) * t
+                );
+                self.axial_to_tile(cube.to_axial())
+            })
+            .collect()
+    }
+
+    /// Returns the hexgrid coordinates of the axial coordinates, if the tile is within the grid
+    pub fn axial_to_tile(&self, axial: AxialCoord) -> Option<(u32, u32)> {
+        HexTile::from_axial(axial, self.orientation)
+            .filter(|hextile| hextile.x < self.columns && hextile.y < self.rows)
+            .map(|hextile| (hextile.x, hextile.y))
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of `tiles` rotated around `center` by `steps` times 60°, 
+    /// clockwise on screen (counter-clockwise if negative), in the same order, e.g. to turn a unit formation.
+    /// 
+    /// Returns `None` if any of the rotated tiles lies outside of the grid.
+    pub fn rotate_tiles(&self, tiles: &[(u32, u32)], center: (u32, u32), steps: i32) -> Option<Vec<(u32, u32)>> {
+        let center = HexTile::new(center.0, center.1).to_axial(self.orientation);
+
+        tiles
+            .iter()
+            .map(|&(x, y)| self.axial_to_tile(HexTile::new(x, y).to_axial(self.orientation).rotate_around(center, steps)))
+            .collect()
+    }
+
+    /// Returns the `x` and `y` hexgrid coordinates of `tiles` mirrored across the line through `center` 
+    /// along `axis`, in the same order, e.g. to flip a map stamp.
+    /// 
+    /// Returns `None` if any of the mirrored tiles lies outside of the grid.
+    pub fn reflect_tiles(&self, tiles: &[(u32, u32)], center: (u32, u32), axis: HexAxis) -> Option<Vec<(u32, u32)>> {
+        let center = HexTile::new(center.0, center.1).to_axial(self.orientation);
+
+        tiles
+            .iter()
+            .map(|&(x, y)| self.axial_to_tile(HexTile::new(x, y).to_axial(self.orientation).reflect_around(center, axis)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::grids::hexgrid::HexGridOrientation;
+
+    const ORIENTATIONS: [HexGridOrientation; 2] = [HexGridOrientation::Vertical, HexGridOrientation::Horizontal];
+
+    fn distance(hexgrid: &HexGrid, a: (u32, u32), b: (u32, u32)) -> u32 {
+        HexTile::new(a.0, a.1).distance(&HexTile::new(b.0, b.1), hexgrid.orientation)
+    }
+
+    #[test]
+    fn hex_line_is_contiguous_and_includes_both_ends() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 30, 30, 64.);
+
+            for a in [(10, 10), (15, 16), (19, 13)] {
+                for b in (10..20).flat_map(|x| (10..20).map(move |y| (x, y))) {
+                    let line = hexgrid.hex_line(a, b);
+
+                    assert_eq!(line.first(), Some(&a));
+                    assert_eq!(line.last(), Some(&b));
+                    assert_eq!(line.len() as u32, distance(&hexgrid, a, b) + 1, "{a:?} -> {b:?}");
+                    assert!(line.windows(2).all(|step| distance(&hexgrid, step[0], step[1]) == 1), "{a:?} -> {b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hex_line_breaks_ties_the_same_way_anywhere_on_the_grid() {
+        // Steps running along the edges between tiles, where rounding is ambiguous
+        let steps = [(2, -1), (1, 1), (-1, 2), (-2, 1), (-1, -1), (1, -2), (4, -2), (2, 2), (6, -3)];
+
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 400, 400, 64.);
+
+            let relative_line = |start: (u32, u32), step: (i32, i32)| {
+                let origin = HexTile::new(start.0, start.1).to_axial(orientation);
+                let end = hexgrid.axial_to_tile(origin + AxialCoord::new(step.0, step.1)).unwrap();
+
+                hexgrid
+                    .hex_line(start, end)
+                    .into_iter()
+                    .map(|(x, y)| HexTile::new(x, y).to_axial(orientation) - origin)
+                    .collect::<Vec<AxialCoord>>()
+            };
+
+            for step in steps {
+                let near_origin = relative_line((8, 8), step);
+
+                for start in [(121, 77), (300, 350), (391, 391)] {
+                    assert_eq!(relative_line(start, step), near_origin, "{step:?} from {start:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hex_line_of_a_single_tile() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 5, 5, 64.);
+
+        assert_eq!(hexgrid.hex_line((2, 3), (2, 3)), vec![(2, 3)]);
+    }
+
+    #[test]
+    fn ranges_of_an_interior_tile() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 15, 15, 64.);
+            let center = (7, 7);
+
+            assert_eq!(hexgrid.tiles_in_range(center, 0), vec![center]);
+
+            for radius in 1..=4 {
+                let range = hexgrid.tiles_in_range(center, radius);
+                assert_eq!(range.len() as u32, 1 + 3 * radius * (radius + 1));
+                assert!(range.iter().all(|&tile| distance(&hexgrid, center, tile) <= radius));
+            }
+        }
+    }
+
+    #[test]
+    fn rings_and_spirals_of_an_interior_tile() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 15, 15, 64.);
+            let center = (7, 7);
+
+            assert_eq!(hexgrid.hex_ring(center, 0).collect::<Vec<_>>(), vec![center]);
+
+            for radius in 1..=4 {
+                let ring: Vec<(u32, u32)> = hexgrid.hex_ring(center, radius).collect();
+                assert_eq!(ring.len() as u32, 6 * radius);
+                assert!(ring.iter().all(|&tile| distance(&hexgrid, center, tile) == radius));
+                assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+
+                // Going around the ring, every tile is next to the previous one
+                assert!(ring.windows(2).all(|step| distance(&hexgrid, step[0], step[1]) == 1));
+
+                let spiral: Vec<(u32, u32)> = hexgrid.hex_spiral(center, radius).collect();
+                assert_eq!(spiral.first(), Some(&center));
+                assert_eq!(spiral.iter().copied().collect::<HashSet<_>>(), hexgrid.tiles_in_range(center, radius).into_iter().collect());
+            }
+        }
+    }
+
+    #[test]
+    fn ranges_leave_out_tiles_outside_of_the_grid() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 4, 4, 64.);
+
+        let range = hexgrid.tiles_in_range((0, 0), 10);
+        assert_eq!(range.len(), 16);
+
+        let range = hexgrid.tiles_in_range((0, 0), 1);
+        assert_eq!(range.len(), 3);
+        assert!(hexgrid.hex_ring((0, 0), 2).all(|(x, y)| x < 4 && y < 4));
+    }
+
+    #[test]
+    fn rotating_and_reflecting_tiles_round_trips() {
+        for orientation in ORIENTATIONS {
+            let hexgrid = HexGrid::with_id(0, orientation, 20, 20, 64.);
+            let center = (10, 10);
+            let shape = vec![(10, 10), (11, 10), (12, 11), (9, 12)];
+
+            assert_eq!(hexgrid.rotate_tiles(&shape, center, 6), Some(shape.clone()));
+
+            let rotated = hexgrid.rotate_tiles(&shape, center, 2).unwrap();
+            assert_eq!(hexgrid.rotate_tiles(&rotated, center, -2), Some(shape.clone()));
+            assert!(shape.iter().zip(&rotated).all(|(&tile, &rotated)| {
+                distance(&hexgrid, center, tile) == distance(&hexgrid, center, rotated)
+            }));
+
+            for axis in [HexAxis::Q, HexAxis::R, HexAxis::S] {
+                let reflected = hexgrid.reflect_tiles(&shape, center, axis).unwrap();
+                assert_eq!(hexgrid.reflect_tiles(&reflected, center, axis), Some(shape.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn rotating_tiles_out_of_the_grid_fails() {
+        let hexgrid = HexGrid::with_id(0, HexGridOrientation::Vertical, 5, 5, 64.);
+
+        assert_eq!(hexgrid.rotate_tiles(&[(4, 2)], (0, 2), 3), None);
+    }
+}
@@ -0,0 +1,409 @@
+use bevy::{
+    prelude::*, 
+    tasks::{
+        block_on, 
+        futures_lite::future, 
+        IoTaskPool, 
+        Task
+    }
+};
+use serde::{
+    Deserialize, 
+    Serialize
+};
+
+use std::{
+    collections::HashMap, 
+    path::{
+        Path, 
+        PathBuf
+    }
+};
+
+use super::{
+    decoration::HexTerrain, 
+    hexgrid::{
+        HexGrid, 
+        HexTile, 
+        HexTileSpawned
+    }
+};
+use crate::{
+    error::{
+        report_in_world, 
+        Bevy2dUtilError, 
+        ErrorReporter
+    }, 
+    MainCameraFilter
+};
+
+/// A [`Plugin`] streaming the tiles of grids too large to be kept in memory: the map is stored
+/// in chunks on disk, chunks near the main camera are loaded and spawned while distant ones
+/// are written back to disk and despawned.
+/// 
+/// Only grids spawned with [`build_spawn_streamed_hexgrid_system()`] are streamed.
+/// Files are read and written asynchronously on the [`IoTaskPool`].
+#[derive(Clone, Default)]
+pub struct HexStreamingPlugin {
+    pub configs: HexStreamingConfigs,
+}
+
+impl Plugin for HexStreamingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.configs.clone());
+        app.init_resource::<HexChunkStreams>();
+
+        app.add_event::<Bevy2dUtilError>();
+
+        app.add_systems(Update, (stream_hexgrid_chunks, spawn_loaded_hexgrid_chunks).chain());
+    }
+}
+
+/// Configurations of the [`HexStreamingPlugin`]
+#[derive(Resource, Clone)]
+pub struct HexStreamingConfigs {
+    /// Directory in which the chunk files are stored, one sub-directory per grid
+    pub directory: PathBuf,
+
+    /// Amount of columns and rows of tiles in a chunk
+    pub chunk_size: u32,
+
+    /// Chunks up to this many chunks away from the one under the camera are loaded
+    pub load_radius: u32,
+
+    /// Chunks further than this many chunks away from the one under the camera are unloaded,
+    /// should be larger than `load_radius` so chunks do not flicker at the border
+    pub unload_radius: u32,
+}
+
+impl Default for HexStreamingConfigs {
+    fn default() -> Self {
+        HexStreamingConfigs {
+            directory: PathBuf::from("maps"),
+            chunk_size: 16,
+            load_radius: 2,
+            unload_radius: 3,
+        }
+    }
+}
+
+impl HexStreamingConfigs {
+    /// Returns the path of the file storing a chunk of the [`HexGrid`] with the given id.
+    pub fn chunk_path(&self, grid_id: u64, (chunk_x, chunk_y): (u32, u32)) -> PathBuf {
+        self.directory
+            .join(format!("grid_{grid_id}"))
+            .join(format!("chunk_{chunk_x}_{chunk_y}.ron"))
+    }
+}
+
+/// A [`Component`] marking a [`HexGrid`] [`Entity`] whose tiles are streamed from disk.
+#[derive(Component, Clone, Copy)]
+pub struct StreamedHexGrid;
+
+/// The content of a chunk file.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct HexChunkData {
+    pub tiles: Vec<StreamedHexTile>,
+}
+
+/// The stored state of a single [`HexTile`], tiles missing from a chunk file are spawned without
+/// [`Sprite`] nor [`HexTerrain`].
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StreamedHexTile {
+    pub x: u32,
+    pub y: u32,
+
+    /// Path of the image of its [`Sprite`]
+    pub texture: Option<String>,
+
+    pub terrain: Option<String>,
+}
+
+type ChunkKey = (u64, (u32, u32));
+
+/// A [`Resource`] keeping track of the chunks which are loaded, being loaded, or being saved.
+#[derive(Resource, Default)]
+pub struct HexChunkStreams {
+    loaded: HashMap<ChunkKey, Vec<Entity>>,
+    loading: HashMap<ChunkKey, (Entity, Task<Result<Option<HexChunkData>, Bevy2dUtilError>>)>,
+    saving: HashMap<ChunkKey, Task<Result<(), Bevy2dUtilError>>>,
+}
+
+impl HexChunkStreams {
+    /// Returns whether the chunk of the [`HexGrid`] with the given id is spawned.
+    pub fn is_chunk_loaded(&self, grid_id: u64, chunk: (u32, u32)) -> bool {
+        self.loaded.contains_key(&(grid_id, chunk))
+    }
+
+    /// Returns the amount of spawned chunks over all grids.
+    pub fn loaded_chunks(&self) -> usize {
+        self.loaded.len()
+    }
+
+    /// Drops the chunks of a despawned grid, pending saves are left to complete
+    pub(crate) fn forget_grid(&mut self, grid_id: u64) {
+        self.loaded.retain(|(chunk_grid_id, _), _| *chunk_grid_id != grid_id);
+        self.loading.retain(|(chunk_grid_id, _), _| *chunk_grid_id != grid_id);
+    }
+}
+
+/// Builds an 'exclusive' [`System`] closure which spawns an [`Entity`] containing the [`HexGrid`]
+/// at specified translation coordinates, without any tile: its tiles are spawned chunk by chunk
+/// by the [`HexStreamingPlugin`] as the camera gets close to them, sending a [`HexTileSpawned`] event for each.
+/// 
+/// Reports a [`Bevy2dUtilError::InvalidGridDimensions`] instead of spawning anything if the 
+/// [`HexGrid`] has invalid dimensions (see [`HexGrid::validate_dimensions()`]).
+/// 
+/// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+pub fn build_spawn_streamed_hexgrid_system(hexgrid: HexGrid, hexgrid_translation: Vec3) -> impl FnMut(&mut World) {
+    move | world: &mut World | {
+        if let Err(error) = hexgrid.validate_dimensions() {
+            report_in_world(world, error);
+            return;
+        }
+
+        world.spawn((
+            hexgrid.clone(),
+            StreamedHexGrid,
+            Transform::from_translation(hexgrid_translation),
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// Builds a [`System`] closure which writes every loaded chunk of the [`HexGrid`] specified by id
+/// to disk without despawning them, e.g. before exiting.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_save_hexgrid_chunks_system(grid_id: u64) -> impl FnMut(
+    Res<HexStreamingConfigs>,
+    ResMut<HexChunkStreams>,
+    Query<(&HexTile, Option<&Sprite>, Option<&HexTerrain>)>,
+    Res<AssetServer>,
+) {
+    move |
+        configs: Res<HexStreamingConfigs>,
+        mut streams: ResMut<HexChunkStreams>,
+        hextiles: Query<(&HexTile, Option<&Sprite>, Option<&HexTerrain>)>,
+        asset_server: Res<AssetServer>
+    | {
+        let chunks: Vec<(ChunkKey, Vec<Entity>)> = streams.loaded
+            .iter()
+            .filter(|((chunk_grid_id, _), _)| *chunk_grid_id == grid_id)
+            .map(|(&key, tiles)| (key, tiles.clone()))
+            .collect();
+
+        for (key, tiles) in chunks {
+            let data = chunk_data(&tiles, &hextiles, &asset_server);
+            let task = save_chunk_task(configs.chunk_path(key.0, key.1), data);
+            // A dropped task is cancelled, so a previous save of the same chunk is left to finish
+            if let Some(previous_task) = streams.saving.insert(key, task) {
+                previous_task.detach();
+            }
+        }
+    }
+}
+
+/// Collects the stored state of the given tiles
+fn chunk_data(
+    tiles: &[Entity],
+    hextiles: &Query<(&HexTile, Option<&Sprite>, Option<&HexTerrain>)>,
+    asset_server: &AssetServer,
+) -> HexChunkData {
+    HexChunkData {
+        tiles: tiles
+            .iter()
+            .filter_map(|&tile_entity| hextiles.get(tile_entity).ok())
+            .map(|(hextile, sprite, terrain)| StreamedHexTile {
+                x: hextile.x,
+                y: hextile.y,
+                texture: sprite
+                    .and_then(|sprite| asset_server.get_path(sprite.image.id()))
+                    .map(|path| path.to_string()),
+                terrain: terrain.map(|terrain| terrain.0.clone()),
+            })
+            .collect(),
+    }
+}
+
+/// Returns a [`Bevy2dUtilError::FileIo`] for the given path
+fn file_io_error(path: &Path, reason: impl std::fmt::Display) -> Bevy2dUtilError {
+    Bevy2dUtilError::FileIo { path: path.display().to_string(), reason: reason.to_string() }
+}
+
+/// Serializes a chunk and writes it to disk on the [`IoTaskPool`]
+fn save_chunk_task(path: PathBuf, data: HexChunkData) -> Task<Result<(), Bevy2dUtilError>> {
+    let serialized = ron::ser::to_string_pretty(&data, ron::ser::PrettyConfig::default());
+
+    IoTaskPool::get().spawn(async move {
+        let serialized = serialized.map_err(|error| file_io_error(&path, error))?;
+
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory).map_err(|error| file_io_error(&path, error))?;
+        }
+        std::fs::write(&path, serialized).map_err(|error| file_io_error(&path, error))
+    })
+}
+
+/// Reads and deserializes a chunk on the [`IoTaskPool`], a missing file yielding `None`
+fn load_chunk_task(path: PathBuf) -> Task<Result<Option<HexChunkData>, Bevy2dUtilError>> {
+    IoTaskPool::get().spawn(async move {
+        match std::fs::read_to_string(&path) {
+            Ok(serialized) => ron::from_str(&serialized)
+                .map(Some)
+                .map_err(|error| file_io_error(&path, error)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(file_io_error(&path, error)),
+        }
+    })
+}
+
+/// Starts loading the chunks close to the camera, and saves and despawns the distant ones
+fn stream_hexgrid_chunks(
+    mut commands: Commands,
+    configs: Res<HexStreamingConfigs>,
+    mut streams: ResMut<HexChunkStreams>,
+    camera: Query<&GlobalTransform, MainCameraFilter>,
+    hexgrids: Query<(Entity, &HexGrid, &GlobalTransform), With<StreamedHexGrid>>,
+    hextiles: Query<(&HexTile, Option<&Sprite>, Option<&HexTerrain>)>,
+    asset_server: Res<AssetServer>,
+    mut error_reporter: ErrorReporter,
+) {
+    // Forget finished saves
+    let mut failed_saves = Vec::new();
+    streams.saving.retain(|_, task| match block_on(future::poll_once(task)) {
+        Some(result) => {
+            if let Err(error) = result {
+                failed_saves.push(error);
+            }
+            false
+        }
+        None => true,
+    });
+    for error in failed_saves {
+        error_reporter.report(error);
+    }
+
+    // Forget chunks of despawned grids, their tiles were despawned with them
+    streams.loaded.retain(|(grid_id, _), _| hexgrids.iter().any(|(_, hexgrid, _)| hexgrid.id == *grid_id));
+
+    let Ok(camera_transform) = camera.single() else { return; };
+    let chunk_size = configs.chunk_size.max(1) as i64;
+
+    for (grid_entity, hexgrid, grid_transform) in &hexgrids {
+        let local_camera = grid_transform.affine().inverse().transform_point3(camera_transform.translation()).truncate();
+        let (tile_x, tile_y) = hexgrid.local_to_offset_coord(local_camera);
+        let camera_chunk = (tile_x.div_euclid(chunk_size), tile_y.div_euclid(chunk_size));
+
+        let chunk_columns = (hexgrid.columns as i64 + chunk_size - 1) / chunk_size;
+        let chunk_rows = (hexgrid.rows as i64 + chunk_size - 1) / chunk_size;
+        let chunk_distance = |(chunk_x, chunk_y): (u32, u32)| {
+            (chunk_x as i64 - camera_chunk.0).abs().max((chunk_y as i64 - camera_chunk.1).abs())
+        };
+
+        // Unload distant chunks
+        let distant: Vec<ChunkKey> = streams.loaded
+            .keys()
+            .filter(|(grid_id, chunk)| *grid_id == hexgrid.id && chunk_distance(*chunk) > configs.unload_radius as i64)
+            .copied()
+            .collect();
+
+        for key in distant {
+            let Some(tiles) = streams.loaded.remove(&key) else { continue; };
+
+            let data = chunk_data(&tiles, &hextiles, &asset_server);
+            let task = save_chunk_task(configs.chunk_path(key.0, key.1), data);
+            // A dropped task is cancelled, so a previous save of the same chunk is left to finish
+            if let Some(previous_task) = streams.saving.insert(key, task) {
+                previous_task.detach();
+            }
+
+            for tile_entity in tiles {
+                commands.entity(tile_entity).despawn();
+            }
+        }
+
+        // Load close chunks
+        let load_radius = configs.load_radius as i64;
+        for chunk_x in (camera_chunk.0 - load_radius).max(0)..=(camera_chunk.0 + load_radius).min(chunk_columns - 1) {
+            for chunk_y in (camera_chunk.1 - load_radius).max(0)..=(camera_chunk.1 + load_radius).min(chunk_rows - 1) {
+                let key = (hexgrid.id, (chunk_x as u32, chunk_y as u32));
+
+                // A chunk being saved is loaded once the file is written, so it is not read half-written
+                if streams.loaded.contains_key(&key) || streams.loading.contains_key(&key) || streams.saving.contains_key(&key) {
+                    continue;
+                }
+
+                let task = load_chunk_task(configs.chunk_path(key.0, key.1));
+                streams.loading.insert(key, (grid_entity, task));
+            }
+        }
+    }
+}
+
+/// Spawns the tiles of chunks which finished loading
+fn spawn_loaded_hexgrid_chunks(
+    mut commands: Commands,
+    configs: Res<HexStreamingConfigs>,
+    mut streams: ResMut<HexChunkStreams>,
+    hexgrids: Query<&HexGrid, With<StreamedHexGrid>>,
+    asset_server: Res<AssetServer>,
+    mut error_reporter: ErrorReporter,
+) {
+    let mut finished = Vec::new();
+    streams.loading.retain(|&key, (grid_entity, task)| match block_on(future::poll_once(task)) {
+        Some(result) => {
+            finished.push((key, *grid_entity, result));
+            false
+        }
+        None => true,
+    });
+
+    let chunk_size = configs.chunk_size.max(1);
+
+    for ((grid_id, (chunk_x, chunk_y)), grid_entity, result) in finished {
+        let Ok(hexgrid) = hexgrids.get(grid_entity) else { continue; };
+
+        let data = match result {
+            Ok(data) => data.unwrap_or_default(),
+            Err(error) => {
+                error_reporter.report(error);
+                HexChunkData::default()
+            }
+        };
+        let stored: HashMap<(u32, u32), StreamedHexTile> = data.tiles
+            .into_iter()
+            .map(|tile| ((tile.x, tile.y), tile))
+            .collect();
+
+        let mut tiles = Vec::new();
+
+        for col in (chunk_x * chunk_size)..((chunk_x + 1) * chunk_size).min(hexgrid.columns) {
+            for row in (chunk_y * chunk_size)..((chunk_y + 1) * chunk_size).min(hexgrid.rows) {
+                let mut tile = commands.spawn((
+                    HexTile::new(col, row).get_bundle(hexgrid),
+                    ChildOf(grid_entity),
+                ));
+
+                if let Some(stored_tile) = stored.get(&(col, row)) {
+                    if let Some(texture) = &stored_tile.texture {
+                        tile.insert(Sprite {
+                            custom_size: Some(hexgrid.hextile_sprite_size()),
+                            image: asset_server.load(texture),
+                            ..Default::default()
+                        });
+                    }
+                    if let Some(terrain) = &stored_tile.terrain {
+                        tile.insert(HexTerrain(terrain.clone()));
+                    }
+                }
+
+                let tile_entity = tile.id();
+                commands.send_event(HexTileSpawned { grid_entity, tile_entity, coord: (col, row) });
+                tiles.push(tile_entity);
+            }
+        }
+
+        streams.loaded.insert((grid_id, (chunk_x, chunk_y)), tiles);
+    }
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile, 
+        TileTextures
+    }, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// An overlay texture layer drawn over the base texture of tiles (see [`build_change_hexgrid_textures_system()`](super::hexgrid::build_change_hexgrid_textures_system)), 
+/// e.g. roads, resources or decals.
+/// 
+/// Each covered tile gets a child [`Entity`] with a [`Sprite`] and a [`HexTileTextureLayer`], 
+/// raised by `z_offset` above the tile.
+#[derive(Clone)]
+pub struct TileTextureLayer {
+    /// Identifies the layer, rewriting a layer replaces the sprites of the same index only
+    pub index: u32,
+
+    /// `z` of the layer sprites relative to their tile, layers with larger offsets are drawn above
+    pub z_offset: f32,
+
+    /// The textures of the layer, resolved per tile like the base textures
+    pub textures: TileTextures,
+
+    /// Ranges in hexgrid tile order notation (see [`HexTile::coord_to_order()`]) of the tiles 
+    /// covered by the layer, `None` covers every tile
+    pub ranges: Option<Vec<(u32, u32)>>,
+}
+
+impl TileTextureLayer {
+    /// Returns whether the tile with the given order is covered by this layer
+    pub fn covers(&self, order_pos: u32) -> bool {
+        self.ranges
+            .as_ref()
+            .is_none_or(|ranges| ranges.iter().any(|&(start, end)| order_pos >= start && order_pos <= end))
+    }
+}
+
+/// A [`Component`] marking the child [`Entity`] of a [`HexTile`] drawing one of its [`TileTextureLayer`]s.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component)]
+pub struct HexTileTextureLayer {
+    pub index: u32,
+}
+
+/// Builds a [`System`] closure which (re)draws the given [`TileTextureLayer`]s over the tiles of the 
+/// [`HexGrid`] specified by id: the previous sprites of these layers are despawned, and a new one is 
+/// spawned for every tile each layer covers. Layers with other indices are left untouched.
+/// 
+/// Pass a layer with empty `ranges` to remove it from every tile.
+/// 
+/// Reports a [`Bevy2dUtilError::GridNotFound`] if no such grid is spawned.
+/// 
+/// Can be added to the [`Startup`] schedule if set to run after the entities have 
+/// been spawned, also can be used as a one-shot system.
+pub fn build_change_hexgrid_texture_layers_system(
+    grid_id: u64,
+    layers: Vec<TileTextureLayer>,
+) -> impl FnMut(
+    Commands,
+    Res<AssetServer>,
+    Res<HexGridRegistry>,
+    Res<HexTileIndex>,
+    Query<&HexGrid>,
+    Query<&Children, With<HexTile>>,
+    Query<&HexTileTextureLayer>,
+    ErrorReporter,
+) {
+    move |
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        registry: Res<HexGridRegistry>,
+        tile_index: Res<HexTileIndex>,
+        hexgrids: Query<&HexGrid>,
+        tile_children: Query<&Children, With<HexTile>>,
+        texture_layers: Query<&HexTileTextureLayer>,
+        mut error_reporter: ErrorReporter
+    | {
+        let _span = info_span!("hexgrid_apply_texture_layers", grid_id, layers = layers.len()).entered();
+
+        let Some(hexgrid) = registry.get(grid_id).and_then(|grid_entity| hexgrids.get(grid_entity).ok()) else {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return;
+        };
+
+        for (x, y, tile_entity) in tile_index.iter_grid(grid_id) {
+            // Despawn the previous sprites of the rewritten layers
+            if let Ok(children) = tile_children.get(tile_entity) {
+                for &child in children {
+                    if texture_layers.get(child).is_ok_and(|texture_layer| layers.iter().any(|layer| layer.index == texture_layer.index)) {
+                        commands.entity(child).despawn();
+                    }
+                }
+            }
+
+            let hextile = HexTile::new(x, y);
+            let order_pos = hextile.coord_to_order(hexgrid.columns);
+
+            for layer in layers.iter().filter(|layer| layer.covers(order_pos)) {
+                commands.spawn((
+                    HexTileTextureLayer { index: layer.index },
+                    layer.textures.texture_for_tile(&hextile, hexgrid.columns).to_sprite(&asset_server, hexgrid.hextile_sprite_size()),
+                    Transform::from_xyz(0., 0., layer.z_offset),
+                    ChildOf(tile_entity),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use super::{
+    hexgrid::HexTile, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// The [`Sprite`] image of a tile, as captured in a [`HexGridTextureSnapshot`]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexTileTextureState {
+    /// The `x` and `y` hexgrid coordinates of the tile
+    pub coord: (u32, u32),
+
+    /// Asset path of the image
+    pub image: String,
+
+    /// Index of the texture in the atlas of the [`Sprite`], if it has one
+    pub atlas_index: Option<usize>,
+}
+
+/// The [`Sprite`] image assignment of every tile of a [`HexGrid`](super::hexgrid::HexGrid), so temporary
+/// texture changes (previews, battle highlights...) can be rolled back with [`HexGridTextureSnapshot::restore()`]
+/// or [`build_restore_hexgrid_textures_system()`].
+/// 
+/// Only images loaded from a path are captured, tiles without a [`Sprite`] or whose image
+/// was created at runtime are left out (and left untouched when restoring).
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexGridTextureSnapshot {
+    pub grid_id: u64,
+
+    /// Captured tiles, in tile order (see [`HexTile::coord_to_order()`])
+    pub tiles: Vec<HexTileTextureState>,
+}
+
+impl HexGridTextureSnapshot {
+    /// Captures the [`Sprite`] images of the spawned tiles of the grid with the given id.
+    pub fn capture(
+        grid_id: u64,
+        tile_index: &HexTileIndex,
+        sprites: &Query<&Sprite, With<HexTile>>,
+    ) -> Self {
+        let mut tiles: Vec<HexTileTextureState> = tile_index
+            .iter_grid(grid_id)
+            .filter_map(|(x, y, tile_entity)| {
+                let sprite = sprites.get(tile_entity).ok()?;
+                let image = sprite.image.path()?.to_string();
+
+                Some(HexTileTextureState {
+                    coord: (x, y),
+                    image,
+                    atlas_index: sprite.texture_atlas.as_ref().map(|atlas| atlas.index),
+                })
+            })
+            .collect();
+        tiles.sort_unstable_by_key(|tile| (tile.coord.1, tile.coord.0));
+
+        HexGridTextureSnapshot { grid_id, tiles }
+    }
+
+    /// Gives the captured images back to the [`Sprite`]s of the tiles of the grid, loading them again if needed.
+    /// 
+    /// Tiles which were despawned since are skipped, and atlas indices are only restored on
+    /// [`Sprite`]s which still use an atlas.
+    pub fn restore(
+        &self,
+        asset_server: &AssetServer,
+        tile_index: &HexTileIndex,
+        sprites: &mut Query<&mut Sprite, With<HexTile>>,
+    ) {
+        for tile in &self.tiles {
+            let Some(tile_entity) = tile_index.get_tile_entity(self.grid_id, tile.coord.0, tile.coord.1) else { continue; };
+            let Ok(mut sprite) = sprites.get_mut(tile_entity) else { continue; };
+
+            if sprite.image.path().is_none_or(|path| path.to_string() != tile.image) {
+                sprite.image = asset_server.load(tile.image.clone());
+            }
+
+            if let (Some(atlas), Some(index)) = (sprite.texture_atlas.as_mut(), tile.atlas_index) {
+                atlas.index = index;
+            }
+        }
+    }
+}
+
+/// Builds a [`System`] closure which returns a [`HexGridTextureSnapshot`] of the [`HexGrid`](super::hexgrid::HexGrid)
+/// specified by id, or `None` (reporting a [`Bevy2dUtilError::GridNotFound`]) if no such grid is spawned.
+/// 
+/// Meant to be used as a one-shot system whose output is kept, e.g. with
+/// [`World::run_system_once()`](bevy::ecs::system::RunSystemOnce::run_system_once).
+pub fn build_snapshot_hexgrid_textures_system(grid_id: u64) -> impl FnMut(
+    Res<HexGridRegistry>,
+    Res<HexTileIndex>,
+    Query<&Sprite, With<HexTile>>,
+    ErrorReporter,
+) -> Option<HexGridTextureSnapshot> {
+    move |
+        registry: Res<HexGridRegistry>,
+        tile_index: Res<HexTileIndex>,
+        sprites: Query<&Sprite, With<HexTile>>,
+        mut error_reporter: ErrorReporter
+    | {
+        if registry.get(grid_id).is_none() {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return None;
+        }
+
+        Some(HexGridTextureSnapshot::capture(grid_id, &tile_index, &sprites))
+    }
+}
+
+/// Builds a [`System`] closure which restores the [`Sprite`] images of a [`HexGridTextureSnapshot`]
+/// (see [`HexGridTextureSnapshot::restore()`]).
+/// 
+/// Reports a [`Bevy2dUtilError::GridNotFound`] if the grid of the snapshot is not spawned.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_restore_hexgrid_textures_system(snapshot: HexGridTextureSnapshot) -> impl FnMut(
+    Res<AssetServer>,
+    Res<HexGridRegistry>,
+    Res<HexTileIndex>,
+    Query<&mut Sprite, With<HexTile>>,
+    ErrorReporter,
+) {
+    move |
+        asset_server: Res<AssetServer>,
+        registry: Res<HexGridRegistry>,
+        tile_index: Res<HexTileIndex>,
+        mut sprites: Query<&mut Sprite, With<HexTile>>,
+        mut error_reporter: ErrorReporter
+    | {
+        if registry.get(snapshot.grid_id).is_none() {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id: snapshot.grid_id });
+            return;
+        }
+
+        snapshot.restore(&asset_server, &tile_index, &mut sprites);
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use std::borrow::Cow;
+
+use super::hexgrid::{
+    HexGrid, 
+    HexGridAnchor, 
+    HexGridOrientation, 
+    HexGridSpawned, 
+    HexTile, 
+    HexTileSpawned, 
+    TileTexture, 
+    TileTextures
+};
+use crate::error::ErrorReporter;
+
+/// Returns the `bevy_ecs_tilemap` [`TilemapType`] laying out tiles like a [`HexGrid`] of the given orientation.
+pub fn tilemap_type(orientation: HexGridOrientation) -> TilemapType {
+    match orientation {
+        // Odd columns are shifted up by half a tile
+        HexGridOrientation::Vertical => TilemapType::Hexagon(HexCoordSystem::ColumnOdd),
+        HexGridOrientation::Horizontal => TilemapType::Hexagon(HexCoordSystem::RowOdd),
+    }
+}
+
+/// Returns the `bevy_ecs_tilemap` [`TilemapAnchor`] placing the tilemap like the [`HexGridAnchor`] of a [`HexGrid`].
+pub fn tilemap_anchor(hexgrid: &HexGrid) -> TilemapAnchor {
+    match hexgrid.anchor {
+        HexGridAnchor::Center => TilemapAnchor::Center,
+        HexGridAnchor::BottomLeft => TilemapAnchor::BottomLeft,
+        HexGridAnchor::BottomRight => TilemapAnchor::BottomRight,
+        HexGridAnchor::TopLeft => TilemapAnchor::TopLeft,
+        HexGridAnchor::TopRight => TilemapAnchor::TopRight,
+        // Custom anchors are relative to the tilemap size, the point of the tilemap moved onto its translation
+        HexGridAnchor::Offset(offset) => {
+            let size = hexgrid.local_bounds().size();
+            TilemapAnchor::Custom(if size.x > 0. && size.y > 0. { -offset / size } else { Vec2::ZERO })
+        },
+    }
+}
+
+/// Builds a [`System`] closure which spawns a [`HexGrid`] rendered through `bevy_ecs_tilemap` 
+/// instead of individual [`Sprite`]s, at the specified translation relative to the world (global).
+/// 
+/// The [`HexGrid`] component is inserted on the tilemap [`Entity`], and every tilemap tile 
+/// [`Entity`] receives a [`HexTile`] component and is a child of it, so the grid registry, 
+/// tile index and coordinate APIs of this crate keep working. Tile textures are resolved 
+/// from [`TileTextures`] once, each distinct path becoming one texture of the tilemap, 
+/// or with the atlas variants the sprite sheet becoming the single texture of the tilemap 
+/// (whose textures are then expected to be laid out in a grid of tile-sized cells). 
+/// Animated tiles show their first frame, and [`TileTextures::Procedural`] closures are expected to return images.
+/// 
+/// Requires the `ecs_tilemap` feature, the [`GridsPlugin`](super::GridsPlugin) adds the [`TilemapPlugin`].
+/// 
+/// Reports a [`Bevy2dUtilError::InvalidGridDimensions`](crate::error::Bevy2dUtilError::InvalidGridDimensions) 
+/// instead of spawning anything if the [`HexGrid`] has invalid dimensions (see [`HexGrid::validate_dimensions()`]).
+/// 
+/// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+pub fn build_spawn_hexgrid_tilemap_system(
+    hexgrid: HexGrid,
+    hexgrid_translation: Vec3,
+    textures_configs: TileTextures,
+) -> impl FnMut(Commands, Res<AssetServer>, ErrorReporter) {
+    move |
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        mut error_reporter: ErrorReporter
+    | {
+        if let Err(error) = hexgrid.validate_dimensions() {
+            error_reporter.report(error);
+            return;
+        }
+
+        let _span = info_span!("hexgrid_tilemap_spawn", grid_id = hexgrid.id, tiles = hexgrid.columns * hexgrid.rows).entered();
+
+        let map_size = TilemapSize { x: hexgrid.columns, y: hexgrid.rows };
+        let tilemap_entity = commands.spawn(hexgrid.clone()).id();
+        let mut tile_storage = TileStorage::empty(map_size);
+
+        let mut texture_paths: Vec<Cow<str>> = Vec::new();
+
+        for col in 0..hexgrid.columns {
+            for row in 0..hexgrid.rows {
+                let hextile = HexTile::new(col, row);
+
+                let texture_index = match textures_configs.texture_for_tile(&hextile, hexgrid.columns) {
+                    TileTexture::Image(path) => match texture_paths.iter().position(|known_path| *known_path == path) {
+                        Some(index) => index,
+                        None => {
+                            texture_paths.push(path);
+                            texture_paths.len() - 1
+                        }
+                    },
+                    TileTexture::Atlas { index, .. } => index,
+                };
+
+                let tile_pos = TilePos { x: col, y: row };
+                let tile_entity = commands.spawn((
+                    hextile,
+                    TileBundle {
+                        position: tile_pos,
+                        tilemap_id: TilemapId(tilemap_entity),
+                        texture_index: TileTextureIndex(texture_index as u32),
+                        ..Default::default()
+                    },
+                    ChildOf(tilemap_entity),
+                ))
+                .id();
+
+                tile_storage.set(&tile_pos, tile_entity);
+                commands.send_event(HexTileSpawned { grid_entity: tilemap_entity, tile_entity, coord: (col, row) });
+            }
+        }
+
+        let texture = match &textures_configs {
+            TileTextures::AtlasSingle { image, .. }
+            | TileTextures::AtlasMultiple { image, .. }
+            | TileTextures::AtlasAnimated { image, .. } => TilemapTexture::Single(asset_server.load(image)),
+            _ => TilemapTexture::Vector(texture_paths.iter().map(|path| asset_server.load(&**path)).collect()),
+        };
+        let sprite_size = hexgrid.hextile_sprite_size();
+        let tile_size = TilemapTileSize { x: sprite_size.x, y: sprite_size.y };
+
+        commands.entity(tilemap_entity).insert(TilemapBundle {
+            grid_size: tile_size.into(),
+            map_type: tilemap_type(hexgrid.orientation),
+            size: map_size,
+            storage: tile_storage,
+            texture,
+            tile_size,
+            anchor: tilemap_anchor(&hexgrid),
+            transform: Transform::from_translation(hexgrid_translation),
+            ..Default::default()
+        });
+
+        commands.send_event(HexGridSpawned { grid_id: hexgrid.id, grid_entity: tilemap_entity });
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use std::collections::HashSet;
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }, 
+    registry::{
+        HexGridRegistry, 
+        HexTileIndex
+    }
+};
+use crate::error::{
+    Bevy2dUtilError, 
+    ErrorReporter
+};
+
+/// Builds a [`System`] closure which sets the `color` of the [`Sprite`] of the tiles at 
+/// `hextiles_coords` in the [`HexGrid`] specified by id to `color`, without replacing their texture, 
+/// e.g. for movement range or threat highlighting.
+/// 
+/// Tints are undone with [`build_clear_hextile_tints_system()`]. Like the 
+/// [`HexLightingPlugin`](super::lighting::HexLightingPlugin), this takes over the `color` of tile [`Sprite`]s.
+/// 
+/// Reports a [`Bevy2dUtilError::GridNotFound`] if no such grid is spawned, and a 
+/// [`Bevy2dUtilError::TileOutOfBounds`] for every coordinate outside of it.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_tint_hextiles_system(
+    grid_id: u64,
+    color: Color,
+    hextiles_coords: HashSet<(u32, u32)>,
+) -> impl FnMut(
+    Res<HexGridRegistry>,
+    Res<HexTileIndex>,
+    Query<&HexGrid>,
+    Query<&mut Sprite, With<HexTile>>,
+    ErrorReporter,
+) {
+    move |
+        registry: Res<HexGridRegistry>,
+        tile_index: Res<HexTileIndex>,
+        hexgrids: Query<&HexGrid>,
+        mut sprites: Query<&mut Sprite, With<HexTile>>,
+        mut error_reporter: ErrorReporter
+    | {
+        let Some(hexgrid) = registry.get(grid_id).and_then(|grid_entity| hexgrids.get(grid_entity).ok()) else {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return;
+        };
+
+        for &(x, y) in &hextiles_coords {
+            if x >= hexgrid.columns || y >= hexgrid.rows {
+                error_reporter.report(Bevy2dUtilError::TileOutOfBounds { grid_id, coord: (x, y) });
+                continue;
+            }
+            let Some(tile_entity) = tile_index.get_tile_entity(grid_id, x, y) else { continue; };
+
+            if let Ok(mut sprite) = sprites.get_mut(tile_entity) {
+                sprite.color = color;
+            }
+        }
+    }
+}
+
+/// Builds a [`System`] closure which resets the `color` of the [`Sprite`] of the tiles at 
+/// `hextiles_coords` (or of all tiles if `None`) in the [`HexGrid`] specified by id to white, 
+/// undoing [`build_tint_hextiles_system()`].
+/// 
+/// Reports a [`Bevy2dUtilError::GridNotFound`] if no such grid is spawned.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_clear_hextile_tints_system(
+    grid_id: u64,
+    hextiles_coords: Option<HashSet<(u32, u32)>>,
+) -> impl FnMut(
+    Res<HexGridRegistry>,
+    Res<HexTileIndex>,
+    Query<&mut Sprite, With<HexTile>>,
+    ErrorReporter,
+) {
+    move |
+        registry: Res<HexGridRegistry>,
+        tile_index: Res<HexTileIndex>,
+        mut sprites: Query<&mut Sprite, With<HexTile>>,
+        mut error_reporter: ErrorReporter
+    | {
+        if registry.get(grid_id).is_none() {
+            error_reporter.report(Bevy2dUtilError::GridNotFound { grid_id });
+            return;
+        }
+
+        for (x, y, tile_entity) in tile_index.iter_grid(grid_id) {
+            if hextiles_coords.as_ref().is_some_and(|coords| !coords.contains(&(x, y))) {
+                continue;
+            }
+
+            if let Ok(mut sprite) = sprites.get_mut(tile_entity) {
+                if sprite.color != Color::WHITE {
+                    sprite.color = Color::WHITE;
+                }
+            }
+        }
+    }
+}
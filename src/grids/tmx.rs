@@ -0,0 +1,337 @@
+use bevy::prelude::*;
+use tiled::{
+    LayerTile, 
+    Loader, 
+    Map, 
+    Orientation, 
+    StaggerAxis, 
+    StaggerIndex
+};
+
+use std::{
+    collections::{
+        BTreeSet, 
+        HashMap, 
+        HashSet
+    }, 
+    path::{
+        Path, 
+        PathBuf
+    }
+};
+
+use super::{
+    hexgrid::{
+        HexGrid, 
+        HexGridOrientation, 
+        HexTile
+    }, 
+    registry::HexGridRegistry
+};
+use crate::error::{
+    report_in_world, 
+    Bevy2dUtilError
+};
+
+/// Builds an 'exclusive' [`System`] closure which reads a hexagonal [Tiled](https://www.mapeditor.org/) 
+/// map (`.tmx`) and spawns a matching [`HexGrid`] with the given id at the given translation, each tile 
+/// receiving a [`Sprite`] with the image of the topmost tile layer at its position.
+/// 
+/// Maps staggered along X become [`HexGridOrientation::Vertical`] grids, maps staggered along Y 
+/// become [`HexGridOrientation::Horizontal`] ones. As rows of this crate grow upwards, the map is 
+/// flipped vertically; when its stagger index does not line up with the odd columns or rows being 
+/// shifted, the grid gets an extra row whose tiles outside of the map receive no [`Sprite`].
+/// 
+/// Tile images are taken from "collection of images" tilesets, or cut out of the image of single-image 
+/// tilesets with a [`TextureAtlas`]. Their paths are made relative to `asset_root` (e.g. `"assets"`) 
+/// to be loaded by the [`AssetServer`].
+/// 
+/// Reports a [`Bevy2dUtilError::FileIo`] and spawns nothing if the map cannot be read or is not hexagonal, 
+/// and a [`Bevy2dUtilError::DuplicateGridId`] if a grid with the same id is already registered. Tiles of 
+/// tilesets without any image are left without a [`Sprite`], reporting a [`Bevy2dUtilError::FileIo`] per tileset.
+/// 
+/// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+pub fn build_spawn_hexgrid_from_tmx_system(
+    path: impl Into<PathBuf>,
+    asset_root: impl Into<PathBuf>,
+    grid_id: u64,
+    hexgrid_translation: Vec3,
+) -> impl FnMut(&mut World) {
+    let path = path.into();
+    let asset_root = asset_root.into();
+
+    move | world: &mut World | {
+        let file_io_error = |reason: String| Bevy2dUtilError::FileIo { path: path.display().to_string(), reason };
+
+        let map = match Loader::new().load_tmx_map(&path) {
+            Ok(map) if map.orientation == Orientation::Hexagonal => map,
+            Ok(_) => {
+                report_in_world(world, file_io_error("the map is not hexagonal".to_string()));
+                return;
+            }
+            Err(error) => {
+                report_in_world(world, file_io_error(error.to_string()));
+                return;
+            }
+        };
+
+        if world.get_resource::<HexGridRegistry>().is_some_and(|registry| registry.get(grid_id).is_some()) {
+            report_in_world(world, Bevy2dUtilError::DuplicateGridId { grid_id });
+            return;
+        }
+
+        let layout = TmxLayout::of(&map);
+        let hexgrid = match HexGrid::try_with_id(grid_id, layout.orientation, layout.columns, layout.rows, layout.hextile_width) {
+            Ok(hexgrid) => hexgrid.with_hextile_height(layout.hextile_height),
+            Err(error) => {
+                report_in_world(world, error);
+                return;
+            }
+        };
+
+        // The spawned grid is told apart from grids spawned before, as they may share its id without the `GridsPlugin`
+        let mut hexgrid_entities = world.query_filtered::<Entity, With<HexGrid>>();
+        let previous_hexgrids: HashSet<Entity> = hexgrid_entities.iter(world).collect();
+
+        let mut spawn_hexgrid = hexgrid.clone().build_spawn_hexgrid_entity_system(hexgrid_translation);
+        spawn_hexgrid(world);
+
+        let Some(grid_entity) = hexgrid_entities
+            .iter(world)
+            .find(|grid_entity| !previous_hexgrids.contains(grid_entity))
+        else { return; };
+
+        let tile_entities: HashMap<(u32, u32), Entity> = world
+            .get::<Children>(grid_entity)
+            .into_iter()
+            .flatten()
+            .filter_map(|&tile_entity| world.get::<HexTile>(tile_entity).map(|hextile| ((hextile.x, hextile.y), tile_entity)))
+            .collect();
+
+        let mut sprites = Vec::new();
+        let mut unsupported_tilesets = BTreeSet::new();
+
+        for x in 0..map.width {
+            for y in 0..map.height {
+                let Some(layer_tile) = topmost_tile(&map, x, y) else { continue; };
+                let Some(tile_image) = TmxTileImage::of(&layer_tile) else {
+                    unsupported_tilesets.insert(layer_tile.tileset_index());
+                    continue;
+                };
+
+                let Some(&tile_entity) = tile_entities.get(&layout.to_hexgrid(x, y)) else { continue; };
+                sprites.push((tile_entity, tile_image));
+            }
+        }
+
+        for tileset_index in unsupported_tilesets {
+            let reason = format!("the tileset \"{}\" is neither a single image nor a collection of images", map.tilesets()[tileset_index].name);
+            report_in_world(world, file_io_error(reason));
+        }
+
+        let asset_path = |image_path: &Path| image_path.strip_prefix(&asset_root).unwrap_or(image_path).to_path_buf();
+        let mut spritesheets = HashMap::new();
+
+        for (tile_entity, tile_image) in sprites {
+            let sprite = match tile_image {
+                TmxTileImage::Image(image_path) => Sprite {
+                    image: world.resource::<AssetServer>().load(asset_path(&image_path)),
+                    ..Default::default()
+                },
+                TmxTileImage::Spritesheet { tileset_index, tile_id } => {
+                    let (image, layout) = spritesheets
+                        .entry(tileset_index)
+                        .or_insert_with(|| load_spritesheet(world, &map, tileset_index, asset_path))
+                        .clone();
+
+                    Sprite {
+                        image,
+                        texture_atlas: Some(TextureAtlas { layout, index: tile_id as usize }),
+                        ..Default::default()
+                    }
+                }
+            };
+
+            world.entity_mut(tile_entity).insert(Sprite {
+                custom_size: Some(hexgrid.hextile_sprite_size()),
+                ..sprite
+            });
+        }
+    }
+}
+
+/// How the tiles of a Tiled map are laid out in a [`HexGrid`]
+struct TmxLayout {
+    orientation: HexGridOrientation,
+    columns: u32,
+    rows: u32,
+    hextile_width: f32,
+    hextile_height: f32,
+
+    /// Added to the flipped row of shifted columns (staggered along X), or of every row (staggered along Y)
+    row_offset: u32,
+    stagger_axis: StaggerAxis,
+    stagger_index: StaggerIndex,
+}
+
+impl TmxLayout {
+    fn of(map: &Map) -> Self {
+        let odd_index = map.stagger_index == StaggerIndex::Odd;
+
+        let (orientation, hextile_width, hextile_height, row_offset) = match map.stagger_axis {
+            // Shifted columns are shifted down in Tiled, odd columns are shifted up here
+            StaggerAxis::X => (HexGridOrientation::Vertical, map.tile_width as f32, map.tile_height as f32, odd_index as u32),
+            // Shifted rows must keep the parity of odd rows once flipped
+            StaggerAxis::Y => (HexGridOrientation::Horizontal, map.tile_height as f32, map.tile_width as f32, (map.height % 2 == 0) as u32 ^ !odd_index as u32),
+        };
+
+        TmxLayout {
+            orientation,
+            columns: map.width,
+            rows: map.height + row_offset,
+            hextile_width,
+            hextile_height,
+            row_offset,
+            stagger_axis: map.stagger_axis,
+            stagger_index: map.stagger_index,
+        }
+    }
+
+    /// Returns the hexgrid coordinates of a tile of the map
+    fn to_hexgrid(&self, x: u32, y: u32) -> (u32, u32) {
+        let flipped_row = self.rows - 1 - self.row_offset - y;
+
+        let offset = match self.stagger_axis {
+            StaggerAxis::X => {
+                let shifted = (x % 2 == 1) == (self.stagger_index == StaggerIndex::Odd);
+                if shifted { 0 } else { self.row_offset }
+            }
+            StaggerAxis::Y => self.row_offset,
+        };
+
+        (x, flipped_row + offset)
+    }
+}
+
+/// Returns the topmost tile at a position of the map, if any
+fn topmost_tile(map: &Map, x: u32, y: u32) -> Option<LayerTile<'_>> {
+    map.layers()
+        .filter_map(|layer| layer.as_tile_layer())
+        .filter_map(|tile_layer| tile_layer.get_tile(x as i32, y as i32))
+        .last()
+}
+
+/// Where the image of a tile of the map comes from
+enum TmxTileImage {
+    /// The image of a tile of a "collection of images" tileset
+    Image(PathBuf),
+
+    /// A tile of a single-image tileset, cut out of the image of the tileset
+    Spritesheet { tileset_index: usize, tile_id: u32 },
+}
+
+impl TmxTileImage {
+    /// Returns `None` for tiles of tilesets without any image
+    fn of(layer_tile: &LayerTile) -> Option<Self> {
+        if let Some(image) = layer_tile.get_tile().and_then(|tile| tile.image.as_ref().map(|image| image.source.clone())) {
+            return Some(TmxTileImage::Image(image));
+        }
+
+        layer_tile.get_tileset().image.is_some().then(|| TmxTileImage::Spritesheet { 
+            tileset_index: layer_tile.tileset_index(), 
+            tile_id: layer_tile.id(),
+        })
+    }
+}
+
+/// Starts loading the image of a single-image tileset, and adds a [`TextureAtlasLayout`] 
+/// cutting it into the tiles of the tileset
+fn load_spritesheet(
+    world: &mut World, 
+    map: &Map, 
+    tileset_index: usize, 
+    asset_path: impl Fn(&Path) -> PathBuf,
+) -> (Handle<Image>, Handle<TextureAtlasLayout>) {
+    let tileset = &map.tilesets()[tileset_index];
+    let image_path = tileset.image.as_ref().map(|image| asset_path(&image.source)).unwrap_or_default();
+
+    let columns = tileset.columns.max(1);
+    let layout = TextureAtlasLayout::from_grid(
+        UVec2::new(tileset.tile_width, tileset.tile_height),
+        columns,
+        tileset.tilecount.div_ceil(columns),
+        Some(UVec2::splat(tileset.spacing)),
+        Some(UVec2::splat(tileset.margin)),
+    );
+
+    let image = world.resource::<AssetServer>().load(image_path);
+    let layout = world.resource_mut::<Assets<TextureAtlasLayout>>().add(layout);
+
+    (image, layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::Events;
+
+    use super::*;
+
+    const MAP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="hexagonal" renderorder="right-down" width="3" height="2" tilewidth="32" tileheight="28" infinite="0" hexsidelength="16" staggeraxis="x" staggerindex="odd" nextlayerid="2" nextobjectid="1">
+ <tileset firstgid="1" name="sheet" tilewidth="32" tileheight="28" spacing="2" margin="1" tilecount="4" columns="2">
+  <image source="sheet.png" width="67" height="59"/>
+ </tileset>
+ <tileset firstgid="5" name="empty" tilewidth="32" tileheight="28" tilecount="1" columns="0">
+  <grid orientation="orthogonal" width="1" height="1"/>
+ </tileset>
+ <layer id="1" name="ground" width="3" height="2">
+  <data encoding="csv">
+1,2,3,
+4,0,5
+</data>
+ </layer>
+</map>
+"#;
+
+    #[test]
+    fn spritesheet_tiles_are_cut_out_with_a_texture_atlas() {
+        let directory = std::env::temp_dir().join(format!("bevy2d_utilities_tmx_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("map.tmx"), MAP).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.init_asset::<TextureAtlasLayout>();
+        app.add_event::<Bevy2dUtilError>();
+
+        let mut spawn_hexgrid = build_spawn_hexgrid_from_tmx_system(directory.join("map.tmx"), &directory, 4, Vec3::ZERO);
+        spawn_hexgrid(app.world_mut());
+
+        let map = Loader::new().load_tmx_map(directory.join("map.tmx")).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        let layout = TmxLayout::of(&map);
+        let world = app.world_mut();
+
+        let mut hextiles = world.query::<(&HexTile, Option<&Sprite>)>();
+        let atlas_indices: HashMap<(u32, u32), usize> = hextiles
+            .iter(world)
+            .filter_map(|(hextile, sprite)| Some(((hextile.x, hextile.y), sprite?.texture_atlas.as_ref()?.index)))
+            .collect();
+
+        // Tiles 1 to 4 of the spritesheet, the empty position and the imageless tileset get no sprite
+        assert_eq!(atlas_indices.len(), 4);
+        for (index, (x, y)) in [(0, 0), (1, 0), (2, 0), (0, 1)].into_iter().enumerate() {
+            assert_eq!(atlas_indices.get(&layout.to_hexgrid(x, y)), Some(&index));
+        }
+
+        let atlas_layouts = world.resource::<Assets<TextureAtlasLayout>>();
+        let (_, atlas_layout) = atlas_layouts.iter().next().unwrap();
+        assert_eq!(atlas_layouts.len(), 1);
+        assert_eq!(atlas_layout.textures.len(), 4);
+        assert_eq!(atlas_layout.textures[1], URect::new(35, 1, 67, 29));
+
+        let errors: Vec<Bevy2dUtilError> = world.resource_mut::<Events<Bevy2dUtilError>>().drain().collect();
+        assert!(matches!(errors.as_slice(), [Bevy2dUtilError::FileIo { reason, .. }] if reason.contains("\"empty\"")));
+    }
+}
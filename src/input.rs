@@ -0,0 +1,187 @@
+use bevy::{
+    input::{
+        gamepad::{
+            Gamepad, 
+            GamepadButton
+        }, 
+        mouse::{
+            MouseScrollUnit, 
+            MouseWheel
+        }, 
+        touch::Touches, 
+        InputSystem
+    }, 
+    prelude::*
+};
+
+/// A [`Plugin`] which maps keyboard, mouse, touch and gamepad input to abstract actions
+/// (pan, zoom, select, confirm, cancel), stored in the [`InputActions`] resource every frame.
+/// 
+/// Camera and grid interaction systems read the [`InputActions`] instead of raw input when it
+/// is present, so rebinding and multi-device support only need to change the [`InputBindings`].
+#[derive(Clone, Default)]
+pub struct InputActionsPlugin {
+    pub bindings: InputBindings,
+}
+
+impl Plugin for InputActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<InputBindings>();
+
+        app.insert_resource(self.bindings.clone());
+        app.init_resource::<InputActions>();
+
+        app.add_systems(PreUpdate, update_input_actions.after(InputSystem));
+    }
+}
+
+/// A physical input which can be bound to an action.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub enum ActionInput {
+    Key(KeyCode),
+    Mouse(MouseButton),
+
+    /// A button of any connected gamepad
+    Gamepad(GamepadButton),
+}
+
+/// A [`Resource`] holding which inputs trigger which action, stored by the [`InputActionsPlugin`].
+#[derive(Resource, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct InputBindings {
+    pub pan_up: Vec<ActionInput>,
+    pub pan_down: Vec<ActionInput>,
+    pub pan_left: Vec<ActionInput>,
+    pub pan_right: Vec<ActionInput>,
+
+    /// Each frame one of these is held counts as one zoom step
+    pub zoom_in: Vec<ActionInput>,
+    pub zoom_out: Vec<ActionInput>,
+
+    pub select: Vec<ActionInput>,
+    pub confirm: Vec<ActionInput>,
+    pub cancel: Vec<ActionInput>,
+
+    /// Whether the mouse wheel zooms, one zoom step per line scrolled
+    pub mouse_wheel_zoom: bool,
+
+    /// Whether the left stick of gamepads pans
+    pub gamepad_stick_pan: bool,
+
+    /// Zoom steps per pixel of change of distance between two fingers, `0.0` disables pinch zooming
+    pub pinch_zoom_sensitivity: f32,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        InputBindings {
+            pan_up: vec![ActionInput::Key(KeyCode::KeyW), ActionInput::Key(KeyCode::ArrowUp), ActionInput::Gamepad(GamepadButton::DPadUp)],
+            pan_down: vec![ActionInput::Key(KeyCode::KeyS), ActionInput::Key(KeyCode::ArrowDown), ActionInput::Gamepad(GamepadButton::DPadDown)],
+            pan_left: vec![ActionInput::Key(KeyCode::KeyA), ActionInput::Key(KeyCode::ArrowLeft), ActionInput::Gamepad(GamepadButton::DPadLeft)],
+            pan_right: vec![ActionInput::Key(KeyCode::KeyD), ActionInput::Key(KeyCode::ArrowRight), ActionInput::Gamepad(GamepadButton::DPadRight)],
+            zoom_in: vec![ActionInput::Gamepad(GamepadButton::RightTrigger)],
+            zoom_out: vec![ActionInput::Gamepad(GamepadButton::LeftTrigger)],
+            select: vec![ActionInput::Mouse(MouseButton::Left), ActionInput::Gamepad(GamepadButton::South)],
+            confirm: vec![ActionInput::Mouse(MouseButton::Left), ActionInput::Key(KeyCode::Enter), ActionInput::Gamepad(GamepadButton::South)],
+            cancel: vec![ActionInput::Mouse(MouseButton::Right), ActionInput::Key(KeyCode::Escape), ActionInput::Gamepad(GamepadButton::East)],
+            mouse_wheel_zoom: true,
+            gamepad_stick_pan: true,
+            pinch_zoom_sensitivity: 0.02,
+        }
+    }
+}
+
+/// A [`Resource`] holding the state of every action during the current frame,
+/// updated by the [`InputActionsPlugin`] in [`PreUpdate`].
+#[derive(Resource, Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputActions {
+    /// Pan direction, each axis between `-1.0` and `1.0`
+    pub pan: Vec2,
+
+    /// Zoom steps, positive values zoom in
+    pub zoom: f32,
+
+    /// Whether select was pressed this frame
+    pub select: bool,
+
+    /// Whether confirm was pressed this frame
+    pub confirm: bool,
+
+    /// Whether cancel was pressed this frame
+    pub cancel: bool,
+}
+
+/// Pixels scrolled by touchpads and browsers which count as one line scrolled by a mouse wheel
+const PIXELS_PER_SCROLL_LINE: f32 = 100.;
+
+/// Returns the vertical distance scrolled by a [`MouseWheel`] event in lines, 
+/// whether the device reports lines or pixels
+pub(crate) fn scrolled_lines(mouse_wheel: &MouseWheel) -> f32 {
+    match mouse_wheel.unit {
+        MouseScrollUnit::Line => mouse_wheel.y,
+        MouseScrollUnit::Pixel => mouse_wheel.y / PIXELS_PER_SCROLL_LINE,
+    }
+}
+
+/// Feeds every input device into the [`InputActions`] resource
+pub(crate) fn update_input_actions(
+    bindings: Res<InputBindings>,
+    mut actions: ResMut<InputActions>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    touches: Res<Touches>,
+    gamepads: Query<&Gamepad>,
+) {
+    let pressed = |inputs: &[ActionInput]| inputs.iter().any(|input| match *input {
+        ActionInput::Key(key) => keys.pressed(key),
+        ActionInput::Mouse(button) => mouse_buttons.pressed(button),
+        ActionInput::Gamepad(button) => gamepads.iter().any(|gamepad| gamepad.pressed(button)),
+    });
+    let just_pressed = |inputs: &[ActionInput]| inputs.iter().any(|input| match *input {
+        ActionInput::Key(key) => keys.just_pressed(key),
+        ActionInput::Mouse(button) => mouse_buttons.just_pressed(button),
+        ActionInput::Gamepad(button) => gamepads.iter().any(|gamepad| gamepad.just_pressed(button)),
+    });
+
+    let mut pan = Vec2::ZERO;
+    if pressed(&bindings.pan_up) { pan.y += 1.; }
+    if pressed(&bindings.pan_down) { pan.y -= 1.; }
+    if pressed(&bindings.pan_right) { pan.x += 1.; }
+    if pressed(&bindings.pan_left) { pan.x -= 1.; }
+    if bindings.gamepad_stick_pan {
+        for gamepad in &gamepads {
+            pan += gamepad.left_stick();
+        }
+    }
+
+    let mut zoom = 0.;
+    if pressed(&bindings.zoom_in) { zoom += 1.; }
+    if pressed(&bindings.zoom_out) { zoom -= 1.; }
+
+    let wheel_zoom: f32 = mouse_wheel_reader.read().map(scrolled_lines).sum();
+    if bindings.mouse_wheel_zoom {
+        zoom += wheel_zoom;
+    }
+
+    let mut active_touches = touches.iter();
+    if let (Some(first), Some(second), None) = (active_touches.next(), active_touches.next(), active_touches.next()) {
+        // Spreading the fingers apart zooms in
+        let previous_distance = first.previous_position().distance(second.previous_position());
+        let distance = first.position().distance(second.position());
+        zoom += (distance - previous_distance) * bindings.pinch_zoom_sensitivity;
+    }
+
+    let new_actions = InputActions {
+        pan: pan.clamp(Vec2::NEG_ONE, Vec2::ONE),
+        zoom,
+        select: just_pressed(&bindings.select),
+        confirm: just_pressed(&bindings.confirm),
+        cancel: just_pressed(&bindings.cancel),
+    };
+
+    if *actions != new_actions {
+        *actions = new_actions;
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::{
+    app::{
+        App, 
+        Plugin
+    }, 
+    ecs::{
+        entity::Entity, 
+        query::With, 
+        world::World
+    }, 
+    transform::components::{
+        GlobalTransform, 
+        Transform
+    }
+};
+use bevy_inspector_egui::{
+    bevy_egui::{
+        EguiContext, 
+        EguiContextPass, 
+        EguiPlugin, 
+        PrimaryEguiContext
+    }, 
+    bevy_inspector, 
+    egui, 
+    DefaultInspectorConfigPlugin
+};
+
+use crate::{
+    dynamic_camera::{
+        CameraMoveConfigs, 
+        CameraZoomConfigs
+    }, 
+    grids::hexgrid::HexGrid, 
+    window::WindowConfigs, 
+    MainCameraFilter
+};
+
+/// A [`Plugin`] which adds bevy-inspector-egui panels for live-tuning the crate's configurations:
+/// camera speeds and limits, window settings, and a browser listing every [`HexGrid`] with 
+/// a button focusing the camera on it.
+/// 
+/// Adds the [`EguiPlugin`] if it has not been added yet.
+#[derive(Clone, Default)]
+pub struct InspectorPanelsPlugin;
+
+impl Plugin for InspectorPanelsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin { enable_multipass_for_primary_context: true });
+        }
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+
+        app.add_systems(EguiContextPass, inspector_panels_ui);
+    }
+}
+
+/// Draws the camera, window and grid browser panels
+fn inspector_panels_ui(world: &mut World) {
+    let Ok(egui_context) = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world)
+    else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    egui::Window::new("Camera").show(egui_context.get_mut(), |ui| {
+        ui.heading("Movement");
+        if world.contains_resource::<CameraMoveConfigs>() {
+            bevy_inspector::ui_for_resource::<CameraMoveConfigs>(world, ui);
+        } else {
+            ui.label("WASD movement is not enabled");
+        }
+
+        ui.heading("Zoom");
+        if world.contains_resource::<CameraZoomConfigs>() {
+            bevy_inspector::ui_for_resource::<CameraZoomConfigs>(world, ui);
+        } else {
+            ui.label("Scroll zooming is not enabled");
+        }
+    });
+
+    egui::Window::new("Window").show(egui_context.get_mut(), |ui| {
+        if world.contains_resource::<WindowConfigs>() {
+            bevy_inspector::ui_for_resource::<WindowConfigs>(world, ui);
+        } else {
+            ui.label("WindowUtilPlugin is not added");
+        }
+    });
+
+    let grids: Vec<(Entity, HexGrid, GlobalTransform)> = world
+        .query::<(Entity, &HexGrid, &GlobalTransform)>()
+        .iter(world)
+        .map(|(entity, hexgrid, global_transform)| (entity, hexgrid.clone(), *global_transform))
+        .collect();
+
+    let mut focus_target = None;
+
+    egui::Window::new("Grids").show(egui_context.get_mut(), |ui| {
+        if grids.is_empty() {
+            ui.label("No grids spawned");
+        }
+
+        for (entity, hexgrid, global_transform) in &grids {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "#{} ({entity}) {}x{} tiles, {}px wide",
+                    hexgrid.id, hexgrid.columns, hexgrid.rows, hexgrid.hextile_width
+                ));
+                if ui.button("Focus camera").clicked() {
+                    focus_target = Some(global_transform.translation());
+                }
+            });
+        }
+    });
+
+    if let Some(target) = focus_target {
+        let mut cameras = world.query_filtered::<&mut Transform, MainCameraFilter>();
+
+        for mut camera_transform in cameras.iter_mut(world) {
+            camera_transform.translation.x = target.x;
+            camera_transform.translation.y = target.y;
+        }
+    }
+}
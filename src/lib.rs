@@ -1,21 +1,384 @@
+pub mod error;
+#[cfg(feature = "dynamic_camera")]
 pub mod dynamic_camera;
+#[cfg(feature = "window")]
 pub mod window;
+#[cfg(feature = "grids")]
 pub mod grids;
+#[cfg(feature = "inspector")]
+pub mod inspector;
+#[cfg(any(feature = "dynamic_camera", feature = "grids"))]
+pub mod input;
+#[cfg(feature = "replay")]
+pub mod replay;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+#[cfg(feature = "stress")]
+pub mod stress;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
 
 pub mod prelude {
     #[doc(hidden)]
+    pub use crate::{
+        error::Bevy2dUtilError,
+        Bevy2dUtilitiesPlugin
+    };
+
+    #[doc(hidden)]
+    #[cfg(any(feature = "dynamic_camera", feature = "grids"))]
+    pub use crate::input::{
+        ActionInput,
+        InputActions,
+        InputActionsPlugin,
+        InputBindings
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "dynamic_camera")]
     pub use crate::dynamic_camera::{
+        build_move_camera_by_actions_system,
+        build_scroll_zoom_camera_system,
+        build_spawn_camera_system,
+        build_wasd_move_camera_system,
+        build_zoom_camera_by_actions_system,
+        confine_camera,
+        CameraAccessibilityConfigs,
+        CameraConfinement,
         CameraMoveConfigs,
-        CameraZoomConfigs
+        CameraZoomConfigs,
+        ConfinementTarget,
+        Dynamic2dCameraPlugin
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "window")]
+    pub use crate::window::{
+        build_close_secondary_window_system,
+        build_drag_window_by_background_system,
+        build_place_window_system,
+        build_select_video_mode_system,
+        build_set_window_decorations_system,
+        build_spawn_secondary_window_system,
+        toggle_always_on_top,
+        toggle_window_decorations,
+        AvailableVideoModes,
+        CloseRequested,
+        ConfirmClose,
+        ConfirmWindowMode,
+        FullScreenConfig,
+        RequestWindowMode,
+        SafeAreaInsets,
+        SecondaryWindow,
+        SecondaryWindowCamera,
+        SecondaryWindowSpawned,
+        WindowConfigs,
+        WindowModeApplied,
+        WindowModeReverted,
+        WindowModeRevertReason,
+        WindowPlacement,
+        WindowTransparency,
+        WindowUtilPlugin
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "grids")]
+    pub use crate::grids::{
+        anchor::{
+            attach_to_hextile,
+            HexAnchor,
+            HexAttachment
+        },
+        animation::TileAnimation,
+        batching::{
+            HexGridTextureAtlas,
+            HexTextureBatchingPlugin
+        },
+        borders::{
+            build_spawn_region_border_system,
+            HexRegionBorder,
+            HexRegionBorderConfigs
+        },
+        commands::HexGridCommandsExt,
+        coords::{
+            AxialCoord,
+            CubeCoord,
+            HexAxis,
+            HexDirection,
+            OffsetLayout
+        },
+        culling::{
+            HexCullingMode,
+            HexCullingPlugin,
+            HexGridCulling
+        },
+        decoration::{
+            DecorationRule,
+            HexDecoration,
+            HexDecorationPlugin,
+            HexTerrain
+        },
+        diagnostics::HexGridDiagnosticsPlugin,
+        fog::{
+            HexFogOfWarPlugin,
+            HexFogState,
+            HexGridFog,
+            HexTileFog
+        },
+        fov::{
+            HexFieldOfViewPlugin,
+            HexTileHidden,
+            HexTileOpaque,
+            HexViewer
+        },
+        generation::{
+            build_assign_hexgrid_terrain_system,
+            HexNoise
+        },
+        gizmos::{
+            toggle_hexgrid_gizmos,
+            HexGridGizmoConfigs,
+            HexGridGizmosPlugin
+        },
+        hexgrid::{
+            build_change_hexgrid_textures_system,
+            build_change_hextile_textures_system,
+            HexGrid,
+            HexGridAnchor,
+            HexGridOrientation,
+            HexGridSpawned,
+            HexTile,
+            HexTileSpawned,
+            TextureChoice,
+            TileRegion,
+            TileTexture,
+            TileTextures
+        },
+        interaction::{
+            HexGridInteractionPlugin,
+            HexTileClicked,
+            HexTileHoverEnter,
+            HexTileHoverExit,
+            HexTileHoverHighlight
+        },
+        labels::{
+            toggle_hextile_labels,
+            HexTileLabel,
+            HexTileLabelConfigs,
+            HexTileLabelsPlugin
+        },
+        layering::{
+            HexGridLayering,
+            HexTileZOffset
+        },
+        lighting::{
+            HexAmbientLight,
+            HexLightingPlugin,
+            HexLightSource,
+            HexTileLight
+        },
+        lod::{
+            HexGridLod,
+            HexGridLodMesh,
+            HexLodPlugin
+        },
+        material::build_change_hexgrid_materials_system,
+        mesh::{
+            filled_hexagon_mesh,
+            outline_hexagon_mesh
+        },
+        overlap::{
+            tile_at_world_position,
+            tiles_overlapping_aabb,
+            tiles_overlapping_circle,
+            tiles_overlapping_tile,
+            translate_tile_coord
+        },
+        overlay::{
+            build_spawn_hexgrid_overlay_system,
+            build_toggle_hexgrid_overlay_system,
+            HexGridOverlayConfigs,
+            HexGridOverlayPlugin
+        },
+        pathfinding::{
+            collect_movement_costs,
+            MovementCost
+        },
+        picking::{
+            HexTilePick,
+            HoveredHexTile
+        },
+        placement::{
+            build_placement_preview_system,
+            despawn_placement_ghost,
+            PlacementConfirmed,
+            PlacementGhost,
+            PlacementPreviewConfigs
+        },
+        regions::{
+            assign_hextiles_to_region,
+            HexRegion,
+            HexRegionChanged,
+            HexRegionIndex,
+            HexRegionsPlugin
+        },
+        registry::{
+            HexGridRegistry,
+            HexTileIndex
+        },
+        selection::{
+            HexTileSelectionChanged,
+            HexTileSelectionConfigs,
+            HexTileSelectionOverlay,
+            SelectedTile
+        },
+        texture_layers::{
+            build_change_hexgrid_texture_layers_system,
+            HexTileTextureLayer,
+            TileTextureLayer
+        },
+        texture_snapshot::{
+            build_restore_hexgrid_textures_system,
+            build_snapshot_hexgrid_textures_system,
+            HexGridTextureSnapshot,
+            HexTileTextureState
+        },
+        tint::{
+            build_clear_hextile_tints_system,
+            build_tint_hextiles_system
+        },
+        GridsPlugin
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "replay")]
+    pub use crate::replay::{
+        build_start_recording_system,
+        build_start_replay_system,
+        build_stop_recording_system,
+        InputRecorder,
+        InputRecording,
+        InputReplayer,
+        RecordReplayPlugin
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "snapshot")]
+    pub use crate::snapshot::{
+        build_load_session_system,
+        build_save_session_system,
+        SessionSnapshot
+    };
+
+    #[doc(hidden)]
+    #[cfg(all(feature = "grids", feature = "snapshot"))]
+    pub use crate::grids::persistence::{
+        build_spawn_hexgrid_from_file_system,
+        load_hexgrid_from_file,
+        save_hexgrid_to_file,
+        HexGridFile
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "streaming")]
+    pub use crate::grids::streaming::{
+        build_save_hexgrid_chunks_system,
+        build_spawn_streamed_hexgrid_system,
+        HexChunkStreams,
+        HexStreamingConfigs,
+        HexStreamingPlugin,
+        StreamedHexGrid
     };
+
+    #[doc(hidden)]
+    #[cfg(feature = "tiled")]
+    pub use crate::grids::tmx::build_spawn_hexgrid_from_tmx_system;
+
+    #[doc(hidden)]
+    #[cfg(feature = "physics")]
+    pub use crate::grids::physics::{
+        HexGridCollider,
+        HexGridPhysicsPlugin,
+        HexTileBlocked
+    };
+}
+
+use bevy::app::{
+    PluginGroup, 
+    PluginGroupBuilder
+};
+
+/// Query filter matching the main [`Camera2d`](bevy::core_pipeline::core_2d::Camera2d), 
+/// excluding the cameras of secondary windows
+#[cfg(feature = "window")]
+pub(crate) type MainCameraFilter = (
+    bevy::ecs::query::With<bevy::core_pipeline::core_2d::Camera2d>, 
+    bevy::ecs::query::Without<window::SecondaryWindowCamera>
+);
+
+/// Query filter matching the main [`Camera2d`](bevy::core_pipeline::core_2d::Camera2d)
+#[cfg(all(not(feature = "window"), any(feature = "dynamic_camera", feature = "grids", feature = "snapshot")))]
+pub(crate) type MainCameraFilter = bevy::ecs::query::With<bevy::core_pipeline::core_2d::Camera2d>;
+
+/// A [`PluginGroup`] containing every plugin of this crate (depending on the enabled cargo features).
+/// 
+/// Can be configured in one expression, e.g.:
+/// 
+/// `Bevy2dUtilitiesPlugin::new().with_camera(camera_plugin).with_window(window_plugin).with_grids(grids_plugin)`
+/// 
+/// Plugins which are not configured are added with their default settings.
+#[derive(Clone, Default)]
+pub struct Bevy2dUtilitiesPlugin {
+    #[cfg(feature = "dynamic_camera")]
+    camera: dynamic_camera::Dynamic2dCameraPlugin,
+
+    #[cfg(feature = "window")]
+    window: window::WindowUtilPlugin,
+
+    #[cfg(feature = "grids")]
+    grids: grids::GridsPlugin,
 }
 
-use bevy::app::plugin_group;
+impl Bevy2dUtilitiesPlugin {
+    pub fn new() -> Self {
+        Bevy2dUtilitiesPlugin::default()
+    }
+
+    /// Replaces the [`Dynamic2dCameraPlugin`](dynamic_camera::Dynamic2dCameraPlugin) added by this group.
+    #[cfg(feature = "dynamic_camera")]
+    pub fn with_camera(mut self, camera: dynamic_camera::Dynamic2dCameraPlugin) -> Self {
+        self.camera = camera;
+        self
+    }
+
+    /// Replaces the [`WindowUtilPlugin`](window::WindowUtilPlugin) added by this group.
+    #[cfg(feature = "window")]
+    pub fn with_window(mut self, window: window::WindowUtilPlugin) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Replaces the [`GridsPlugin`](grids::GridsPlugin) added by this group.
+    #[cfg(feature = "grids")]
+    pub fn with_grids(mut self, grids: grids::GridsPlugin) -> Self {
+        self.grids = grids;
+        self
+    }
+}
+
+impl PluginGroup for Bevy2dUtilitiesPlugin {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>();
 
+        #[cfg(feature = "dynamic_camera")]
+        let group = group.add(self.camera);
 
-plugin_group! {
-    pub struct Bevy2dUtilitiesPlugin {
-        dynamic_camera:::Dynamic2dCameraPlugin,
-        window:::WindowUtilPlugin
+        #[cfg(feature = "window")]
+        let group = group.add(self.window);
+
+        #[cfg(feature = "grids")]
+        let group = group.add(self.grids);
+
+        group
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,271 @@
+use bevy::{
+    prelude::*, 
+    window::PrimaryWindow
+};
+use serde::{
+    Deserialize, 
+    Serialize
+};
+
+use std::path::PathBuf;
+
+use crate::{
+    error::{
+        report_in_world, 
+        Bevy2dUtilError
+    }, 
+    grids::placement::PlacementConfirmed, 
+    input::{
+        update_input_actions, 
+        InputActions, 
+        InputActionsPlugin
+    }
+};
+
+/// A [`Plugin`] recording the [`InputActions`], the cursor position and tile interaction events 
+/// with timestamps, and replaying recordings by feeding them back in place of live input, 
+/// for demo recordings, bug reproductions and automated end-to-end tests.
+/// 
+/// Start and stop with [`build_start_recording_system()`], [`build_stop_recording_system()`] 
+/// and [`build_start_replay_system()`].
+#[derive(Clone, Default)]
+pub struct RecordReplayPlugin;
+
+impl Plugin for RecordReplayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputActionsPlugin>() {
+            app.add_plugins(InputActionsPlugin::default());
+        }
+
+        app.init_resource::<InputRecorder>();
+        app.init_resource::<InputReplayer>();
+
+        app.add_event::<Bevy2dUtilError>();
+        app.add_event::<PlacementConfirmed>();
+
+        app.add_systems(PreUpdate, replay_recorded_frames.after(update_input_actions));
+        app.add_systems(Last, (record_frame, compare_replayed_interactions));
+    }
+}
+
+/// A recording, as written to a RON file.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct InputRecording {
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// The input of a frame in which something changed, inputs persisting until the next recorded frame.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecordedFrame {
+    /// Seconds since the start of the recording
+    pub time: f32,
+
+    pub actions: InputActions,
+
+    /// Cursor position in the primary window, in logical pixels
+    pub cursor: Option<Vec2>,
+
+    /// `(grid_id, coord)` of the tiles on which placement was confirmed during this frame
+    pub placements: Vec<(u64, (u32, u32))>,
+}
+
+/// A [`Resource`] holding the recording in progress, if any.
+#[derive(Resource, Default)]
+pub struct InputRecorder {
+    recording: Option<(f32, InputRecording)>,
+}
+
+impl InputRecorder {
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+}
+
+/// A [`Resource`] holding the recording being replayed, if any.
+#[derive(Resource, Default)]
+pub struct InputReplayer {
+    replay: Option<(f32, InputRecording)>,
+    next_frame: usize,
+    expected_placements: Vec<(u64, (u32, u32))>,
+    observed_placements: Vec<(u64, (u32, u32))>,
+}
+
+impl InputReplayer {
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Returns whether the tile interactions observed during the replay differ from the recorded ones, 
+    /// i.e. the replay did not reproduce the recorded session.
+    pub fn diverged(&self) -> bool {
+        !self.expected_placements.starts_with(&self.observed_placements)
+            || (!self.is_replaying() && self.expected_placements != self.observed_placements)
+    }
+}
+
+/// Builds a [`System`] closure which starts recording, discarding any unsaved recording.
+/// 
+/// Can be used as a one-shot system, or run on a key press.
+pub fn build_start_recording_system() -> impl FnMut(ResMut<InputRecorder>, Res<Time>) {
+    move | mut recorder: ResMut<InputRecorder>, time: Res<Time> | {
+        recorder.recording = Some((time.elapsed_secs(), InputRecording::default()));
+    }
+}
+
+/// Builds an 'exclusive' [`System`] closure which stops recording and writes the recording 
+/// to a RON file at the given path.
+/// 
+/// Reports a [`Bevy2dUtilError::FileIo`] if the file cannot be written.
+/// 
+/// Can be used as a one-shot system, or run on a key press.
+pub fn build_stop_recording_system(path: impl Into<PathBuf>) -> impl FnMut(&mut World) {
+    let path = path.into();
+
+    move | world: &mut World | {
+        let Some((_, recording)) = world.resource_mut::<InputRecorder>().recording.take() else { return; };
+
+        let result = ron::ser::to_string_pretty(&recording, ron::ser::PrettyConfig::default())
+            .map_err(|error| error.to_string())
+            .and_then(|serialized| std::fs::write(&path, serialized).map_err(|error| error.to_string()));
+
+        if let Err(reason) = result {
+            report_in_world(world, Bevy2dUtilError::FileIo { path: path.display().to_string(), reason });
+        }
+    }
+}
+
+/// Builds an 'exclusive' [`System`] closure which reads a recording from a RON file at the given 
+/// path and starts replaying it.
+/// 
+/// Reports a [`Bevy2dUtilError::FileIo`] if the file cannot be read.
+/// 
+/// Can be used as a one-shot system, e.g. at [`Startup`] of an end-to-end test.
+pub fn build_start_replay_system(path: impl Into<PathBuf>) -> impl FnMut(&mut World) {
+    let path = path.into();
+
+    move | world: &mut World | {
+        let result = std::fs::read_to_string(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|serialized| ron::from_str::<InputRecording>(&serialized).map_err(|error| error.to_string()));
+
+        match result {
+            Ok(recording) => {
+                let start = world.resource::<Time>().elapsed_secs();
+                let expected_placements = recording.frames
+                    .iter()
+                    .flat_map(|frame| frame.placements.iter().copied())
+                    .collect();
+
+                *world.resource_mut::<InputReplayer>() = InputReplayer {
+                    replay: Some((start, recording)),
+                    next_frame: 0,
+                    expected_placements,
+                    observed_placements: Vec::new(),
+                };
+            }
+            Err(reason) => report_in_world(world, Bevy2dUtilError::FileIo { path: path.display().to_string(), reason }),
+        }
+    }
+}
+
+/// Records the inputs of the frame if they changed since the last recorded frame
+fn record_frame(
+    mut recorder: ResMut<InputRecorder>,
+    time: Res<Time>,
+    actions: Option<Res<InputActions>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut placement_reader: EventReader<PlacementConfirmed>,
+) {
+    let placements: Vec<(u64, (u32, u32))> = placement_reader
+        .read()
+        .map(|placement| (placement.pick.grid_id, placement.pick.coord))
+        .collect();
+
+    let Some((start, recording)) = recorder.recording.as_mut() else { return; };
+
+    let frame = RecordedFrame {
+        time: time.elapsed_secs() - *start,
+        actions: actions.map(|actions| *actions).unwrap_or_default(),
+        cursor: windows.single().ok().and_then(|window| window.cursor_position()),
+        placements,
+    };
+
+    let changed = recording.frames.last().is_none_or(|last| {
+        last.actions != frame.actions || last.cursor != frame.cursor || !frame.placements.is_empty()
+    });
+    if changed {
+        recording.frames.push(frame);
+    }
+}
+
+/// Feeds the recorded frames which are due in place of live input
+fn replay_recorded_frames(
+    mut replayer: ResMut<InputReplayer>,
+    time: Res<Time>,
+    mut actions: ResMut<InputActions>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let replayer = replayer.as_mut();
+    let Some((start, recording)) = replayer.replay.as_ref() else { return; };
+    let elapsed = time.elapsed_secs() - *start;
+
+    let mut replayed_actions: Option<InputActions> = None;
+    let mut cursor = None;
+
+    while let Some(frame) = recording.frames.get(replayer.next_frame) {
+        if frame.time > elapsed {
+            break;
+        }
+
+        // One-frame actions of skipped frames are kept, so they are not lost at a lower frame rate
+        replayed_actions = Some(match replayed_actions {
+            Some(previous) => InputActions {
+                select: previous.select || frame.actions.select,
+                confirm: previous.confirm || frame.actions.confirm,
+                cancel: previous.cancel || frame.actions.cancel,
+                ..frame.actions
+            },
+            None => frame.actions,
+        });
+        cursor = Some(frame.cursor);
+        replayer.next_frame += 1;
+    }
+
+    match replayed_actions {
+        Some(replayed_actions) => *actions = replayed_actions,
+        // Inputs persist until the next recorded frame, one-frame actions do not
+        None => {
+            if let Some(last) = replayer.next_frame.checked_sub(1).and_then(|index| recording.frames.get(index)) {
+                *actions = InputActions {
+                    select: false,
+                    confirm: false,
+                    cancel: false,
+                    ..last.actions
+                };
+            }
+        }
+    }
+
+    if let (Some(cursor), Ok(mut window)) = (cursor, windows.single_mut()) {
+        window.set_cursor_position(cursor);
+    }
+
+    if replayer.next_frame >= recording.frames.len() {
+        replayer.replay = None;
+    }
+}
+
+/// Collects the tile interactions happening during a replay
+fn compare_replayed_interactions(
+    mut replayer: ResMut<InputReplayer>,
+    mut placement_reader: EventReader<PlacementConfirmed>,
+) {
+    if !replayer.is_replaying() && replayer.expected_placements.is_empty() {
+        placement_reader.clear();
+        return;
+    }
+
+    for placement in placement_reader.read() {
+        replayer.observed_placements.push((placement.pick.grid_id, placement.pick.coord));
+    }
+}
@@ -0,0 +1,243 @@
+use bevy::prelude::*;
+use serde::{
+    Deserialize, 
+    Serialize
+};
+
+use std::path::PathBuf;
+
+use crate::{
+    error::{
+        report_in_world, 
+        Bevy2dUtilError
+    }, 
+    MainCameraFilter
+};
+#[cfg(feature = "grids")]
+use crate::grids::{
+    decoration::HexTerrain, 
+    hexgrid::{
+        HexGrid, 
+        HexTile
+    }
+};
+#[cfg(feature = "window")]
+use crate::window::WindowConfigs;
+
+/// The state of a whole session, as written to a single file by [`build_save_session_system()`]
+/// and restored by [`build_load_session_system()`]: all grids, the main camera, and the window settings.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct SessionSnapshot {
+    #[cfg(feature = "grids")]
+    pub grids: Vec<HexGridSnapshot>,
+
+    pub camera: Option<CameraSnapshot>,
+
+    #[cfg(feature = "window")]
+    pub window: Option<WindowConfigs>,
+}
+
+/// The state of a [`HexGrid`] and of its tiles.
+#[cfg(feature = "grids")]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HexGridSnapshot {
+    pub hexgrid: HexGrid,
+    pub translation: Vec3,
+    pub tiles: Vec<HexTileSnapshot>,
+}
+
+/// The state of a single [`HexTile`], only tiles with a texture or a terrain are stored.
+#[cfg(feature = "grids")]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HexTileSnapshot {
+    pub x: u32,
+    pub y: u32,
+
+    /// Path of the image of its [`Sprite`]
+    pub texture: Option<String>,
+
+    pub terrain: Option<String>,
+}
+
+/// The state of the main camera.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct CameraSnapshot {
+    pub translation: Vec3,
+
+    /// `OrthographicProjection.scale`, if the projection is orthographic
+    pub scale: Option<f32>,
+}
+
+impl SessionSnapshot {
+    /// Captures the current state of the session from the [`World`].
+    pub fn capture(world: &mut World) -> Self {
+        let camera = world
+            .query_filtered::<(&Transform, &Projection), MainCameraFilter>()
+            .single(world)
+            .ok()
+            .map(|(transform, projection)| CameraSnapshot {
+                translation: transform.translation,
+                scale: match projection {
+                    Projection::Orthographic(ortho) => Some(ortho.scale),
+                    _ => None,
+                },
+            });
+
+        SessionSnapshot {
+            #[cfg(feature = "grids")]
+            grids: capture_grids(world),
+            camera,
+            #[cfg(feature = "window")]
+            window: world.get_resource::<WindowConfigs>().cloned(),
+        }
+    }
+
+    /// Replaces the current state of the session by this snapshot: despawns every [`HexGrid`]
+    /// and spawns the stored ones, moves the main camera and replaces the [`WindowConfigs`]
+    /// (which are then applied by the [`WindowUtilPlugin`](crate::window::WindowUtilPlugin)).
+    pub fn restore(&self, world: &mut World) {
+        #[cfg(feature = "grids")]
+        restore_grids(&self.grids, world);
+
+        if let Some(camera) = self.camera {
+            if let Ok((mut transform, mut projection)) = world
+                .query_filtered::<(&mut Transform, &mut Projection), MainCameraFilter>()
+                .single_mut(world)
+            {
+                transform.translation = camera.translation;
+
+                if let (Some(scale), Projection::Orthographic(ortho)) = (camera.scale, projection.as_mut()) {
+                    ortho.scale = scale;
+                }
+            }
+        }
+
+        #[cfg(feature = "window")]
+        if let Some(window_configs) = &self.window {
+            world.insert_resource(window_configs.clone());
+        }
+    }
+}
+
+#[cfg(feature = "grids")]
+fn capture_grids(world: &mut World) -> Vec<HexGridSnapshot> {
+    let mut grids: Vec<(Entity, HexGridSnapshot)> = world
+        .query::<(Entity, &HexGrid, &Transform)>()
+        .iter(world)
+        .map(|(grid_entity, hexgrid, transform)| (grid_entity, HexGridSnapshot {
+            hexgrid: hexgrid.clone(),
+            translation: transform.translation,
+            tiles: Vec::new(),
+        }))
+        .collect();
+
+    let asset_server = world.get_resource::<AssetServer>().cloned();
+
+    for (hextile, child_of, sprite, terrain) in world
+        .query::<(&HexTile, &ChildOf, Option<&Sprite>, Option<&HexTerrain>)>()
+        .iter(world)
+    {
+        let texture = sprite
+            .zip(asset_server.as_ref())
+            .and_then(|(sprite, asset_server)| asset_server.get_path(sprite.image.id()))
+            .map(|path| path.to_string());
+        let terrain = terrain.map(|terrain| terrain.0.clone());
+
+        if texture.is_none() && terrain.is_none() {
+            continue;
+        }
+
+        if let Some((_, grid)) = grids.iter_mut().find(|(grid_entity, _)| *grid_entity == child_of.parent()) {
+            grid.tiles.push(HexTileSnapshot { x: hextile.x, y: hextile.y, texture, terrain });
+        }
+    }
+
+    // Sorted so the same session always yields the same file
+    grids.sort_by_key(|(_, grid)| grid.hexgrid.id);
+    grids.into_iter().map(|(_, grid)| grid).collect()
+}
+
+#[cfg(feature = "grids")]
+fn restore_grids(grids: &[HexGridSnapshot], world: &mut World) {
+    let existing: Vec<Entity> = world
+        .query_filtered::<Entity, With<HexGrid>>()
+        .iter(world)
+        .collect();
+    for grid_entity in existing {
+        world.despawn(grid_entity);
+    }
+
+    for grid in grids {
+        let mut spawn_hexgrid = grid.hexgrid.clone().build_spawn_hexgrid_entity_system(grid.translation);
+        spawn_hexgrid(world);
+
+        let Some(grid_entity) = world
+            .query::<(Entity, &HexGrid)>()
+            .iter(world)
+            .find(|(_, hexgrid)| hexgrid.id == grid.hexgrid.id)
+            .map(|(grid_entity, _)| grid_entity)
+        else { continue; };
+
+        let Some(children) = world.get::<Children>(grid_entity).map(|children| children.to_vec()) else { continue; };
+
+        for tile_entity in children {
+            let Some(hextile) = world.get::<HexTile>(tile_entity) else { continue; };
+            let Some(stored) = grid.tiles.iter().find(|tile| tile.x == hextile.x && tile.y == hextile.y) else { continue; };
+
+            if let Some(texture) = &stored.texture {
+                let image = world.resource::<AssetServer>().load(texture);
+                world.entity_mut(tile_entity).insert(Sprite {
+                    custom_size: Some(grid.hexgrid.hextile_sprite_size()),
+                    image,
+                    ..Default::default()
+                });
+            }
+            if let Some(terrain) = &stored.terrain {
+                world.entity_mut(tile_entity).insert(HexTerrain(terrain.clone()));
+            }
+        }
+    }
+}
+
+/// Builds an 'exclusive' [`System`] closure which writes a [`SessionSnapshot`] of the
+/// current session to a RON file at the given path.
+/// 
+/// Reports a [`Bevy2dUtilError::FileIo`] if the file cannot be written.
+/// 
+/// Can be used as a one-shot system, or run on a key press.
+pub fn build_save_session_system(path: impl Into<PathBuf>) -> impl FnMut(&mut World) {
+    let path = path.into();
+
+    move | world: &mut World | {
+        let snapshot = SessionSnapshot::capture(world);
+
+        let result = ron::ser::to_string_pretty(&snapshot, ron::ser::PrettyConfig::default())
+            .map_err(|error| error.to_string())
+            .and_then(|serialized| std::fs::write(&path, serialized).map_err(|error| error.to_string()));
+
+        if let Err(reason) = result {
+            report_in_world(world, Bevy2dUtilError::FileIo { path: path.display().to_string(), reason });
+        }
+    }
+}
+
+/// Builds an 'exclusive' [`System`] closure which reads a [`SessionSnapshot`] from a RON file
+/// at the given path and restores it (see [`SessionSnapshot::restore()`]).
+/// 
+/// Reports a [`Bevy2dUtilError::FileIo`] and leaves the session untouched if the file cannot be read.
+/// 
+/// Can be used as a one-shot system, or run on a key press.
+pub fn build_load_session_system(path: impl Into<PathBuf>) -> impl FnMut(&mut World) {
+    let path = path.into();
+
+    move | world: &mut World | {
+        let result = std::fs::read_to_string(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|serialized| ron::from_str::<SessionSnapshot>(&serialized).map_err(|error| error.to_string()));
+
+        match result {
+            Ok(snapshot) => snapshot.restore(world),
+            Err(reason) => report_in_world(world, Bevy2dUtilError::FileIo { path: path.display().to_string(), reason }),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use bevy::prelude::*;
+
+use crate::{
+    grids::hexgrid::{
+        build_change_hexgrid_textures_system, 
+        HexGrid, 
+        HexGridOrientation, 
+        TileTextures
+    }, 
+    grids::next_random, 
+    MainCameraFilter
+};
+
+/// A camera keyframe of a [`StressScenario`]: the camera moves towards `translation` 
+/// and `scale` over `duration` seconds.
+#[derive(Clone, Copy)]
+pub struct CameraKeyframe {
+    pub translation: Vec2,
+
+    /// Target `OrthographicProjection.scale`
+    pub scale: f32,
+
+    /// Seconds taken to reach this keyframe from the previous one
+    pub duration: f32,
+}
+
+/// Parameters of a reproducible benchmark scene spawned by the [`StressTestPlugin`].
+#[derive(Clone)]
+pub struct StressScenario {
+    /// The id of the spawned [`HexGrid`]
+    pub grid_id: u64,
+
+    pub orientation: HexGridOrientation,
+    pub columns: u32,
+    pub rows: u32,
+    pub hextile_width: f32,
+
+    /// `Some(textures)` textures the grid once spawned, `None` leaves the tiles without [`Sprite`]s
+    pub textures: Option<TileTextures>,
+
+    /// Amount of sprites moving over the grid, bouncing off its bounds
+    pub moving_units: u32,
+
+    /// Speed of the moving units, in pixels per second
+    pub unit_speed: f32,
+
+    /// Keyframes the main camera loops through, an empty script leaves the camera still
+    pub camera_script: Vec<CameraKeyframe>,
+
+    /// Seconds skipped before frame times are recorded (asset loading, pipeline compilation...)
+    pub warmup: f32,
+
+    /// Seconds during which frame times are recorded
+    pub duration: f32,
+
+    /// Seed of the unit placement and directions, the same seed always yields the same scene
+    pub seed: u64,
+}
+
+impl Default for StressScenario {
+    fn default() -> Self {
+        StressScenario {
+            grid_id: u64::MAX,
+            orientation: HexGridOrientation::Vertical,
+            columns: 100,
+            rows: 100,
+            hextile_width: 32.,
+            textures: None,
+            moving_units: 1000,
+            unit_speed: 100.,
+            camera_script: Vec::new(),
+            warmup: 2.,
+            duration: 10.,
+            seed: 0,
+        }
+    }
+}
+
+/// A [`Resource`] collecting the frame times of the running [`StressScenario`].
+#[derive(Resource, Default, Clone)]
+pub struct StressStats {
+    /// Recorded frame times in seconds
+    pub frame_times: Vec<f32>,
+
+    /// Whether the recording `duration` has elapsed
+    pub finished: bool,
+}
+
+impl StressStats {
+    pub fn mean(&self) -> Option<f32> {
+        if self.frame_times.is_empty() {
+            return None;
+        }
+        Some(self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32)
+    }
+
+    pub fn min(&self) -> Option<f32> {
+        self.frame_times.iter().copied().reduce(f32::min)
+    }
+
+    pub fn max(&self) -> Option<f32> {
+        self.frame_times.iter().copied().reduce(f32::max)
+    }
+
+    /// Returns the frame time below which `percentile` (`0.0..=1.0`) of the frames fall, e.g. `0.99` for the 99th percentile.
+    pub fn percentile(&self, percentile: f32) -> Option<f32> {
+        if self.frame_times.is_empty() {
+            return None;
+        }
+        let mut sorted = self.frame_times.clone();
+        sorted.sort_by(f32::total_cmp);
+
+        let index = ((sorted.len() - 1) as f32 * percentile.clamp(0., 1.)).round() as usize;
+        Some(sorted[index])
+    }
+}
+
+/// A [`Component`] for the moving sprites spawned by a [`StressScenario`].
+#[derive(Component, Clone, Copy)]
+pub struct StressUnit {
+    pub velocity: Vec2,
+}
+
+/// A [`Plugin`] which spawns the [`StressScenario`] at [`Startup`], moves its units and camera, 
+/// and records frame times into the [`StressStats`] resource, logging a summary once finished.
+#[derive(Clone, Default)]
+pub struct StressTestPlugin {
+    pub scenario: StressScenario,
+}
+
+impl Plugin for StressTestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StressStats>();
+
+        app.add_systems(Startup, build_spawn_stress_scene_system(self.scenario.clone()));
+        if let Some(textures) = self.scenario.textures.clone() {
+            app.add_systems(PostStartup, build_change_hexgrid_textures_system(textures, self.scenario.grid_id));
+        }
+
+        app.add_systems(Update, (
+            move_stress_units,
+            build_play_camera_script_system(self.scenario.camera_script.clone()),
+            build_record_frame_times_system(self.scenario.warmup, self.scenario.duration),
+        ));
+    }
+}
+
+/// Returns the size of the area covered by the tiles of a grid, centered on the grid
+fn grid_extents(scenario: &StressScenario) -> Vec2 {
+    HexGrid::with_id(scenario.grid_id, scenario.orientation, scenario.columns, scenario.rows, scenario.hextile_width)
+        .local_bounds()
+        .size()
+}
+
+/// Builds an 'exclusive' [`System`] closure which spawns the grid and moving units of a [`StressScenario`].
+/// 
+/// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+pub fn build_spawn_stress_scene_system(scenario: StressScenario) -> impl FnMut(&mut World) {
+    move | world: &mut World | {
+        let mut spawn_hexgrid = HexGrid::with_id(
+            scenario.grid_id,
+            scenario.orientation,
+            scenario.columns,
+            scenario.rows,
+            scenario.hextile_width
+        )
+        .build_spawn_hexgrid_entity_system(Vec3::ZERO);
+        spawn_hexgrid(world);
+
+        let half_extents = grid_extents(&scenario) / 2.;
+        let mut random_state = scenario.seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+
+        world.spawn_batch((0..scenario.moving_units).map(|_| {
+            let position = Vec2::new(
+                (next_random(&mut random_state) * 2. - 1.) * half_extents.x,
+                (next_random(&mut random_state) * 2. - 1.) * half_extents.y,
+            );
+            let direction = Vec2::from_angle(next_random(&mut random_state) * std::f32::consts::TAU);
+
+            (
+                StressUnit { velocity: direction * scenario.unit_speed },
+                StressUnitBounds(half_extents),
+                Sprite::from_color(Color::srgb(0.9, 0.2, 0.2), Vec2::splat(scenario.hextile_width / 2.)),
+                Transform::from_translation(position.extend(1.)),
+            )
+        }));
+    }
+}
+
+/// The half extents of the grid a [`StressUnit`] bounces inside of
+#[derive(Component, Clone, Copy)]
+struct StressUnitBounds(Vec2);
+
+/// Moves the [`StressUnit`]s, reflecting their velocity off the grid bounds
+fn move_stress_units(
+    time: Res<Time>,
+    mut units: Query<(&mut StressUnit, &StressUnitBounds, &mut Transform)>,
+) {
+    for (mut unit, bounds, mut transform) in &mut units {
+        let mut position = transform.translation.truncate() + unit.velocity * time.delta_secs();
+
+        if position.x.abs() > bounds.0.x {
+            unit.velocity.x = -unit.velocity.x;
+            position.x = position.x.clamp(-bounds.0.x, bounds.0.x);
+        }
+        if position.y.abs() > bounds.0.y {
+            unit.velocity.y = -unit.velocity.y;
+            position.y = position.y.clamp(-bounds.0.y, bounds.0.y);
+        }
+
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+/// Builds a [`System`] closure which loops the main camera through the [`CameraKeyframe`]s
+pub fn build_play_camera_script_system(camera_script: Vec<CameraKeyframe>) -> impl FnMut(
+    Res<Time>,
+    Single<(&mut Transform, &mut Projection), MainCameraFilter>
+) {
+    let mut keyframe_index = 0;
+    let mut keyframe_elapsed = 0.;
+    let mut start: Option<(Vec2, f32)> = None;
+
+    move |
+        time: Res<Time>,
+        camera: Single<(&mut Transform, &mut Projection), MainCameraFilter>
+    | {
+        if camera_script.is_empty() {
+            return;
+        }
+        let (mut transform, mut projection) = camera.into_inner();
+        let Projection::Orthographic(ortho) = projection.as_mut() else { return; };
+
+        let (start_translation, start_scale) = *start.get_or_insert((transform.translation.truncate(), ortho.scale));
+        let keyframe = camera_script[keyframe_index];
+
+        keyframe_elapsed += time.delta_secs();
+        let progress = if keyframe.duration > 0. { (keyframe_elapsed / keyframe.duration).min(1.) } else { 1. };
+
+        let translation = start_translation.lerp(keyframe.translation, progress);
+        transform.translation.x = translation.x;
+        transform.translation.y = translation.y;
+        ortho.scale = start_scale + (keyframe.scale - start_scale) * progress;
+
+        if progress >= 1. {
+            keyframe_index = (keyframe_index + 1) % camera_script.len();
+            keyframe_elapsed = 0.;
+            start = Some((keyframe.translation, keyframe.scale));
+        }
+    }
+}
+
+/// Builds a [`System`] closure which records real frame times into [`StressStats`] between 
+/// `warmup` and `warmup + duration` seconds, then logs a summary.
+pub fn build_record_frame_times_system(warmup: f32, duration: f32) -> impl FnMut(
+    Res<Time<Real>>,
+    ResMut<StressStats>
+) {
+    let mut elapsed = 0.;
+
+    move |
+        time: Res<Time<Real>>,
+        mut stress_stats: ResMut<StressStats>
+    | {
+        if stress_stats.finished {
+            return;
+        }
+        elapsed += time.delta_secs();
+
+        if elapsed < warmup {
+            return;
+        }
+        if elapsed < warmup + duration {
+            stress_stats.frame_times.push(time.delta_secs());
+            return;
+        }
+
+        stress_stats.finished = true;
+        info!(
+            "Stress test finished: {} frames, mean {:.2}ms, min {:.2}ms, max {:.2}ms, p99 {:.2}ms",
+            stress_stats.frame_times.len(),
+            stress_stats.mean().unwrap_or(0.) * 1000.,
+            stress_stats.min().unwrap_or(0.) * 1000.,
+            stress_stats.max().unwrap_or(0.) * 1000.,
+            stress_stats.percentile(0.99).unwrap_or(0.) * 1000.,
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use bevy::{
+    app::App, 
+    asset::{
+        AssetApp, 
+        AssetPlugin
+    }, 
+    ecs::entity::Entity, 
+    image::Image, 
+    input::{
+        keyboard::{
+            Key, 
+            KeyCode, 
+            KeyboardInput, 
+            NativeKey
+        }, 
+        mouse::{
+            MouseScrollUnit, 
+            MouseWheel
+        }, 
+        ButtonState, 
+        InputPlugin
+    }, 
+    math::Vec2, 
+    transform::TransformPlugin, 
+    window::{
+        ExitCondition, 
+        PrimaryWindow, 
+        Window, 
+        WindowPlugin
+    }, 
+    MinimalPlugins
+};
+
+#[cfg(feature = "grids")]
+use bevy::{
+    ecs::{
+        hierarchy::ChildOf, 
+        world::World
+    }, 
+    transform::components::Transform
+};
+
+#[cfg(feature = "grids")]
+use crate::grids::hexgrid::{
+    HexGrid, 
+    HexTile
+};
+
+/// Returns a minimal headless [`App`] (no rendering, no OS window) able to run the systems 
+/// of this crate: [`MinimalPlugins`], assets (with the [`Image`] asset registered, but no loaders), 
+/// input, transform propagation, and window management without a primary window.
+/// 
+/// Add this crate's plugins on top of it, and [`spawn_test_window`] if a system needs a [`PrimaryWindow`].
+pub fn headless_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        TransformPlugin,
+        WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            close_when_requested: false,
+        },
+    ));
+    app.init_asset::<Image>();
+
+    app
+}
+
+/// Spawns a [`Window`] marked as the [`PrimaryWindow`] (it will never be opened, as there is no winit backend) 
+/// and returns its [`Entity`].
+pub fn spawn_test_window(app: &mut App) -> Entity {
+    app.world_mut().spawn((Window::default(), PrimaryWindow)).id()
+}
+
+/// Moves the cursor of the given (test) window, in logical pixels relative to its top-left corner.
+pub fn set_cursor_position(app: &mut App, window: Entity, position: Option<Vec2>) {
+    if let Some(mut window) = app.world_mut().get_mut::<Window>(window) {
+        window.set_cursor_position(position);
+    }
+}
+
+/// Sends a fake [`KeyboardInput`] event pressing the key, it is picked up by the [`InputPlugin`] 
+/// on the next [`App::update`] (so both `just_pressed` and `pressed` are true during that update).
+pub fn press_key(app: &mut App, key_code: KeyCode) {
+    send_keyboard_input(app, key_code, ButtonState::Pressed);
+}
+
+/// Sends a fake [`KeyboardInput`] event releasing the key.
+pub fn release_key(app: &mut App, key_code: KeyCode) {
+    send_keyboard_input(app, key_code, ButtonState::Released);
+}
+
+fn send_keyboard_input(app: &mut App, key_code: KeyCode, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Sends a fake [`MouseWheel`] event scrolling `y` lines (positive is scrolling up).
+pub fn scroll_mouse_wheel(app: &mut App, y: f32) {
+    app.world_mut().send_event(MouseWheel {
+        unit: MouseScrollUnit::Line,
+        x: 0.,
+        y,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Returns the [`Entity`] containing the [`HexGrid`] with the given id, if spawned.
+#[cfg(feature = "grids")]
+pub fn find_hexgrid_entity(world: &mut World, grid_id: u64) -> Option<Entity> {
+    world
+        .query::<(Entity, &HexGrid)>()
+        .iter(world)
+        .find(|(_, hexgrid)| hexgrid.id == grid_id)
+        .map(|(entity, _)| entity)
+}
+
+/// Returns the `(x, y)` coordinates of every [`HexTile`] child of the [`HexGrid`] with the given id.
+#[cfg(feature = "grids")]
+pub fn hexgrid_tile_coords(world: &mut World, grid_id: u64) -> Vec<(u32, u32)> {
+    let Some(grid_entity) = find_hexgrid_entity(world, grid_id) else { return Vec::new(); };
+
+    world
+        .query::<(&HexTile, &ChildOf)>()
+        .iter(world)
+        .filter(|(_, child_of)| child_of.parent() == grid_entity)
+        .map(|(hextile, _)| (hextile.x, hextile.y))
+        .collect()
+}
+
+/// Returns the `translation` (relative to the grid) of the [`HexTile`] at `coord` in the [`HexGrid`] with the given id.
+#[cfg(feature = "grids")]
+pub fn hextile_translation(world: &mut World, grid_id: u64, coord: (u32, u32)) -> Option<Vec2> {
+    let grid_entity = find_hexgrid_entity(world, grid_id)?;
+
+    world
+        .query::<(&HexTile, &ChildOf, &Transform)>()
+        .iter(world)
+        .find(|(hextile, child_of, _)| child_of.parent() == grid_entity && (hextile.x, hextile.y) == coord)
+        .map(|(_, _, transform)| transform.translation.truncate())
+}
+
+/// Panics unless the [`HexGrid`] with the given id is spawned with exactly `columns * rows` 
+/// [`HexTile`] children, each coordinate appearing once.
+#[cfg(feature = "grids")]
+pub fn assert_hexgrid_complete(world: &mut World, grid_id: u64) {
+    let Some(grid_entity) = find_hexgrid_entity(world, grid_id) else {
+        panic!("HexGrid {grid_id} is not spawned");
+    };
+    let hexgrid = world.get::<HexGrid>(grid_entity).cloned().unwrap();
+
+    let mut coords = hexgrid_tile_coords(world, grid_id);
+    coords.sort_unstable();
+    coords.dedup();
+
+    assert_eq!(
+        coords.len() as u32, 
+        hexgrid.columns * hexgrid.rows,
+        "HexGrid {grid_id} should contain {}x{} distinct tiles", hexgrid.columns, hexgrid.rows
+    );
+}
+
+/// Panics unless the [`HexTile`] at `coord` in the [`HexGrid`] with the given id sits at 
+/// `expected` (relative to the grid), within `tolerance`.
+#[cfg(feature = "grids")]
+pub fn assert_hextile_translation(world: &mut World, grid_id: u64, coord: (u32, u32), expected: Vec2, tolerance: f32) {
+    let Some(translation) = hextile_translation(world, grid_id, coord) else {
+        panic!("HexTile {coord:?} of HexGrid {grid_id} is not spawned");
+    };
+
+    assert!(
+        translation.distance(expected) <= tolerance,
+        "HexTile {coord:?} of HexGrid {grid_id} is at {translation}, expected {expected}"
+    );
+}
@@ -2,55 +2,617 @@ use bevy::{
     app::{
         App, 
         Plugin, 
+        Startup, 
         Update,
-    }, ecs::{
+    }, 
+    asset::{
+        AssetServer, 
+        Assets, 
+        Handle
+    }, 
+    color::Color, 
+    core_pipeline::core_2d::Camera2d, 
+    ecs::{
+        change_detection::DetectChanges, 
+        component::Component, 
+        entity::Entity, 
+        event::{
+            Event, 
+            EventReader, 
+            EventWriter
+        }, 
+        query::{
+            Changed, 
+            Has, 
+            With
+        }, 
+        reflect::ReflectResource, 
         resource::Resource, 
+        schedule::{
+            common_conditions::resource_exists, 
+            IntoScheduleConfigs
+        }, 
         system::{
+            Commands, 
+            Local, 
+            NonSend, 
             Query, 
-            Res
+            Res, 
+            ResMut
         }
     }, 
+    image::Image, 
     input::{
         keyboard::KeyCode, 
+        mouse::MouseButton, 
         ButtonInput
     }, 
+    math::{
+        IVec2, 
+        UVec2, 
+        Vec2
+    }, 
+    reflect::Reflect, 
+    render::camera::{
+        Camera, 
+        ClearColor, 
+        RenderTarget, 
+        Viewport
+    }, 
+    time::{
+        Time, 
+        Timer, 
+        TimerMode
+    }, 
     window::{
+        CompositeAlphaMode, 
+        Monitor, 
         MonitorSelection, 
+        PrimaryMonitor, 
+        PrimaryWindow, 
+        VideoMode, 
         VideoModeSelection, 
         Window, 
-        WindowMode
-    }
+        WindowCloseRequested, 
+        WindowClosed, 
+        WindowLevel, 
+        WindowMode, 
+        WindowPosition, 
+        WindowRef, 
+        WindowResized
+    }, 
+    winit::WinitWindows
+};
+use winit::window::Icon;
+
+use crate::{
+    error::{
+        Bevy2dUtilError, 
+        ErrorReporter
+    }, 
+    MainCameraFilter
+};
+
+#[cfg(feature = "inspector")]
+use bevy_inspector_egui::{
+    prelude::ReflectInspectorOptions, 
+    InspectorOptions
 };
 
 /// A [`Plugin`] that defines an interface for common window functionality support in Bevy
 #[derive(Clone, Default)]
-pub struct WindowUtilPlugin;
+pub struct WindowUtilPlugin {
+    /// Path towards an image asset to be used as the window/taskbar icon.
+    /// 
+    /// `Some(path)` will load the image at [`Startup`] and apply it to the primary 
+    /// window through winit once it has finished loading.
+    /// 
+    /// `None` will leave the platform default icon.
+    pub window_icon: Option<String>,
+
+    /// The [`WindowConfigs`] inserted into the world as a [`Resource`].
+    /// 
+    /// Defaults to a `512x512` window toggling into exclusive fullscreen on `F11`.
+    pub window_configs: WindowConfigs,
+
+    /// Whether to intercept the window close request (e.g. clicking the `X` button).
+    /// 
+    /// `true` will forward every [`WindowCloseRequested`] as a [`CloseRequested`] event instead, 
+    /// so a "save before quitting?" dialog can be shown. The window will only close once a 
+    /// [`ConfirmClose`] event is sent for it.
+    /// 
+    /// Requires Bevy's `WindowPlugin` to be configured with `close_when_requested: false`, 
+    /// otherwise Bevy closes the window on its own.
+    pub intercept_close_requests: bool,
+}
 
 // Interesting question would be - how to make setting choices persist into the next time of opening the app
 impl Plugin for WindowUtilPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(WindowConfigs::new((512,512), FullScreenConfig::Fullscreen));
+        app.register_type::<WindowConfigs>();
+        app.register_type::<SafeAreaInsets>();
 
-        app.add_systems(Update, f11_change_window_mode);
+        app.insert_resource(self.window_configs.clone());
+        app.init_resource::<AvailableVideoModes>();
+        app.init_resource::<SafeAreaInsets>();
+
+        app.add_event::<Bevy2dUtilError>();
+        app.add_event::<SecondaryWindowSpawned>();
+        app.add_event::<CloseRequested>();
+        app.add_event::<ConfirmClose>();
+        app.add_event::<RequestWindowMode>();
+        app.add_event::<WindowModeApplied>();
+        app.add_event::<ConfirmWindowMode>();
+        app.add_event::<WindowModeReverted>();
+
+        if self.intercept_close_requests {
+            app.add_systems(Update, (forward_close_requests, close_confirmed_windows).chain());
+        }
+
+        if let Some(icon_path) = self.window_icon.clone() {
+            app.add_systems(Startup, build_load_window_icon_system(icon_path));
+            app.add_systems(Update, apply_window_icon.run_if(resource_exists::<WindowIcon>));
+        }
+
+        app.add_systems(Update, (
+            f11_change_window_mode,
+            apply_window_configs,
+            apply_startup_window_placement,
+            inset_camera_viewport_to_safe_area,
+            update_available_video_modes,
+            despawn_closed_secondary_window_cameras,
+            (request_window_modes, verify_window_mode, confirm_or_revert_window_mode).chain(),
+        ));
     }
 }
 
+#[derive(Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FullScreenConfig {
     Fullscreen,
     BorderlessFullscreen
 }
 
-#[derive(Resource)]
-struct WindowConfigs {
-    size: (u32, u32),
-    full_screen_mode: FullScreenConfig
+/// Configurations for the window, stored as a [`Resource`] by the [`WindowUtilPlugin`]
+#[derive(Clone, Resource, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+#[cfg_attr(feature = "inspector", derive(InspectorOptions), reflect(InspectorOptions))]
+pub struct WindowConfigs {
+    pub size: (u32, u32),
+
+    /// Which fullscreen mode `F11` toggles into
+    pub full_screen_mode: FullScreenConfig,
+
+    /// The video mode used by [`FullScreenConfig::Fullscreen`] (exclusive fullscreen).
+    /// 
+    /// `Some(video_mode)` will request that specific resolution and refresh rate, pick 
+    /// one from the [`AvailableVideoModes`] resource.
+    /// 
+    /// `None` will keep the monitor's current video mode.
+    pub video_mode: Option<VideoMode>,
+
+    /// Overrides the scale factor (DPI scaling) reported by the OS for the primary window.
+    /// 
+    /// `Some(1.0)` keeps one logical pixel equal to one physical pixel, which stops 
+    /// high-DPI displays from blurring or stretching pixel art.
+    /// 
+    /// `None` will use the scale factor provided by the OS.
+    pub scale_factor_override: Option<f32>,
+
+    /// Where to place the primary window at startup, and on which monitor.
+    /// 
+    /// `None` will leave the placement up to the OS. Use [`build_place_window_system`] 
+    /// to re-place the window later on.
+    pub placement: Option<(WindowPlacement, MonitorSelection)>,
+
+    /// Whether the primary window has decorations (title bar, borders).
+    /// 
+    /// Changes to this field are applied at runtime, `false` is useful for 
+    /// borderless-windowed setups and streaming overlays.
+    pub decorations: bool,
+
+    /// The window level of the primary window.
+    /// 
+    /// [`WindowLevel::AlwaysOnTop`] keeps it above other windows, which suits companion-tool style apps.
+    /// Changes to this field are applied at runtime.
+    pub window_level: WindowLevel,
+
+    /// Transparency settings for overlay-style apps.
+    /// 
+    /// `Some(_)` makes the primary window transparent and sets the [`ClearColor`] 
+    /// (whose alpha is what actually lets the desktop show through).
+    /// 
+    /// `None` leaves the window opaque and the [`ClearColor`] untouched.
+    pub transparency: Option<WindowTransparency>,
+
+    /// Whether to shrink the viewport of the main [`Camera2d`] so it stays inside the [`SafeAreaInsets`].
+    /// 
+    /// Useful on mobile and web targets where notches and rounded corners would otherwise 
+    /// cover parts of the rendered scene.
+    pub inset_viewport_to_safe_area: bool,
+
+    /// Whether the canvas should follow the size of its parent HTML element on web builds, 
+    /// so the game resizes with the page. Ignored on other targets.
+    pub fit_canvas_to_parent: bool,
+}
+
+/// Transparency settings for the primary window, see [`WindowConfigs::transparency`].
+/// 
+/// Some platforms only honor transparency when the window is created with it, in that case 
+/// also call [`WindowTransparency::apply_to`] on the `primary_window` given to Bevy's `WindowPlugin`.
+#[derive(Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowTransparency {
+    /// How the window's alpha is composited with the desktop, where supported
+    pub composite_alpha_mode: CompositeAlphaMode,
+
+    /// The [`ClearColor`] to use, usually with an alpha below `1.0`
+    pub clear_color: Color,
+}
+
+impl WindowTransparency {
+    pub fn new(composite_alpha_mode: CompositeAlphaMode, clear_color: Color) -> Self {
+        WindowTransparency {
+            composite_alpha_mode,
+            clear_color,
+        }
+    }
+
+    /// Sets the transparency related fields of a [`Window`]
+    pub fn apply_to(&self, window: &mut Window) {
+        window.transparent = true;
+        window.composite_alpha_mode = self.composite_alpha_mode;
+    }
+}
+
+impl Default for WindowTransparency {
+    fn default() -> Self {
+        WindowTransparency::new(CompositeAlphaMode::Auto, Color::NONE)
+    }
 }
 
 impl WindowConfigs {
-    fn new(size: (u32, u32), full_screen_mode: FullScreenConfig) -> Self {
+    pub fn new(size: (u32, u32), full_screen_mode: FullScreenConfig) -> Self {
         WindowConfigs { 
             size, 
-            full_screen_mode
+            full_screen_mode,
+            video_mode: None,
+            scale_factor_override: None,
+            placement: None,
+            decorations: true,
+            window_level: WindowLevel::Normal,
+            transparency: None,
+            inset_viewport_to_safe_area: false,
+            fit_canvas_to_parent: true,
+        }
+    }
+
+    /// Returns the [`VideoModeSelection`] corresponding to the configured `video_mode`
+    pub fn video_mode_selection(&self) -> VideoModeSelection {
+        match self.video_mode {
+            Some(video_mode) => VideoModeSelection::Specific(video_mode),
+            None => VideoModeSelection::Current,
+        }
+    }
+}
+
+impl Default for WindowConfigs {
+    fn default() -> Self {
+        WindowConfigs::new((512,512), FullScreenConfig::Fullscreen)
+    }
+}
+
+/// Applies the [`WindowConfigs`] settings to the primary window whenever the resource changes
+fn apply_window_configs(
+    window_configs: Res<WindowConfigs>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    clear_color: Option<ResMut<ClearColor>>,
+) {
+    if !window_configs.is_changed() {
+        return;
+    }
+
+    if let (Some(transparency), Some(mut clear_color)) = (window_configs.transparency, clear_color) {
+        clear_color.0 = transparency.clear_color;
+    }
+
+    for mut window in windows.iter_mut() {
+        if window.resolution.scale_factor_override() != window_configs.scale_factor_override {
+            window.resolution.set_scale_factor_override(window_configs.scale_factor_override);
+        }
+        if window.decorations != window_configs.decorations {
+            window.decorations = window_configs.decorations;
+        }
+        if window.window_level != window_configs.window_level {
+            window.window_level = window_configs.window_level;
+        }
+        #[cfg(target_arch = "wasm32")]
+        if window.fit_canvas_to_parent != window_configs.fit_canvas_to_parent {
+            window.fit_canvas_to_parent = window_configs.fit_canvas_to_parent;
+        }
+        if let Some(transparency) = window_configs.transparency {
+            if !window.transparent || window.composite_alpha_mode != transparency.composite_alpha_mode {
+                transparency.apply_to(&mut window);
+            }
+        }
+    }
+}
+
+/// A [`Resource`] holding the display safe-area insets in logical pixels (the parts 
+/// of the screen covered by notches, rounded corners or system bars).
+/// 
+/// winit does not report these yet, so on mobile/WASM targets the app is expected to 
+/// fill this in from its platform glue (e.g. CSS `env(safe-area-inset-*)` or the native 
+/// window insets). Stays zeroed on desktop.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+/// Insets the viewport of the main camera by the [`SafeAreaInsets`] when configured to do so
+fn inset_camera_viewport_to_safe_area(
+    window_configs: Res<WindowConfigs>,
+    safe_area_insets: Res<SafeAreaInsets>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Camera, MainCameraFilter>,
+) {
+    if !window_configs.inset_viewport_to_safe_area {
+        return;
+    }
+    let Ok(window) = windows.single() else { return; };
+
+    let scale_factor = window.resolution.scale_factor();
+    let physical_position = UVec2::new(
+        (safe_area_insets.left * scale_factor) as u32, 
+        (safe_area_insets.top * scale_factor) as u32
+    );
+    let physical_size = UVec2::new(window.resolution.physical_width(), window.resolution.physical_height())
+        .saturating_sub(UVec2::new(
+            ((safe_area_insets.left + safe_area_insets.right) * scale_factor) as u32,
+            ((safe_area_insets.top + safe_area_insets.bottom) * scale_factor) as u32,
+        ));
+
+    // Minimized windows report a size of zero, which is not a valid viewport
+    if physical_size.x == 0 || physical_size.y == 0 {
+        return;
+    }
+
+    for mut camera in cameras.iter_mut() {
+        let up_to_date = camera.viewport.as_ref().is_some_and(|viewport| {
+            viewport.physical_position == physical_position && viewport.physical_size == physical_size
+        });
+
+        if !up_to_date {
+            camera.viewport = Some(Viewport {
+                physical_position,
+                physical_size,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+/// Builds a [`System`] closure which enables or disables the decorations of the primary 
+/// window by setting [`WindowConfigs::decorations`].
+/// 
+/// Can be used as a one-shot system.
+pub fn build_set_window_decorations_system(decorations: bool) -> impl FnMut(ResMut<WindowConfigs>) {
+    move | mut window_configs: ResMut<WindowConfigs> | {
+        window_configs.decorations = decorations;
+    }
+}
+
+/// [`System`] which flips [`WindowConfigs::decorations`], enabling or disabling the decorations 
+/// of the primary window.
+pub fn toggle_window_decorations(mut window_configs: ResMut<WindowConfigs>) {
+    window_configs.decorations = !window_configs.decorations;
+}
+
+/// Builds a [`System`] closure which lets the user drag an undecorated primary window around by 
+/// clicking-and-dragging with the left mouse button anywhere on its background.
+/// 
+/// `is_excluded` receives the cursor position (logical pixels, relative to the window's top-left) and 
+/// should return `true` over interactive areas (buttons, the game board...) where clicks must not 
+/// start a drag.
+/// 
+/// Does nothing while the window has decorations. Opt-in, add it to the [`Update`] schedule.
+pub fn build_drag_window_by_background_system(
+    is_excluded: impl Fn(Vec2) -> bool + Send + Sync + 'static
+) -> impl FnMut(
+    Res<ButtonInput<MouseButton>>,
+    Query<&mut Window, With<PrimaryWindow>>
+) {
+    move |
+        mouse_buttons: Res<ButtonInput<MouseButton>>,
+        mut windows: Query<&mut Window, With<PrimaryWindow>>
+    | {
+        if !mouse_buttons.just_pressed(MouseButton::Left) {
+            return;
+        }
+
+        for mut window in windows.iter_mut() {
+            if window.decorations {
+                continue;
+            }
+            let Some(cursor_position) = window.cursor_position() else { continue; };
+
+            if !is_excluded(cursor_position) {
+                window.start_drag_move();
+            }
+        }
+    }
+}
+
+/// [`System`] which switches [`WindowConfigs::window_level`] between [`WindowLevel::AlwaysOnTop`] 
+/// and [`WindowLevel::Normal`].
+pub fn toggle_always_on_top(mut window_configs: ResMut<WindowConfigs>) {
+    window_configs.window_level = match window_configs.window_level {
+        WindowLevel::AlwaysOnTop => WindowLevel::Normal,
+        _ => WindowLevel::AlwaysOnTop,
+    };
+}
+
+/// Placement presets for the primary window relative to a monitor.
+/// 
+/// The `*Half` presets also resize the window to cover that half of the monitor.
+#[derive(Clone, Copy, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowPlacement {
+    Centered,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    LeftHalf,
+    RightHalf,
+    TopHalf,
+    BottomHalf,
+}
+
+/// Builds a [`System`] closure which moves (and for half-screen presets resizes) the primary 
+/// window according to the [`WindowPlacement`] on the selected monitor.
+/// 
+/// [`MonitorSelection::Current`] falls back to the primary monitor.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_place_window_system(placement: WindowPlacement, monitor_selection: MonitorSelection) -> impl FnMut(
+    Query<&mut Window, With<PrimaryWindow>>,
+    Query<(Entity, &Monitor, Has<PrimaryMonitor>)>,
+    ErrorReporter
+) {
+    move |
+        mut windows: Query<&mut Window, With<PrimaryWindow>>,
+        monitors: Query<(Entity, &Monitor, Has<PrimaryMonitor>)>,
+        mut error_reporter: ErrorReporter
+    | {
+        let Some(monitor) = resolve_monitor(monitor_selection, &monitors) else {
+            error_reporter.report(Bevy2dUtilError::MonitorNotFound);
+            return;
+        };
+
+        for mut window in windows.iter_mut() {
+            place_window(&mut window, placement, &monitor);
+        }
+    }
+}
+
+/// Applies [`WindowConfigs::placement`] once, as soon as the selected monitor is available
+fn apply_startup_window_placement(
+    mut placed: Local<bool>,
+    window_configs: Res<WindowConfigs>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    monitors: Query<(Entity, &Monitor, Has<PrimaryMonitor>)>,
+) {
+    if *placed {
+        return;
+    }
+    let Some((placement, monitor_selection)) = window_configs.placement else {
+        *placed = true;
+        return;
+    };
+    // Monitors are only spawned once winit has resumed, try again next frame
+    let Some(monitor) = resolve_monitor(monitor_selection, &monitors) else { return; };
+
+    for mut window in windows.iter_mut() {
+        place_window(&mut window, placement, &monitor);
+    }
+    *placed = true;
+}
+
+/// Returns the [`Monitor`] corresponding to the [`MonitorSelection`], 
+/// [`MonitorSelection::Current`] falls back to the primary monitor.
+fn resolve_monitor(
+    monitor_selection: MonitorSelection, 
+    monitors: &Query<(Entity, &Monitor, Has<PrimaryMonitor>)>
+) -> Option<Monitor> {
+    match monitor_selection {
+        MonitorSelection::Entity(entity) => monitors.get(entity).ok().map(|(_, monitor, _)| monitor.clone()),
+        MonitorSelection::Index(index) => monitors.iter().nth(index).map(|(_, monitor, _)| monitor.clone()),
+        _ => monitors
+            .iter()
+            .find(|(_, _, is_primary)| *is_primary)
+            .map(|(_, monitor, _)| monitor.clone()),
+    }
+}
+
+/// Sets the position (and size for half-screen presets) of the window in physical pixels 
+fn place_window(window: &mut Window, placement: WindowPlacement, monitor: &Monitor) {
+    let monitor_size = IVec2::new(monitor.physical_width as i32, monitor.physical_height as i32);
+
+    let window_size = match placement {
+        WindowPlacement::LeftHalf | WindowPlacement::RightHalf => IVec2::new(monitor_size.x / 2, monitor_size.y),
+        WindowPlacement::TopHalf | WindowPlacement::BottomHalf => IVec2::new(monitor_size.x, monitor_size.y / 2),
+        _ => IVec2::new(window.resolution.physical_width() as i32, window.resolution.physical_height() as i32),
+    };
+
+    let offset = match placement {
+        WindowPlacement::Centered => (monitor_size - window_size) / 2,
+        WindowPlacement::TopLeft | WindowPlacement::LeftHalf | WindowPlacement::TopHalf => IVec2::ZERO,
+        WindowPlacement::TopRight => IVec2::new(monitor_size.x - window_size.x, 0),
+        WindowPlacement::BottomLeft => IVec2::new(0, monitor_size.y - window_size.y),
+        WindowPlacement::BottomRight => monitor_size - window_size,
+        WindowPlacement::RightHalf => IVec2::new(monitor_size.x / 2, 0),
+        WindowPlacement::BottomHalf => IVec2::new(0, monitor_size.y / 2),
+    };
+
+    if window_size.x as u32 != window.resolution.physical_width() || window_size.y as u32 != window.resolution.physical_height() {
+        window.resolution.set_physical_resolution(window_size.x as u32, window_size.y as u32);
+    }
+    window.position = WindowPosition::At(monitor.physical_position + offset);
+}
+
+/// A [`Resource`] listing the video modes (resolution + refresh rate) supported by the primary monitor, 
+/// sorted from the largest resolution and highest refresh rate downwards.
+/// 
+/// Kept up to date by the [`WindowUtilPlugin`].
+#[derive(Resource, Default, Clone)]
+pub struct AvailableVideoModes {
+    pub modes: Vec<VideoMode>,
+}
+
+/// Refreshes [`AvailableVideoModes`] whenever the primary [`Monitor`] is added or changes
+fn update_available_video_modes(
+    mut available_video_modes: ResMut<AvailableVideoModes>,
+    monitors: Query<&Monitor, (With<PrimaryMonitor>, Changed<Monitor>)>,
+) {
+    for monitor in &monitors {
+        let mut modes = monitor.video_modes.clone();
+
+        modes.sort_by(|a, b| {
+            (b.physical_size.x * b.physical_size.y, b.refresh_rate_millihertz)
+                .cmp(&(a.physical_size.x * a.physical_size.y, a.refresh_rate_millihertz))
+        });
+        modes.dedup();
+
+        available_video_modes.modes = modes;
+    }
+}
+
+/// Builds a [`System`] closure which selects the given [`VideoMode`] (or the monitor's current 
+/// one with `None`) for exclusive fullscreen, and re-applies it to the primary window if it 
+/// is currently in exclusive fullscreen.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_select_video_mode_system(video_mode: Option<VideoMode>) -> impl FnMut(
+    ResMut<WindowConfigs>,
+    Query<&mut Window, With<PrimaryWindow>>
+) {
+    move |
+        mut window_configs: ResMut<WindowConfigs>,
+        mut windows: Query<&mut Window, With<PrimaryWindow>>
+    | {
+        window_configs.video_mode = video_mode;
+
+        for mut window in windows.iter_mut() {
+            if let WindowMode::Fullscreen(monitor_selection, _) = window.mode {
+                window.mode = WindowMode::Fullscreen(monitor_selection, window_configs.video_mode_selection());
+            }
         }
     }
 }
@@ -59,15 +621,21 @@ impl WindowConfigs {
 fn f11_change_window_mode(
     keyboard: Res<ButtonInput<KeyCode>>,
     window_configs: Res<WindowConfigs>,
-    mut windows: Query<&mut Window>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
 ) {
     if keyboard.just_pressed(KeyCode::F11) {
 
+        // Browsers do not allow exclusive fullscreen, fall back to borderless fullscreen
+        #[cfg(target_arch = "wasm32")]
+        let full_screen_mode = FullScreenConfig::BorderlessFullscreen;
+        #[cfg(not(target_arch = "wasm32"))]
+        let full_screen_mode = window_configs.full_screen_mode;
+
         for mut window in  windows.iter_mut() {
-            match window_configs.full_screen_mode {
+            match full_screen_mode {
                 FullScreenConfig::Fullscreen => {
                     window.mode = match window.mode {
-                        WindowMode::Windowed => WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current),
+                        WindowMode::Windowed => WindowMode::Fullscreen(MonitorSelection::Current, window_configs.video_mode_selection()),
                         _ => WindowMode::Windowed,
                     };
                 },
@@ -81,4 +649,569 @@ fn f11_change_window_mode(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Seconds given to the platform to apply a requested window mode before it is considered to have failed
+const WINDOW_MODE_VERIFY_TIMEOUT: f32 = 2.;
+
+/// An [`Event`] requesting a "keep these settings?" transition of the primary window to another mode and/or size.
+/// 
+/// The [`WindowUtilPlugin`] applies the new mode, verifies that it took effect and sends a [`WindowModeApplied`] 
+/// event. Unless a [`ConfirmWindowMode`] event is sent within `confirm_timeout` seconds, or if the mode could 
+/// not be applied, the previous working mode and size are restored and a [`WindowModeReverted`] event is sent.
+#[derive(Event, Clone, Copy)]
+pub struct RequestWindowMode {
+    pub mode: WindowMode,
+
+    /// `Some((width, height))` will also resize the window, in logical pixels. Only verified in [`WindowMode::Windowed`]
+    pub size: Option<(u32, u32)>,
+
+    /// Seconds the user has to confirm the new settings before they are reverted
+    pub confirm_timeout: f32,
+}
+
+/// An [`Event`] sent once a [`RequestWindowMode`] took effect, e.g. to show a "keep these settings?" 
+/// dialog answered with [`ConfirmWindowMode`].
+#[derive(Event, Clone, Copy)]
+pub struct WindowModeApplied {
+    pub mode: WindowMode,
+
+    /// Seconds left before the settings are reverted
+    pub confirm_timeout: f32,
+}
+
+/// An [`Event`] keeping the window mode applied by the last [`RequestWindowMode`].
+#[derive(Event, Clone, Copy, Default)]
+pub struct ConfirmWindowMode;
+
+/// An [`Event`] sent when the previous window mode and size were restored after a [`RequestWindowMode`].
+#[derive(Event, Clone, Copy)]
+pub struct WindowModeReverted {
+    pub reason: WindowModeRevertReason,
+}
+
+/// Why a [`RequestWindowMode`] was reverted
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindowModeRevertReason {
+    /// The requested exclusive fullscreen [`VideoMode`] is not supported by the primary monitor
+    UnsupportedVideoMode,
+
+    /// The window did not reach the requested mode or size in time
+    NotApplied,
+
+    /// No [`ConfirmWindowMode`] was sent in time
+    NotConfirmed,
+}
+
+/// The window mode transition in progress
+#[derive(Resource)]
+struct PendingWindowMode {
+    request: RequestWindowMode,
+
+    /// Mode and logical size of the window before the first unconfirmed request
+    previous_mode: WindowMode,
+    previous_size: Vec2,
+
+    /// Whether the transition was verified, and the user is now being asked to confirm it
+    verified: bool,
+
+    /// Whether a [`ConfirmWindowMode`] was sent, possibly before the transition was verified
+    confirmed: bool,
+
+    /// Logical size last reported by the platform through a [`WindowResized`] event since the request
+    reported_size: Option<Vec2>,
+
+    /// Whether the platform had a frame to apply the request, earlier reports predate it
+    applying: bool,
+    timer: Timer,
+}
+
+/// Applies [`RequestWindowMode`] events to the primary window, rejecting unsupported video modes
+fn request_window_modes(
+    mut commands: Commands,
+    mut request_reader: EventReader<RequestWindowMode>,
+    mut reverted_writer: EventWriter<WindowModeReverted>,
+    mut pending: Option<ResMut<PendingWindowMode>>,
+    available_video_modes: Res<AvailableVideoModes>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Some(request) = request_reader.read().last().copied() else { return; };
+    let Ok(mut window) = windows.single_mut() else { return; };
+
+    if let WindowMode::Fullscreen(_, VideoModeSelection::Specific(video_mode)) = request.mode {
+        if !available_video_modes.modes.is_empty() && !available_video_modes.modes.contains(&video_mode) {
+            reverted_writer.write(WindowModeReverted { reason: WindowModeRevertReason::UnsupportedVideoMode });
+            return;
+        }
+    }
+
+    // While a transition is unconfirmed the window is not in a known working state, keep the one from before it
+    let (previous_mode, previous_size) = match pending.as_deref() {
+        Some(pending) => (pending.previous_mode, pending.previous_size),
+        None => (window.mode, window.resolution.size()),
+    };
+
+    window.mode = request.mode;
+    if let Some((width, height)) = request.size {
+        window.resolution.set(width as f32, height as f32);
+    }
+
+    let new_pending = PendingWindowMode {
+        request,
+        previous_mode,
+        previous_size,
+        verified: false,
+        confirmed: false,
+        reported_size: None,
+        applying: false,
+        timer: Timer::from_seconds(WINDOW_MODE_VERIFY_TIMEOUT, TimerMode::Once),
+    };
+
+    match pending.as_mut() {
+        Some(pending) => **pending = new_pending,
+        None => commands.insert_resource(new_pending),
+    }
+}
+
+/// Checks whether the requested window mode took effect, as reported by the platform, then waits for its confirmation
+fn verify_window_mode(
+    pending: Option<ResMut<PendingWindowMode>>,
+    mut resized_reader: EventReader<WindowResized>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+    mut applied_writer: EventWriter<WindowModeApplied>,
+) {
+    let Ok((window_entity, window)) = windows.single() else { return; };
+    let reported_size = resized_reader
+        .read()
+        .filter(|resized| resized.window == window_entity)
+        .last()
+        .map(|resized| Vec2::new(resized.width, resized.height));
+
+    let Some(mut pending) = pending else { return; };
+    if pending.verified {
+        return;
+    }
+
+    // `Window` holds the values which were just requested until the platform had a frame to apply them
+    if !pending.applying {
+        pending.applying = true;
+        return;
+    }
+    if reported_size.is_some() {
+        pending.reported_size = reported_size;
+    }
+
+    let physical_size = UVec2::new(window.resolution.physical_width(), window.resolution.physical_height());
+
+    let applied = match pending.request.mode {
+        WindowMode::Fullscreen(_, VideoModeSelection::Specific(video_mode)) => physical_size == video_mode.physical_size,
+        WindowMode::Fullscreen(..) | WindowMode::BorderlessFullscreen(_) => monitors
+            .single()
+            .is_ok_and(|monitor| physical_size == UVec2::new(monitor.physical_width, monitor.physical_height)),
+        // Leaving a fullscreen mode or resizing the window is only known to have worked once the platform reports the new size
+        WindowMode::Windowed => match (pending.request.size, pending.reported_size) {
+            (Some((width, height)), Some(reported_size)) => {
+                (reported_size.x - width as f32).abs() < 1. && (reported_size.y - height as f32).abs() < 1.
+            },
+            (Some(_), None) => false,
+            (None, reported_size) => pending.previous_mode == WindowMode::Windowed || reported_size.is_some(),
+        },
+    };
+
+    // Reverting on timeout is left to `confirm_or_revert_window_mode`
+    if applied {
+        let confirm_timeout = pending.request.confirm_timeout;
+        pending.verified = true;
+        pending.timer = Timer::from_seconds(confirm_timeout, TimerMode::Once);
+
+        applied_writer.write(WindowModeApplied { mode: pending.request.mode, confirm_timeout });
+    }
+}
+
+/// Keeps the requested window mode once confirmed, or restores the previous one on timeout
+fn confirm_or_revert_window_mode(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingWindowMode>>,
+    time: Res<Time>,
+    mut confirm_reader: EventReader<ConfirmWindowMode>,
+    mut reverted_writer: EventWriter<WindowModeReverted>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let confirmed = confirm_reader.read().count() > 0;
+    let Some(mut pending) = pending else { return; };
+
+    // Confirmations sent before the transition was verified are kept until it is
+    if confirmed {
+        pending.confirmed = true;
+    }
+    if pending.verified && pending.confirmed {
+        commands.remove_resource::<PendingWindowMode>();
+        return;
+    }
+
+    if !pending.timer.tick(time.delta()).finished() {
+        return;
+    }
+
+    if let Ok(mut window) = windows.single_mut() {
+        window.mode = pending.previous_mode;
+        window.resolution.set(pending.previous_size.x, pending.previous_size.y);
+    }
+
+    let reason = if pending.verified { WindowModeRevertReason::NotConfirmed } else { WindowModeRevertReason::NotApplied };
+    reverted_writer.write(WindowModeReverted { reason });
+    commands.remove_resource::<PendingWindowMode>();
+}
+
+/// An [`Event`] sent instead of closing the window when [`WindowUtilPlugin::intercept_close_requests`] is enabled.
+#[derive(Event, Clone, Copy)]
+pub struct CloseRequested {
+    /// The [`Entity`] of the [`Window`] which was requested to close
+    pub window: Entity,
+}
+
+/// An [`Event`] which actually closes the window (exiting the app if it is the primary one) 
+/// after a [`CloseRequested`] has been handled.
+#[derive(Event, Clone, Copy)]
+pub struct ConfirmClose {
+    /// The [`Entity`] of the [`Window`] to close
+    pub window: Entity,
+}
+
+/// Forwards [`WindowCloseRequested`] events as [`CloseRequested`] events
+fn forward_close_requests(
+    mut requested_reader: EventReader<WindowCloseRequested>,
+    mut close_requested_writer: EventWriter<CloseRequested>,
+) {
+    for request in requested_reader.read() {
+        close_requested_writer.write(CloseRequested { window: request.window });
+    }
+}
+
+/// Despawns the [`Window`] entities for which a [`ConfirmClose`] event was sent
+fn close_confirmed_windows(
+    mut commands: Commands,
+    mut confirm_reader: EventReader<ConfirmClose>,
+    windows: Query<(), With<Window>>,
+) {
+    for confirm in confirm_reader.read() {
+        if windows.contains(confirm.window) {
+            commands.entity(confirm.window).despawn();
+        }
+    }
+}
+
+/// Keeps track of the icon image requested through [`WindowUtilPlugin::window_icon`], 
+/// and whether it has already been handed to winit.
+#[derive(Resource)]
+struct WindowIcon {
+    image: Handle<Image>,
+    applied: bool,
+}
+
+/// Build closure which starts loading the window icon image and stores its handle as a [`WindowIcon`] resource
+fn build_load_window_icon_system(icon_path: String) -> impl FnMut(Commands, Res<AssetServer>) {
+    move | mut commands: Commands, asset_server: Res<AssetServer> | {
+        commands.insert_resource(WindowIcon {
+            image: asset_server.load(&icon_path),
+            applied: false,
+        });
+    }
+}
+
+/// Decodes the [`WindowIcon`] image once loaded and sets it as the icon of the primary window through winit
+fn apply_window_icon(
+    mut window_icon: ResMut<WindowIcon>,
+    images: Res<Assets<Image>>,
+    winit_windows: Option<NonSend<WinitWindows>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut error_reporter: ErrorReporter,
+) {
+    // Without a winit backend (e.g. headless apps) there is no window to set the icon of
+    let Some(winit_windows) = winit_windows else { return; };
+
+    if window_icon.applied {
+        return;
+    }
+
+    let Some(image) = images.get(&window_icon.image) else { return; };
+    let Ok(primary_entity) = primary_window.single() else { return; };
+    let Some(winit_window) = winit_windows.get_window(primary_entity) else { return; };
+
+    // Whatever happens from here on, do not attempt to apply the same image again
+    window_icon.applied = true;
+
+    let rgba_image = match image.clone().try_into_dynamic() {
+        Ok(dynamic_image) => dynamic_image.into_rgba8(),
+        Err(error) => {
+            error_reporter.report(Bevy2dUtilError::InvalidWindowIcon { reason: error.to_string() });
+            return;
+        }
+    };
+    let (width, height) = rgba_image.dimensions();
+
+    match Icon::from_rgba(rgba_image.into_raw(), width, height) {
+        Ok(icon) => winit_window.set_window_icon(Some(icon)),
+        Err(error) => error_reporter.report(Bevy2dUtilError::InvalidWindowIcon { reason: error.to_string() }),
+    }
+}
+
+/// A [`Component`] attached to every [`Window`] [`Entity`] spawned by a system 
+/// built with [`build_spawn_secondary_window_system`].
+#[derive(Component, Clone)]
+pub struct SecondaryWindow {
+    /// The label given to the window when it was spawned (e.g. `"palette"`), 
+    /// used to tell multiple secondary windows apart.
+    pub label: String,
+
+    /// The [`Entity`] containing the [`Camera2d`] which renders into this window.
+    pub camera: Entity,
+}
+
+/// A [`Component`] attached to the [`Camera2d`] [`Entity`] rendering into a [`SecondaryWindow`].
+#[derive(Component, Clone)]
+pub struct SecondaryWindowCamera {
+    /// The [`Entity`] containing the [`SecondaryWindow`] this camera targets.
+    pub window: Entity,
+}
+
+/// An [`Event`] sent once a secondary window and its camera have been spawned, 
+/// exposing both entities to the user.
+#[derive(Event, Clone)]
+pub struct SecondaryWindowSpawned {
+    pub label: String,
+    pub window: Entity,
+    pub camera: Entity,
+}
+
+/// Builds a [`System`] closure which spawns an additional [`Window`] [`Entity`], together 
+/// with a [`Camera2d`] [`Entity`] whose render target is that window.
+/// 
+/// The window receives a [`SecondaryWindow`] component and the camera a [`SecondaryWindowCamera`] 
+/// component, both referencing each other. A [`SecondaryWindowSpawned`] event is sent with both entities.
+/// 
+/// When the window is closed, the camera is despawned by the [`WindowUtilPlugin`].
+/// 
+/// Can be added to the [`Startup`] schedule, also can be used as a one-shot system.
+pub fn build_spawn_secondary_window_system(label: &str, window: Window) -> impl FnMut(
+    Commands,
+    EventWriter<SecondaryWindowSpawned>
+) {
+    let label = label.to_string();
+
+    move |
+        mut commands: Commands,
+        mut spawned_writer: EventWriter<SecondaryWindowSpawned>
+    | {
+        let window_entity = commands.spawn(window.clone()).id();
+
+        let camera_entity = commands.spawn((
+            Camera2d::default(),
+            Camera {
+                target: RenderTarget::Window(WindowRef::Entity(window_entity)),
+                ..Default::default()
+            },
+            SecondaryWindowCamera { window: window_entity },
+        ))
+        .id();
+
+        commands.entity(window_entity).insert(SecondaryWindow {
+            label: label.clone(),
+            camera: camera_entity,
+        });
+
+        spawned_writer.write(SecondaryWindowSpawned { 
+            label: label.clone(), 
+            window: window_entity, 
+            camera: camera_entity 
+        });
+    }
+}
+
+/// Builds a [`System`] closure which closes the [`SecondaryWindow`] with the given label 
+/// by despawning its [`Entity`], its camera will be cleaned up once the window is closed.
+/// 
+/// Can be used as a one-shot system.
+pub fn build_close_secondary_window_system(label: &str) -> impl FnMut(
+    Commands,
+    Query<(Entity, &SecondaryWindow)>
+) {
+    let label = label.to_string();
+
+    move |
+        mut commands: Commands,
+        secondary_windows: Query<(Entity, &SecondaryWindow)>
+    | {
+        for (window_entity, secondary_window) in &secondary_windows {
+            if secondary_window.label == label {
+                commands.entity(window_entity).despawn();
+            }
+        }
+    }
+}
+
+/// Despawns the [`SecondaryWindowCamera`] entities whose target window has been closed
+fn despawn_closed_secondary_window_cameras(
+    mut commands: Commands,
+    mut closed_reader: EventReader<WindowClosed>,
+    cameras: Query<(Entity, &SecondaryWindowCamera)>,
+) {
+    for closed in closed_reader.read() {
+        for (camera_entity, secondary_camera) in &cameras {
+            if secondary_camera.window == closed.window {
+                commands.entity(camera_entity).despawn();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{
+        ecs::event::Events, 
+        time::TimeUpdateStrategy, 
+        window::WindowResolution, 
+        MinimalPlugins
+    };
+
+    use super::*;
+
+    fn app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(250)));
+        app.init_resource::<AvailableVideoModes>();
+        app.add_event::<RequestWindowMode>()
+            .add_event::<WindowModeApplied>()
+            .add_event::<ConfirmWindowMode>()
+            .add_event::<WindowModeReverted>()
+            .add_event::<WindowResized>();
+        app.add_systems(Update, (request_window_modes, verify_window_mode, confirm_or_revert_window_mode).chain());
+
+        let window_entity = app.world_mut().spawn((
+            Window { resolution: WindowResolution::new(800., 600.), ..Default::default() },
+            PrimaryWindow,
+        )).id();
+
+        (app, window_entity)
+    }
+
+    fn request_windowed_size(app: &mut App, confirm_timeout: f32) {
+        app.world_mut().send_event(RequestWindowMode { mode: WindowMode::Windowed, size: Some((1024, 768)), confirm_timeout });
+    }
+
+    fn report_size(app: &mut App, window_entity: Entity, width: f32, height: f32) {
+        app.world_mut().send_event(WindowResized { window: window_entity, width, height });
+    }
+
+    fn applied_count(app: &mut App) -> usize {
+        app.world_mut().resource_mut::<Events<WindowModeApplied>>().drain().count()
+    }
+
+    fn reverted(app: &mut App) -> Vec<WindowModeRevertReason> {
+        app.world_mut().resource_mut::<Events<WindowModeReverted>>().drain().map(|reverted| reverted.reason).collect()
+    }
+
+    fn window_size(app: &mut App, window_entity: Entity) -> Vec2 {
+        app.world().get::<Window>(window_entity).unwrap().resolution.size()
+    }
+
+    #[test]
+    fn windowed_resize_is_applied_once_reported_and_kept_when_confirmed() {
+        let (mut app, window_entity) = app();
+
+        request_windowed_size(&mut app, 5.);
+        app.update();
+        app.update();
+        assert_eq!(applied_count(&mut app), 0);
+
+        // Reports of another size, e.g. from before the request, are not enough
+        report_size(&mut app, window_entity, 800., 600.);
+        app.update();
+        assert_eq!(applied_count(&mut app), 0);
+
+        report_size(&mut app, window_entity, 1024., 768.);
+        app.update();
+        assert_eq!(applied_count(&mut app), 1);
+
+        app.world_mut().send_event(ConfirmWindowMode);
+        app.update();
+        assert!(!app.world().contains_resource::<PendingWindowMode>());
+        assert!(reverted(&mut app).is_empty());
+        assert_eq!(window_size(&mut app, window_entity), Vec2::new(1024., 768.));
+    }
+
+    #[test]
+    fn confirmations_sent_before_verification_are_kept() {
+        let (mut app, window_entity) = app();
+
+        request_windowed_size(&mut app, 5.);
+        app.update();
+        app.world_mut().send_event(ConfirmWindowMode);
+        app.update();
+        assert!(app.world().contains_resource::<PendingWindowMode>());
+
+        report_size(&mut app, window_entity, 1024., 768.);
+        app.update();
+        assert!(!app.world().contains_resource::<PendingWindowMode>());
+        assert!(reverted(&mut app).is_empty());
+    }
+
+    #[test]
+    fn unconfirmed_modes_are_reverted() {
+        let (mut app, window_entity) = app();
+
+        request_windowed_size(&mut app, 1.);
+        app.update();
+        report_size(&mut app, window_entity, 1024., 768.);
+        app.update();
+        assert_eq!(applied_count(&mut app), 1);
+
+        // Events only live for two updates, collect them as they are sent
+        let mut reasons = Vec::new();
+        for _ in 0..8 {
+            app.update();
+            reasons.extend(reverted(&mut app));
+        }
+        assert_eq!(reasons, vec![WindowModeRevertReason::NotConfirmed]);
+        assert!(!app.world().contains_resource::<PendingWindowMode>());
+        assert_eq!(window_size(&mut app, window_entity), Vec2::new(800., 600.));
+    }
+
+    #[test]
+    fn modes_which_never_take_effect_are_reverted() {
+        let (mut app, window_entity) = app();
+
+        request_windowed_size(&mut app, 5.);
+        let mut reasons = Vec::new();
+        for _ in 0..12 {
+            app.update();
+            assert_eq!(applied_count(&mut app), 0);
+            reasons.extend(reverted(&mut app));
+        }
+        assert_eq!(reasons, vec![WindowModeRevertReason::NotApplied]);
+        assert_eq!(window_size(&mut app, window_entity), Vec2::new(800., 600.));
+    }
+
+    #[test]
+    fn unsupported_video_modes_are_rejected() {
+        let (mut app, window_entity) = app();
+
+        let video_mode = |width, height| VideoMode { physical_size: UVec2::new(width, height), bit_depth: 32, refresh_rate_millihertz: 60_000 };
+        app.world_mut().resource_mut::<AvailableVideoModes>().modes = vec![video_mode(1920, 1080)];
+
+        app.world_mut().send_event(RequestWindowMode {
+            mode: WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Specific(video_mode(640, 480))),
+            size: None,
+            confirm_timeout: 5.,
+        });
+        app.update();
+
+        assert_eq!(reverted(&mut app), vec![WindowModeRevertReason::UnsupportedVideoMode]);
+        assert!(!app.world().contains_resource::<PendingWindowMode>());
+        assert_eq!(app.world().get::<Window>(window_entity).unwrap().mode, WindowMode::Windowed);
+    }
+}